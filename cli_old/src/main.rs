@@ -3,6 +3,7 @@ extern crate image;
 extern crate raytracer;
 
 use raytracer::{
+	integrator::IntegratorSettings,
 	trace::*,
 	transform::Transform,
 };
@@ -10,6 +11,7 @@ use raytracer::{
 use core::{
 	scene::*,
 	geometry::{self, AccGrid, Mesh},
+	material::{ComplexIor, Conductor, Emission, EmissionColor, EmissionStrength, Metal},
 };
 
 use core::{prelude::*};
@@ -45,7 +47,7 @@ fn main() {
 	let mut scene = Scene::new();
 	let mut sphere_mesh = Mesh::load_ply(PathBuf::from("assets/meshes/ico_sphere.ply"));
 
-	scene.objects.push(Object {
+	scene.add(Object {
 		geometry: Geometry::Sphere(geometry::Sphere {
 				origin: Vector3::new(-1.0, -0.5, 3.5),
 				radius: 0.5
@@ -53,7 +55,7 @@ fn main() {
 
 		material: Material::Diffuse(Vector3::new(1.0, 0.00, 0.00), 0.02),
 	});
-	// scene.objects.push(Object::Sphere(Sphere { origin: Vector3::new(0.74, -0.25, 3.5), radius: 0.75, material: Material::Metal(
+	// scene.add(Object::Sphere(Sphere { origin: Vector3::new(0.74, -0.25, 3.5), radius: 0.75, material: Material::Metal(
 	//     Vector3::new(0.05, 0.25, 1.00), 0.01
 	// )}));
 
@@ -69,13 +71,19 @@ fn main() {
 	let cube_grid = Arc::new(cube_grid);
 	let cube_model = Geometry::Grid(cube_grid);
 
-	scene.objects.push(Object {
+	scene.add(Object {
 		geometry: cube_model,
-		material: Material::Metal(Vector3::new(1.0, 1.0, 0.1), 0.15),
+		material: Material::Conductor(Conductor {
+			ior: ComplexIor::Metal(Metal::Gold),
+			roughness: 0.35,
+			anisotropic: 0.0,
+			anisotropic_rotation: 0.0,
+			thin_film: None,
+		}),
 	});
 
 	// // Floor
-	scene.objects.push(Object {
+	scene.add(Object {
 		geometry: Geometry::Plane(geometry::Plane {
 			origin: Vector3::new(0.0, -1.0, 0.0),
 			normal: Vector3::new(0.0, 1.0, 0.0),
@@ -84,16 +92,21 @@ fn main() {
 	});
 
 	// Ceiling
-	scene.objects.push(Object {
+	scene.add(Object {
 		geometry: Geometry::Plane(geometry::Plane {
 		origin: Vector3::new(0.0, 2.0, 0.0),
 		normal: Vector3::new(0.0, -1.0, 0.0),
 		}),
-		material: Material::Emission(Vector3::new(1.5, 1.5, 1.5), Vector3::new(1.0, 1.0, 1.0), 0.27, 0.0),
+		material: Material::Emission(Emission {
+			color: EmissionColor::Rgb(Vector3::new(1.0, 1.0, 1.0)),
+			texture: None,
+			strength: EmissionStrength::Radiance(1.5),
+			two_sided: false,
+		}),
 	});
 
 	// Frontwall
-	scene.objects.push(Object {
+	scene.add(Object {
 		geometry: Geometry::Plane(geometry::Plane {
 		origin: Vector3::new(0.0, 0.0, -2.0),
 		normal: Vector3::new(0.0, 0.0, 1.0),
@@ -102,7 +115,7 @@ fn main() {
 	});
 
 	// Backwall
-	scene.objects.push(Object  {
+	scene.add(Object  {
 		geometry: Geometry::Plane(geometry::Plane {
 		origin: Vector3::new(0.0, 0.0, 5.0),
 		normal: Vector3::new(0.0, 0.0, -1.0),
@@ -110,7 +123,7 @@ fn main() {
 		material: Material::Diffuse(Vector3::new(0.0, 0.0, 0.0), 0.9),
 	});
 	// left wall
-	scene.objects.push(Object  {
+	scene.add(Object  {
 		geometry: Geometry::Plane(geometry::Plane {
 		origin: Vector3::new(-2.0, 0.0, 0.0),
 		normal: Vector3::new(1.0, 0.0, 0.0),
@@ -118,7 +131,7 @@ fn main() {
 		material: Material::Diffuse(Vector3::new(0.0, 0.0, 0.0), 0.3),
 	});
 	// right wall
-	scene.objects.push(Object  {
+	scene.add(Object  {
 		geometry: Geometry::Plane(geometry::Plane {
 		origin: Vector3::new(2.0, 0.0, 0.0),
 		normal: Vector3::new(-1.0, 0.0, 0.0),
@@ -126,8 +139,8 @@ fn main() {
 		material: Material::Diffuse(Vector3::new(0.0, 0.0, 0.0), 0.3),
 	});
 
-	// scene.lights.push(Light { position: Vector3::new(0.0, 1.95, 2.5), intensity: Vector3::new(0.8, 0.8, 1.0) });
-	// scene.lights.push(Light { position: Vector3::new(1.75, -0.75, 1.0), intensity: Vector3::new(0.8, 1.0, 0.7) });
+	// scene.lights.push(Light::Point(PointLight { position: Vector3::new(0.0, 1.95, 2.5), color: EmissionColor::Rgb(Vector3::new(0.8, 0.8, 1.0)), intensity: 1.0, direction: Vector3::new(0.0, -1.0, 0.0), ies: None }));
+	// scene.lights.push(Light::Point(PointLight { position: Vector3::new(1.75, -0.75, 1.0), color: EmissionColor::Rgb(Vector3::new(0.8, 1.0, 0.7)), intensity: 1.0, direction: Vector3::new(0.0, -1.0, 0.0), ies: None }));
 	let HEIGHT = 340;
 	let WIDTH = HEIGHT / 9 * 16;
 
@@ -145,7 +158,7 @@ fn main() {
 		.camera_settings(camera)
 		.sample_count(500)
 		.tile_size((32, 32))
-		.bounce_limit(5)
+		.integrator(IntegratorSettings::PathTracer { roulette_depth: 3, bounce_limit: Some(5) })
 		.build()
 		.unwrap();
 
@@ -0,0 +1,218 @@
+//!
+//! Generates `core/src/material/albedo_tables.rs`, the directional albedo of single scattering
+//! microfacet BSDFs used for multiple scattering compensation, of the sheen lobe of cloth and
+//! of the diffuse lobes of the principled BSDF
+//!
+//! `cargo run --release -p core --bin albedo_tables`
+//!
+
+use std::{fmt::Write as _, fs};
+
+use core::{
+	material::{
+		bsdf::{Bsdf, MicrofacetReflection},
+		dielectric::MicrofacetDielectric,
+		fresnel::Fresnel,
+		microfacet::Ggx,
+		multiple_scattering::{table_alpha, table_cos, table_eta},
+		principled,
+		sheen::SheenBsdf,
+	},
+	math::prelude::*,
+	sampling,
+};
+
+const CONDUCTOR_RESOLUTION: usize = 32;
+const DIELECTRIC_RESOLUTION: usize = 16;
+const SHEEN_RESOLUTION: usize = 32;
+const DIFFUSE_RESOLUTION: usize = 32;
+/// Square root of the number of samples per table entry
+const STRATA: usize = 128;
+
+/// Estimates the albedo of a BSDF towards wo, `weight` scales each sample by its eta along the path
+fn albedo(bsdf: &dyn Bsdf, cos: f64, weight: impl Fn(Vector3) -> f64) -> f64 {
+	let wo = Vector3::new((1.0 - cos * cos).sqrt(), cos, 0.0);
+	let mut sum = 0.0;
+
+	for i in 0..STRATA {
+		for j in 0..STRATA {
+			let u = Vector2::new((i as f64 + 0.5) / STRATA as f64, (j as f64 + 0.5) / STRATA as f64);
+			// A golden ratio sequence for the lobe choice, decorrelated from the strata
+			let uc = ((i * STRATA + j) as f64 * 0.618_033_988_749_895).fract();
+
+			if let Some(sample) = bsdf.sample(wo, u, uc) {
+				sum += sample.f.x * sample.wi.y.abs() / sample.pdf * weight(sample.wi);
+			}
+		}
+	}
+
+	sum / (STRATA * STRATA) as f64
+}
+
+/// Estimates the albedo of a lobe over the upper hemisphere towards wo by cosine sampling it
+fn diffuse_albedo(f: impl Fn(Vector3, Vector3) -> f64, cos: f64) -> f64 {
+	let wo = Vector3::new((1.0 - cos * cos).sqrt(), cos, 0.0);
+	let mut sum = 0.0;
+
+	for i in 0..STRATA {
+		for j in 0..STRATA {
+			let u = Vector2::new((i as f64 + 0.5) / STRATA as f64, (j as f64 + 0.5) / STRATA as f64);
+			let wi = sampling::cosine_sample_hemisphere(u);
+			sum += f(wo, wi) * PI;
+		}
+	}
+
+	sum / (STRATA * STRATA) as f64
+}
+
+/// Cosine weighted average of a row of albedos over the hemisphere
+fn average(row: &[f64]) -> f64 {
+	let n = row.len();
+	row.iter().enumerate().map(|(c, e)| 2.0 * e * table_cos(c, n)).sum::<f64>() / n as f64
+}
+
+/// The albedos of GGX reflection with Schlick's Fresnel term for f0 of one, and their averages
+fn microfacet_tables(f0: f64) -> (Vec<f64>, Vec<f64>) {
+	let n = CONDUCTOR_RESOLUTION;
+	let mut albedos = Vec::with_capacity(n * n);
+	let mut averages = Vec::with_capacity(n);
+
+	for r in 0..n {
+		let bsdf = MicrofacetReflection::new(Ggx::new(table_alpha(r, n)), Fresnel::Schlick(Vector3::new(f0, f0, f0)));
+		let row: Vec<f64> = (0..n).map(|c| albedo(&bsdf, table_cos(c, n), |_| 1.0)).collect();
+
+		averages.push(average(&row));
+		albedos.extend(row);
+	}
+
+	(albedos, averages)
+}
+
+/// entering: wo lies outside, where the relative index of refraction of the far side is the table's eta
+fn dielectric_table(entering: bool) -> Vec<f64> {
+	let n = DIELECTRIC_RESOLUTION;
+	let mut albedos = Vec::with_capacity(n * n * n);
+
+	for e in 0..n {
+		let eta = table_eta(e, n);
+		let eta = if entering { eta } else { 1.0 / eta };
+		// Undo the radiance scaling of refracted light
+		let weight = |wi: Vector3| if wi.y < 0.0 { eta * eta } else { 1.0 };
+
+		for r in 0..n {
+			let bsdf = MicrofacetDielectric::new(Ggx::new(table_alpha(r, n)), eta, Vector3::new(1.0, 1.0, 1.0));
+			albedos.extend((0..n).map(|c| albedo(&bsdf, table_cos(c, n), weight)));
+		}
+	}
+
+	albedos
+}
+
+fn sheen_tables() -> (Vec<f64>, Vec<f64>) {
+	let n = SHEEN_RESOLUTION;
+	let mut albedos = Vec::with_capacity(n * n);
+	let mut averages = Vec::with_capacity(n);
+
+	for r in 0..n {
+		let bsdf = SheenBsdf::new(Vector3::new(1.0, 1.0, 1.0), table_alpha(r, n), Vector3::new(0.0, 0.0, 0.0));
+		let row: Vec<f64> = (0..n).map(|c| albedo(&bsdf, table_cos(c, n), |_| 1.0)).collect();
+
+		averages.push(average(&row));
+		albedos.extend(row);
+	}
+
+	(albedos, averages)
+}
+
+/// A table of a principled diffuse lobe, indexed by [roughness][cos]
+fn principled_table(f: fn(Vector3, Vector3, f64) -> f64) -> Vec<f64> {
+	let n = DIFFUSE_RESOLUTION;
+	let mut albedos = Vec::with_capacity(n * n);
+
+	for r in 0..n {
+		let roughness = table_alpha(r, n).sqrt();
+		albedos.extend((0..n).map(|c| diffuse_albedo(|wo, wi| f(wo, wi, roughness), table_cos(c, n))));
+	}
+
+	albedos
+}
+
+fn write_table(out: &mut String, doc: &str, name: &str, values: &[f64]) {
+	writeln!(out, "/// {}", doc).unwrap();
+	writeln!(out, "#[rustfmt::skip]").unwrap();
+	writeln!(out, "pub static {}: [f64; {}] = [", name, values.len()).unwrap();
+	for line in values.chunks(8) {
+		let line: Vec<String> = line.iter().map(|v| format!("{:.6}", v)).collect();
+		writeln!(out, "\t{},", line.join(", ")).unwrap();
+	}
+	writeln!(out, "];\n").unwrap();
+}
+
+fn main() {
+	let (conductor, conductor_average) = microfacet_tables(1.0);
+	let (schlick_weight, schlick_weight_average) = microfacet_tables(0.0);
+	let (sheen, sheen_average) = sheen_tables();
+
+	let mut out = String::new();
+	writeln!(out, "//! Generated by `cargo run --release -p core --bin albedo_tables`, don't edit by hand\n").unwrap();
+	writeln!(out, "// Measured values can land close to well known constants\n#![allow(clippy::approx_constant)]\n").unwrap();
+	writeln!(out, "pub const CONDUCTOR_RESOLUTION: usize = {};", CONDUCTOR_RESOLUTION).unwrap();
+	writeln!(out, "pub const DIELECTRIC_RESOLUTION: usize = {};", DIELECTRIC_RESOLUTION).unwrap();
+	writeln!(out, "pub const SHEEN_RESOLUTION: usize = {};", SHEEN_RESOLUTION).unwrap();
+	writeln!(out, "pub const DIFFUSE_RESOLUTION: usize = {};\n", DIFFUSE_RESOLUTION).unwrap();
+	write_table(
+		&mut out,
+		"Albedo of GGX reflection with a Fresnel term of one, indexed by [roughness][cos]",
+		"CONDUCTOR_ALBEDO",
+		&conductor,
+	);
+	write_table(&mut out, "Cosine weighted average of each row of CONDUCTOR_ALBEDO", "CONDUCTOR_AVERAGE_ALBEDO", &conductor_average);
+	write_table(
+		&mut out,
+		"Albedo of GGX reflection with Schlick's Fresnel term for f0 = 0, indexed by [roughness][cos]",
+		"SCHLICK_WEIGHT_ALBEDO",
+		&schlick_weight,
+	);
+	write_table(
+		&mut out,
+		"Cosine weighted average of each row of SCHLICK_WEIGHT_ALBEDO",
+		"SCHLICK_WEIGHT_AVERAGE_ALBEDO",
+		&schlick_weight_average,
+	);
+	write_table(
+		&mut out,
+		"Albedo of a rough dielectric seen from the side with the lower index of refraction, indexed by [eta][roughness][cos]",
+		"DIELECTRIC_ALBEDO_ENTERING",
+		&dielectric_table(true),
+	);
+	write_table(
+		&mut out,
+		"Albedo of a rough dielectric seen from the side with the higher index of refraction, indexed by [eta][roughness][cos]",
+		"DIELECTRIC_ALBEDO_EXITING",
+		&dielectric_table(false),
+	);
+	write_table(&mut out, "Albedo of a white sheen lobe, indexed by [roughness][cos]", "SHEEN_ALBEDO", &sheen);
+	write_table(&mut out, "Cosine weighted average of each row of SHEEN_ALBEDO", "SHEEN_AVERAGE_ALBEDO", &sheen_average);
+	write_table(
+		&mut out,
+		"Albedo of the white principled Burley diffuse, indexed by [roughness][cos]",
+		"PRINCIPLED_DIFFUSE_ALBEDO",
+		&principled_table(principled::burley_diffuse),
+	);
+	write_table(
+		&mut out,
+		"Albedo of the white principled subsurface approximation, indexed by [roughness][cos]",
+		"PRINCIPLED_SUBSURFACE_ALBEDO",
+		&principled_table(principled::subsurface_diffuse),
+	);
+	write_table(
+		&mut out,
+		"Albedo of the principled sheen for a sheen color of one, indexed by [cos]",
+		"PRINCIPLED_SHEEN_ALBEDO",
+		&(0..DIFFUSE_RESOLUTION).map(|c| diffuse_albedo(principled::sheen_weight, table_cos(c, DIFFUSE_RESOLUTION))).collect::<Vec<_>>(),
+	);
+
+	let path = concat!(env!("CARGO_MANIFEST_DIR"), "/src/material/albedo_tables.rs");
+	fs::write(path, out.trim_end().to_string() + "\n").expect("failed to write the albedo tables");
+	println!("wrote {}", path);
+}
@@ -0,0 +1,74 @@
+use crate::math::prelude::*;
+
+/// Wavelength range in nanometers over which spectra are integrated
+const VISIBLE_RANGE: (f64, f64) = (380.0, 780.0);
+const SPECTRUM_STEPS: usize = 80;
+
+///
+/// The CIE 1931 color matching functions
+/// Multi-lobe fit from Wyman et al. 2013, "Simple Analytic Approximations to the CIE XYZ Color Matching Functions"
+///
+pub fn cie_xyz(wavelength: f64) -> Vector3 {
+	let g = |mu: f64, sigma_below: f64, sigma_above: f64| {
+		let t = (wavelength - mu) / if wavelength < mu { sigma_below } else { sigma_above };
+		(-0.5 * t * t).exp()
+	};
+
+	Vector3::new(
+		1.056 * g(599.8, 37.9, 31.0) + 0.362 * g(442.0, 16.0, 26.7) - 0.065 * g(501.1, 20.4, 26.2),
+		0.821 * g(568.8, 46.9, 40.5) + 0.286 * g(530.9, 16.3, 31.1),
+		1.217 * g(437.0, 11.8, 36.0) + 0.681 * g(459.0, 26.0, 13.8),
+	)
+}
+
+/// Converts CIE XYZ to linear sRGB (Rec. 709 primaries, D65 white point)
+pub fn xyz_to_rgb(xyz: Vector3) -> Vector3 {
+	Vector3::new(
+		3.2404542 * xyz.x - 1.5371385 * xyz.y - 0.4985314 * xyz.z,
+		-0.9692660 * xyz.x + 1.8760108 * xyz.y + 0.0415560 * xyz.z,
+		0.0556434 * xyz.x - 0.2040259 * xyz.y + 1.0572252 * xyz.z,
+	)
+}
+
+/// Decodes a value stored with the sRGB transfer curve, like the channels of 8 bit color images
+pub fn srgb_to_linear(value: f64) -> f64 {
+	if value <= 0.04045 {
+		value / 12.92
+	} else {
+		((value + 0.055) / 1.055).powf(2.4)
+	}
+}
+
+/// Spectral radiance of a black body at the given temperature in kelvin, wavelength in nanometers
+pub fn planck(wavelength: f64, temperature: f64) -> f64 {
+	const H: f64 = 6.62607015e-34;
+	const C: f64 = 2.99792458e8;
+	const K: f64 = 1.380649e-23;
+
+	let l = wavelength * 1e-9;
+	2.0 * H * C * C / (l.powi(5) * ((H * C / (l * K * temperature)).exp() - 1.0))
+}
+
+///
+/// The linear RGB color of a black body radiator, with a luminance of one
+///
+/// Out of gamut components are clamped, temperatures below ~1000K are deep red.
+///
+pub fn blackbody(temperature: f64) -> Vector3 {
+	let temperature = temperature.max(1.0);
+	let (start, end) = VISIBLE_RANGE;
+	let step = (end - start) / SPECTRUM_STEPS as f64;
+
+	let xyz = (0..SPECTRUM_STEPS)
+		.map(|i| {
+			let wavelength = start + (i as f64 + 0.5) * step;
+			cie_xyz(wavelength) * planck(wavelength, temperature)
+		})
+		.fold(Vector3::new(0.0, 0.0, 0.0), |sum, xyz| sum + xyz);
+	if xyz.y.is_nan() || xyz.y <= 0.0 {
+		return Vector3::new(0.0, 0.0, 0.0);
+	}
+
+	let rgb = xyz_to_rgb(xyz / xyz.y);
+	Vector3::new(rgb.x.max(0.0), rgb.y.max(0.0), rgb.z.max(0.0))
+}
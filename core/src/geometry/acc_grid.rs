@@ -30,6 +30,8 @@ pub struct AccGrid {
 	pub mapping_table: Vec<usize>,
 	pub resolution: cgmath::Vector3<usize>,
 	pub cell_size: Vector3,
+	/// Total area of the mesh's triangles, computed once on construction
+	pub surface_area: f64,
 }
 
 impl AccGrid {
@@ -75,6 +77,7 @@ impl AccGrid {
 
 		AccGrid {
 			cells,
+			surface_area: mesh.surface_area(),
 			mesh,
 			mapping_table,
 			resolution: grid_res,
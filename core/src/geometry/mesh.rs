@@ -45,6 +45,10 @@ impl Mesh {
 		self.triangles[hit.subobject_index].get_surface_properties(hit)
 	}
 
+	pub fn surface_area(&self) -> f64 {
+		self.triangles.iter().map(Triangle::area).sum()
+	}
+
 	pub fn bake_transform(&mut self, translate: Vector3) {
 		for mut triangle in self.triangles.iter_mut() {
 			triangle.0.position += translate;
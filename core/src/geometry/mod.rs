@@ -6,7 +6,15 @@ use crate::math::prelude::*;
 
 #[derive(Clone, Debug)]
 pub struct SurfaceProperties {
+	/// The (interpolated) shading normal
 	pub normal: Vector3,
+	/// The true normal of the surface, used to decide which side of the surface a direction lies on
+	pub geometric_normal: Vector3,
+	pub uv: Vector2,
+	/// Direction of increasing u, not necessarily orthogonal to the normal
+	pub tangent: Vector3,
+	/// 1 if `normal × tangent` points towards increasing v, -1 for mirrored parametrizations
+	pub handedness: f64,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -56,5 +64,5 @@ pub trait Traceable: Intersect {
 
 
 pub use self::{
-	primitives::{AABB, Sphere, Plane, Triangle, Vertex}, mesh::Mesh, acc_grid::AccGrid
+	primitives::{AABB, Sphere, Plane, Quad, Triangle, Vertex}, mesh::Mesh, acc_grid::AccGrid
 };
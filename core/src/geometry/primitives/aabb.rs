@@ -1,11 +1,49 @@
 use crate::prelude::*;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug)]
 pub struct AABB {
 	pub min: Vector3,
 	pub max: Vector3,
 }
 
+impl AABB {
+	/// Contains nothing, the union with any box is that box
+	pub fn empty() -> AABB {
+		AABB {
+			min: Vector3::new(F_MAX, F_MAX, F_MAX),
+			max: Vector3::new(-F_MAX, -F_MAX, -F_MAX),
+		}
+	}
+
+	pub fn point(point: Vector3) -> AABB {
+		AABB { min: point, max: point }
+	}
+
+	pub fn union(&self, other: &AABB) -> AABB {
+		AABB {
+			min: Vector3::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y), self.min.z.min(other.min.z)),
+			max: Vector3::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y), self.max.z.max(other.max.z)),
+		}
+	}
+
+	pub fn center(&self) -> Vector3 {
+		(self.min + self.max) / 2.0
+	}
+
+	pub fn diagonal(&self) -> Vector3 {
+		self.max - self.min
+	}
+
+	pub fn contains(&self, point: Vector3) -> bool {
+		(0..3).all(|i| point[i] >= self.min[i] && point[i] <= self.max[i])
+	}
+
+	pub fn surface_area(&self) -> f64 {
+		let d = self.diagonal();
+		2.0 * (d.x * d.y + d.y * d.z + d.z * d.x)
+	}
+}
+
 impl Intersect for AABB {
 	fn intersects(&self, ray: Ray) -> Option<Hit> {
 		let inverse_ray_dir = 1.0 / ray.direction;
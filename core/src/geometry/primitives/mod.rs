@@ -1,5 +1,6 @@
 mod aabb;
 mod plane;
+mod quad;
 mod sphere;
 mod triangle;
 mod vertex;
@@ -7,6 +8,7 @@ mod vertex;
 pub use self::{
 	aabb::AABB,
 	plane::Plane,
+	quad::Quad,
 	sphere::Sphere,
 	triangle::Triangle,
 	vertex::Vertex,
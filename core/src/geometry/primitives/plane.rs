@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::math::create_coordinate_system_of_n;
 use serde::{Serialize, Deserialize};
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
@@ -26,8 +27,17 @@ impl Intersect for Plane {
 
 impl Plane {
 	pub fn get_surface_properties(&self, hit: Hit) -> SurfaceProperties {
+		// Planes have no natural parametrization, so the uv's are just the hit position
+		// projected onto an arbitrary basis in the plane, one world unit per texture repeat
+		let (tangent, bitangent) = create_coordinate_system_of_n(self.normal);
+		let local = (hit.ray.origin + hit.ray.direction * hit.distance) - self.origin;
+
 		SurfaceProperties {
 			normal: self.normal,
+			geometric_normal: self.normal,
+			uv: Vector2::new(local.dot(tangent), local.dot(bitangent)),
+			tangent,
+			handedness: 1.0,
 		}
 	}
 }
@@ -0,0 +1,70 @@
+use crate::prelude::*;
+use serde::{Serialize, Deserialize};
+
+///
+/// A parallelogram, spanned by two edges from one of its corners
+///
+/// Its normal is `edge_u × edge_v`, which one-sided emitters emit along. Unlike planes, quads
+/// are finite, so emissive quads make area lights that can be sampled.
+///
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct Quad {
+	pub origin: Vector3,
+	pub edge_u: Vector3,
+	pub edge_v: Vector3,
+}
+
+impl Intersect for Quad {
+	fn intersects(&self, ray: Ray) -> Option<Hit> {
+		let normal = self.edge_u.cross(self.edge_v);
+		let denom = normal.dot(ray.direction);
+		if denom.abs() < 1e-12 {
+			return None;
+		}
+
+		let t = normal.dot(self.origin - ray.origin) / denom;
+		if t <= 1e-8 {
+			return None;
+		}
+
+		let (u, v) = self.coordinates(ray.origin + ray.direction * t);
+		if !(0.0..=1.0).contains(&u) || !(0.0..=1.0).contains(&v) {
+			return None;
+		}
+
+		Some(Hit::new(ray, t))
+	}
+}
+
+impl Quad {
+	/// Position of a point in the quad's plane along both edges, the quad spans [0, 1]²
+	fn coordinates(&self, position: Vector3) -> (f64, f64) {
+		let normal = self.edge_u.cross(self.edge_v);
+		let local = position - self.origin;
+		let n2 = normal.magnitude2();
+
+		(local.cross(self.edge_v).dot(normal) / n2, self.edge_u.cross(local).dot(normal) / n2)
+	}
+
+	pub fn surface_area(&self) -> f64 {
+		self.edge_u.cross(self.edge_v).magnitude()
+	}
+
+	/// A point uniformly distributed over the surface
+	pub fn sample_point(&self, u: Vector2) -> Vector3 {
+		self.origin + self.edge_u * u.x + self.edge_v * u.y
+	}
+
+	pub fn get_surface_properties(&self, hit: Hit) -> SurfaceProperties {
+		let normal = self.edge_u.cross(self.edge_v).normalize();
+		let (u, v) = self.coordinates(hit.ray.origin + hit.ray.direction * hit.distance);
+
+		SurfaceProperties {
+			normal,
+			geometric_normal: normal,
+			uv: Vector2::new(u, v),
+			tangent: self.edge_u.normalize(),
+			handedness: 1.0,
+		}
+	}
+}
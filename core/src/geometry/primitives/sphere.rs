@@ -1,4 +1,4 @@
-use crate::prelude::*;
+use crate::{prelude::*, sampling};
 use serde::{Serialize, Deserialize};
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
@@ -10,7 +10,7 @@ pub struct Sphere {
 impl Intersect for Sphere {
 	fn intersects(&self, ray: Ray) -> Option<Hit> {
 		let c = self.origin - ray.origin;
-		let mut t = c.dot(ray.direction);
+		let t = c.dot(ray.direction);
 		let q = c - t * ray.direction;
 		let p = q.dot(q);
 
@@ -18,7 +18,9 @@ impl Intersect for Sphere {
 			return None;
 		}
 
-		t -= (self.radius * self.radius - p).sqrt();
+		// Rays starting inside the sphere hit the far side
+		let offset = (self.radius * self.radius - p).sqrt();
+		let t = if t - offset > 0.0 { t - offset } else { t + offset };
 		if t <= 0.0 {
 			return None;
 		}
@@ -28,9 +30,31 @@ impl Intersect for Sphere {
 }
 
 impl Sphere {
+	pub fn surface_area(&self) -> f64 {
+		4.0 * PI * self.radius * self.radius
+	}
+
+	/// A point uniformly distributed over the surface
+	pub fn sample_point(&self, u: Vector2) -> Vector3 {
+		self.origin + sampling::uniform_sample_sphere(u) * self.radius
+	}
+
 	pub fn get_surface_properties(&self, hit: Hit) -> SurfaceProperties {
+		let normal = ((hit.ray.origin + hit.ray.direction * hit.distance) - self.origin).normalize();
+
+		// Spherical coordinates around the y axis, u wraps around the equator, v goes from pole to pole
+		let phi = normal.z.atan2(normal.x);
+		let theta = normal.y.clamp(-1.0, 1.0).acos();
+		let uv = Vector2::new(phi / (2.0 * PI) + 0.5, 1.0 - theta / PI);
+		let tangent = Vector3::new(-normal.z, 0.0, normal.x);
+
 		SurfaceProperties {
-			normal: ((hit.ray.origin + hit.ray.direction * hit.distance) - self.origin).normalize(),
+			normal,
+			geometric_normal: normal,
+			uv,
+			tangent,
+			// u runs east and v north, which is clockwise seen from outside
+			handedness: -1.0,
 		}
 	}
 }
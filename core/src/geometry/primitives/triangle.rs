@@ -44,6 +44,18 @@ impl Intersect for Triangle {
 	}
 }
 impl Triangle {
+	pub fn area(&self) -> f64 {
+		(self.1.position - self.0.position).cross(self.2.position - self.0.position).magnitude() / 2.0
+	}
+
+	/// A point uniformly distributed over the triangle
+	pub fn sample_point(&self, u: Vector2) -> Vector3 {
+		let su = u.x.sqrt();
+		let (b0, b1) = (1.0 - su, u.y * su);
+
+		self.0.position * b0 + self.1.position * b1 + self.2.position * (1.0 - b0 - b1)
+	}
+
 	pub fn get_surface_properties(&self, hit: Hit) -> SurfaceProperties {
 		fn area(a: Vector3, b: Vector3, c: Vector3) -> f64 {
 			let ab = a.distance(b);
@@ -60,10 +72,26 @@ impl Triangle {
 		let ba = abp / abc;
 		let bb = bcp / abc;
 		let bc = 1.0 - (ba + bb);
-		let normal = (self.2.normal * ba) + (self.1.normal * bb) + (self.0.normal * bc);
+		let normal = ((self.2.normal * ba) + (self.1.normal * bb) + (self.0.normal * bc)).normalize();
+		let uv = (self.2.uv * ba) + (self.1.uv * bb) + (self.0.uv * bc);
+		let tangent = (self.2.tangent * ba) + (self.1.tangent * bb) + (self.0.tangent * bc);
+
+		// The winding order of the input isn't reliable, so orient the face normal after the vertex normals
+		let face_normal = (self.1.position - self.0.position).cross(self.2.position - self.0.position).normalize();
+		let flipped = face_normal.dot(normal) < 0.0;
+		let geometric_normal = if flipped { -face_normal } else { face_normal };
+
+		// The uv's wind the other way than the positions where the texture is mirrored
+		let (uv1, uv2) = (self.1.uv - self.0.uv, self.2.uv - self.0.uv);
+		let mirrored = uv1.x * uv2.y - uv2.x * uv1.y < 0.0;
+		let handedness = if mirrored != flipped { -1.0 } else { 1.0 };
 
 		return SurfaceProperties {
-			normal: normal.normalize(),
+			normal,
+			geometric_normal,
+			uv,
+			tangent,
+			handedness,
 		};
 	}
 
@@ -0,0 +1,353 @@
+//!
+//! Photometric profiles in the IESNA LM-63 format, the `.ies` files luminaire makers publish
+//!
+//! Only type C photometry is supported, which is what nearly all architectural luminaires use.
+//!
+
+use std::{convert::TryFrom, error, fmt, fs, io, path::PathBuf, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{math::prelude::*, project};
+
+#[derive(Debug)]
+pub enum IesError {
+	Io(io::Error),
+	/// The file ended or had something other than a number where the data was expected
+	Malformed(String),
+	/// Type A and B photometry, used for floodlights and vehicle lighting
+	UnsupportedPhotometry(u32),
+}
+
+impl fmt::Display for IesError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			IesError::Io(error) => write!(f, "couldn't read IES profile: {}", error),
+			IesError::Malformed(reason) => write!(f, "malformed IES profile: {}", reason),
+			IesError::UnsupportedPhotometry(kind) => {
+				write!(f, "IES profile uses photometric type {}, only type C (1) is supported", kind)
+			}
+		}
+	}
+}
+
+impl error::Error for IesError {}
+
+impl From<io::Error> for IesError {
+	fn from(error: io::Error) -> IesError {
+		IesError::Io(error)
+	}
+}
+
+#[derive(Debug)]
+struct Candela {
+	/// Degrees from straight down (nadir), ascending
+	vertical_angles: Vec<f64>,
+	/// Degrees around the vertical axis, ascending, the planes the candela values are measured in
+	horizontal_angles: Vec<f64>,
+	/// Indexed by [horizontal][vertical], scaled to a peak of one
+	values: Vec<Vec<f64>>,
+	/// Mean of the values over the sphere of directions
+	average: f64,
+}
+
+///
+/// How the intensity of a luminaire varies with direction
+///
+/// Values are relative to the brightest direction, the lights using a profile set the actual
+/// intensity. In the project file a profile is just the path to the `.ies` file, relative to the
+/// project file.
+///
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(try_from = "PathBuf", into = "PathBuf")]
+pub struct IesProfile {
+	pub path: PathBuf,
+	candela: Arc<Candela>,
+}
+
+/// Splits the photometric data after the `TILT=` line into numbers
+fn numbers(text: &str) -> impl Iterator<Item = Result<f64, IesError>> + '_ {
+	text.split(|c: char| c.is_whitespace() || c == ',').filter(|token| !token.is_empty()).map(|token| {
+		token.parse::<f64>().map_err(|_| IesError::Malformed(format!("expected a number, found '{}'", token)))
+	})
+}
+
+impl IesProfile {
+	pub fn load(path: PathBuf) -> Result<IesProfile, IesError> {
+		let candela = IesProfile::parse(&fs::read_to_string(&path)?)?;
+
+		Ok(IesProfile {
+			path,
+			candela: Arc::new(candela),
+		})
+	}
+
+	fn parse(text: &str) -> Result<Candela, IesError> {
+		// Keyword lines like [MANUFAC] come first, the photometric data follows the TILT line
+		let tilt_start = text.find("TILT=").ok_or_else(|| IesError::Malformed("missing TILT line".to_string()))?;
+		let tilt_end = text[tilt_start..].find('\n').map_or(text.len(), |end| tilt_start + end);
+		let tilt = text[tilt_start + "TILT=".len()..tilt_end].trim();
+
+		let mut values = numbers(&text[tilt_end..]);
+		let mut next = || values.next().unwrap_or_else(|| Err(IesError::Malformed("unexpected end of file".to_string())));
+
+		// Lamp tilt factors change the output of lamps mounted at an angle, they're ignored
+		if tilt == "INCLUDE" {
+			next()?;
+			let tilt_angles = next()? as usize;
+			for _ in 0..2 * tilt_angles {
+				next()?;
+			}
+		}
+
+		let _lamp_count = next()?;
+		let _lumens_per_lamp = next()?;
+		let _multiplier = next()?;
+		let vertical_count = next()? as usize;
+		let horizontal_count = next()? as usize;
+		let photometric_type = next()? as u32;
+		let _units = next()?;
+		let _size = (next()?, next()?, next()?);
+		let _ballast_factor = next()?;
+		let _ballast_lamp_factor = next()?;
+		let _input_watts = next()?;
+		if photometric_type != 1 {
+			return Err(IesError::UnsupportedPhotometry(photometric_type));
+		}
+		if vertical_count == 0 || horizontal_count == 0 {
+			return Err(IesError::Malformed("no angles".to_string()));
+		}
+
+		let vertical_angles = (0..vertical_count).map(|_| next()).collect::<Result<Vec<_>, _>>()?;
+		let horizontal_angles = (0..horizontal_count).map(|_| next()).collect::<Result<Vec<_>, _>>()?;
+		let mut values = (0..horizontal_count)
+			.map(|_| (0..vertical_count).map(|_| next()).collect::<Result<Vec<_>, _>>())
+			.collect::<Result<Vec<_>, _>>()?;
+
+		let ascending = |angles: &[f64]| angles.windows(2).all(|pair| pair[0] < pair[1]);
+		if !ascending(&vertical_angles) || !ascending(&horizontal_angles) {
+			return Err(IesError::Malformed("angles aren't in ascending order".to_string()));
+		}
+
+		// The multipliers and the lamp output only scale the whole profile, which is normalized anyway
+		let peak = values.iter().flatten().fold(0.0_f64, |peak, &value| peak.max(value));
+		if peak <= 0.0 {
+			return Err(IesError::Malformed("the luminaire emits no light".to_string()));
+		}
+		for value in values.iter_mut().flatten() {
+			*value /= peak;
+		}
+
+		let mut candela = Candela {
+			vertical_angles,
+			horizontal_angles,
+			values,
+			average: 0.0,
+		};
+		candela.average = candela.average();
+
+		Ok(candela)
+	}
+
+	/// Intensity towards a direction relative to the peak, in a frame with the nadir along y and the 0° plane along x
+	pub fn intensity(&self, local: Vector3) -> f64 {
+		let vertical = local.y.clamp(-1.0, 1.0).acos().to_degrees();
+		let horizontal = local.z.atan2(local.x).to_degrees().rem_euclid(360.0);
+
+		self.candela.eval(vertical, horizontal)
+	}
+
+	/// Mean relative intensity over all directions, the fraction of a point light's power the profile lets through
+	pub fn average(&self) -> f64 {
+		self.candela.average
+	}
+}
+
+/// Linear interpolation in a table of ascending angles, None outside of it
+fn interpolate(angles: &[f64], angle: f64) -> Option<(usize, usize, f64)> {
+	let last = angles.len() - 1;
+	if angle < angles[0] || angle > angles[last] {
+		return None;
+	}
+	if last == 0 {
+		return Some((0, 0, 0.0));
+	}
+
+	let upper = angles.partition_point(|&a| a <= angle).max(1).min(last);
+	let t = (angle - angles[upper - 1]) / (angles[upper] - angles[upper - 1]);
+	Some((upper - 1, upper, t))
+}
+
+impl Candela {
+	fn eval(&self, vertical: f64, horizontal: f64) -> f64 {
+		let (v0, v1, tv) = match interpolate(&self.vertical_angles, vertical) {
+			Some(segment) => segment,
+			None => return 0.0,
+		};
+
+		// Most profiles only cover part of the circle and are symmetric in the rest
+		let first = self.horizontal_angles[0];
+		let last = self.horizontal_angles[self.horizontal_angles.len() - 1];
+		let horizontal = match (first as i32, last as i32) {
+			(_, 0) => 0.0,
+			(0, 90) => {
+				let h = if horizontal > 180.0 { 360.0 - horizontal } else { horizontal };
+				if h > 90.0 { 180.0 - h } else { h }
+			}
+			(0, 180) => {
+				if horizontal > 180.0 { 360.0 - horizontal } else { horizontal }
+			}
+			(90, 270) => {
+				if !(90.0..=270.0).contains(&horizontal) { (540.0 - horizontal) % 360.0 } else { horizontal }
+			}
+			_ => horizontal,
+		};
+		let (h0, h1, th) = match interpolate(&self.horizontal_angles, horizontal) {
+			Some(segment) => segment,
+			// Full circles wrap around between the last and the first plane
+			None if last - first > 180.0 => (self.horizontal_angles.len() - 1, 0, {
+				let gap = first + 360.0 - last;
+				(horizontal - last).rem_euclid(360.0) / gap
+			}),
+			None => return 0.0,
+		};
+
+		let at = |h: usize| self.values[h][v0] * (1.0 - tv) + self.values[h][v1] * tv;
+		at(h0) * (1.0 - th) + at(h1) * th
+	}
+
+	/// Integrates the profile over the sphere of directions
+	fn average(&self) -> f64 {
+		const STEPS: usize = 90;
+		let mut sum = 0.0;
+		for i in 0..STEPS {
+			let vertical = 180.0 * (i as f64 + 0.5) / STEPS as f64;
+			let weight = vertical.to_radians().sin();
+			for j in 0..2 * STEPS {
+				let horizontal = 360.0 * (j as f64 + 0.5) / (2 * STEPS) as f64;
+				sum += self.eval(vertical, horizontal) * weight;
+			}
+		}
+
+		// Both angles advance by pi / STEPS, and the sphere covers 4 pi
+		sum * (PI / STEPS as f64).powi(2) / (4.0 * PI)
+	}
+}
+
+impl TryFrom<PathBuf> for IesProfile {
+	type Error = IesError;
+
+	fn try_from(path: PathBuf) -> Result<Self, Self::Error> {
+		// Keep the path as written, so the profile is written back the same way
+		let profile = IesProfile::load(project::resolve_asset(&path))?;
+		Ok(IesProfile { path, ..profile })
+	}
+}
+
+impl From<IesProfile> for PathBuf {
+	fn from(profile: IesProfile) -> PathBuf {
+		profile.path
+	}
+}
+
+impl fmt::Debug for IesProfile {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "IesProfile {{ path: {:?} }}", self.path)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Candela values in the planes of a profile, the same for every vertical angle
+	fn planes(horizontal: &str, values: &[f64]) -> Candela {
+		let count = values.len();
+		let values: Vec<String> = values.iter().map(|value| format!("{} {} {}", value, value, value)).collect();
+		let text = format!(
+			"IESNA:LM-63-2002\n[TEST] planes\nTILT=NONE\n1 1000 1 3 {} 1 2 0 0 0\n1 1 100\n0 90 180\n{}\n{}\n",
+			count,
+			horizontal,
+			values.join("\n")
+		);
+
+		IesProfile::parse(&text).unwrap()
+	}
+
+	fn assert_close(actual: f64, expected: f64) {
+		assert!((actual - expected).abs() < 1e-9, "{} isn't {}", actual, expected);
+	}
+
+	#[test]
+	fn isotropic() {
+		let candela = IesProfile::parse("IESNA:LM-63-2002\nTILT=NONE\n1 1000 1 3 1 1 2 0 0 0\n1 1 100\n0 90 180\n0\n250 250 250\n").unwrap();
+
+		for &(vertical, horizontal) in &[(0.0, 0.0), (45.0, 90.0), (120.0, 200.0), (180.0, 359.0)] {
+			assert_close(candela.eval(vertical, horizontal), 1.0);
+		}
+		assert!((candela.average - 1.0).abs() < 1e-3, "average {}", candela.average);
+	}
+
+	#[test]
+	fn quadrant_symmetry() {
+		let candela = planes("0 90", &[100.0, 50.0]);
+
+		assert_close(candela.eval(90.0, 45.0), 0.75);
+		assert_close(candela.eval(90.0, 135.0), 0.75);
+		assert_close(candela.eval(90.0, 180.0), 1.0);
+		assert_close(candela.eval(90.0, 270.0), 0.5);
+		assert_close(candela.eval(90.0, 315.0), 0.75);
+	}
+
+	#[test]
+	fn bilateral_symmetry_about_0_plane() {
+		let candela = planes("0 90 180", &[100.0, 50.0, 0.0]);
+
+		assert_close(candela.eval(90.0, 135.0), 0.25);
+		assert_close(candela.eval(90.0, 225.0), 0.25);
+		assert_close(candela.eval(90.0, 270.0), 0.5);
+		assert_close(candela.eval(90.0, 315.0), 0.75);
+	}
+
+	#[test]
+	fn bilateral_symmetry_about_90_plane() {
+		let candela = planes("90 180 270", &[100.0, 50.0, 0.0]);
+
+		assert_close(candela.eval(90.0, 135.0), 0.75);
+		assert_close(candela.eval(90.0, 45.0), 0.75);
+		assert_close(candela.eval(90.0, 0.0), 0.5);
+		assert_close(candela.eval(90.0, 315.0), 0.25);
+	}
+
+	#[test]
+	fn full_circle_wraps_around() {
+		let candela = planes("0 120 240", &[100.0, 40.0, 0.0]);
+
+		assert_close(candela.eval(90.0, 60.0), 0.7);
+		assert_close(candela.eval(90.0, 300.0), 0.5);
+		assert_close(candela.eval(90.0, 359.0), 1.0 - 1.0 / 120.0);
+	}
+
+	#[test]
+	fn tilt_include() {
+		let candela = IesProfile::parse(
+			"IESNA:LM-63-2002\nTILT=INCLUDE\n1\n3\n0 45 90\n1 0.9 0.8\n1 1000 1 2 1 1 2 0 0 0\n1 1 100\n0 180\n0\n80 40\n",
+		)
+		.unwrap();
+
+		assert_eq!(candela.vertical_angles, vec![0.0, 180.0]);
+		assert_close(candela.eval(0.0, 0.0), 1.0);
+		assert_close(candela.eval(90.0, 0.0), 0.75);
+	}
+
+	#[test]
+	fn rejects_type_a_and_b() {
+		for photometric_type in 2..=3 {
+			let text = format!("IESNA:LM-63-2002\nTILT=NONE\n1 1000 1 2 1 {} 2 0 0 0\n1 1 100\n0 90\n0\n100 100\n", photometric_type);
+			match IesProfile::parse(&text) {
+				Err(IesError::UnsupportedPhotometry(kind)) => assert_eq!(kind, photometric_type),
+				result => panic!("expected type {} to be rejected, got {:?}", photometric_type, result),
+			}
+		}
+	}
+}
@@ -0,0 +1,51 @@
+//!
+//! Which algorithm renders an image and its parameters, as chosen in the project file
+//!
+//! The integrators themselves are part of the raytracer crate.
+//!
+
+use serde::{Deserialize, Serialize};
+
+use crate::math::prelude::*;
+
+///
+/// ```json
+/// "integrator": { "PathTracer": { "roulette_depth": 3 } }
+/// ```
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum IntegratorSettings {
+	/// Unidirectional path tracing, sampling the lights at every vertex
+	PathTracer {
+		/// Vertices that are always traced before Russian roulette starts ending paths
+		#[serde(default = "default_roulette_depth")]
+		roulette_depth: usize,
+		/// Paths end after this many vertices, which biases the result
+		/// Unlimited by default, Russian roulette ends paths without bias
+		#[serde(default)]
+		bounce_limit: Option<usize>,
+	},
+	/// How much of the hemisphere above the first hit is open, ignoring materials and lights
+	AmbientOcclusion {
+		/// Geometry further away than this doesn't occlude
+		#[serde(default = "unlimited")]
+		distance: f64,
+	},
+}
+
+fn default_roulette_depth() -> usize {
+	3
+}
+
+fn unlimited() -> f64 {
+	F_MAX
+}
+
+impl Default for IntegratorSettings {
+	fn default() -> IntegratorSettings {
+		IntegratorSettings::PathTracer {
+			roulette_depth: default_roulette_depth(),
+			bounce_limit: None,
+		}
+	}
+}
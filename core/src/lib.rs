@@ -1,7 +1,5 @@
 /* Core Type Definitions */
 
-use serde::{Serialize, Deserialize};
-
 pub type Vector3 = cgmath::Vector3<f64>;
 pub type Vector2 = cgmath::Vector2<f64>;
 
@@ -10,6 +8,18 @@ pub mod project;
 pub mod scene;
 pub mod tile;
 pub mod math;
+pub mod texture;
+pub mod ies;
+pub mod shading;
+pub mod sampling;
+pub mod material;
+pub mod color;
+pub mod light;
+pub mod light_tree;
+pub mod sky;
+pub mod integrator;
+
+pub use material::Material;
 
 pub mod prelude {
 	pub use super::{
@@ -17,15 +27,3 @@ pub mod prelude {
 			Hit, Intersect, Ray, SurfaceProperties, Traceable, Vertex
 	 }, Material, math::prelude::*};
 }
-
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
-pub enum Material {
-	Diffuse(Vector3, f64),
-	Metal(Vector3, f64),
-	Emission(Vector3, Vector3, f64, f64),
-}
-
-
-
-
-
@@ -0,0 +1,639 @@
+//!
+//! Lights the integrator samples directly
+//!
+//! Emissive objects are collected into `Scene::emitters` as the scene is built, next to the
+//! point, spot, directional, environment and sky lights of `Scene::lights`. At every path vertex
+//! one of them is picked by the `light_tree` and connected with a shadow ray, instead of waiting
+//! for a BSDF sample to hit it by chance.
+//!
+
+use std::{fmt, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	geometry::{Hit, Ray, Sphere, SurfaceProperties, Triangle, AABB},
+	light_tree::{DirectionCone, LightBounds},
+	material::{Emission, EmissionColor, Material},
+	math::{luminance, prelude::*},
+	sampling::{self, AliasTable, Distribution2D},
+	scene::{Geometry, Object},
+	ies::IesProfile,
+	shading::Frame,
+	sky::SkyLight,
+	texture::Texture,
+};
+
+/// A point on a light, as seen from a shading point
+#[derive(Debug, Clone, Copy)]
+pub struct LightSample {
+	/// Normalized direction from the shading point towards the light
+	pub direction: Vector3,
+	/// Distance to the light along `direction`, infinite for directional lights
+	pub distance: f64,
+	/// Radiance arriving at the shading point if nothing is in between
+	/// For lights without a size this is the irradiance on a surface facing the light
+	pub radiance: Vector3,
+	/// Density of the direction with respect to solid angle at the shading point
+	/// One for lights without a size, whose samples have all the probability in a single direction
+	pub pdf: f64,
+	/// Lights without a size can't be hit by rays, so their samples aren't weighted against BSDF sampling
+	pub delta: bool,
+}
+
+fn default_falloff_angle() -> f64 {
+	5.0
+}
+
+fn default_down() -> Vector3 {
+	Vector3::new(0.0, -1.0, 0.0)
+}
+
+///
+/// A light that isn't part of the geometry
+///
+/// In a project file:
+///
+/// ```json
+/// "lights": [
+///     { "Point": { "position": [0, 1.9, 2.5], "color": { "Blackbody": 2700 }, "intensity": 5 } },
+///     { "Spot": { "position": [0, 3, 0], "direction": [0, -1, 0], "intensity": 40, "cone_angle": 30 } },
+///     { "Point": { "position": [2, 2.8, 0], "intensity": 25, "ies": { "profile": "downlight.ies", "rotation": 45 } } },
+///     { "Directional": { "direction": [-0.3, -1, 0.2], "irradiance": 3, "angular_diameter": 0.53 } },
+///     { "Environment": { "texture": "studio.hdr", "intensity": 1.5, "rotation": 90 } },
+///     { "Sky": { "sun": { "Angles": { "elevation": 35, "azimuth": 140 } }, "turbidity": 3, "intensity": 0.02 } }
+/// ]
+/// ```
+///
+/// Angles are in degrees.
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Light {
+	Point(PointLight),
+	Spot(SpotLight),
+	Directional(DirectionalLight),
+	/// Light arriving from all directions at infinity, for rays that leave the scene
+	Environment(EnvironmentLight),
+	/// Daylight, an analytic sky with the sun in it, instead of an environment map
+	Sky(SkyLight),
+}
+
+/// Emits evenly in all directions from a single point
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PointLight {
+	pub position: Vector3,
+	#[serde(default)]
+	pub color: EmissionColor,
+	/// Radiant intensity, the power per steradian, which the color is scaled by
+	pub intensity: f64,
+	/// Where the nadir of the IES profile points
+	#[serde(default = "default_down")]
+	pub direction: Vector3,
+	#[serde(default)]
+	pub ies: Option<Photometry>,
+}
+
+/// A point light that only emits into a cone
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpotLight {
+	pub position: Vector3,
+	/// The axis of the cone, pointing away from the light
+	pub direction: Vector3,
+	#[serde(default)]
+	pub color: EmissionColor,
+	/// Radiant intensity along the axis
+	pub intensity: f64,
+	/// Angle between the axis and the edge of the cone
+	pub cone_angle: f64,
+	/// Angle over which the light fades out towards the edge of the cone
+	#[serde(default = "default_falloff_angle")]
+	pub falloff_angle: f64,
+	/// A profile within the cone, its nadir along the axis
+	#[serde(default)]
+	pub ies: Option<Photometry>,
+}
+
+///
+/// A measured distribution of intensity over directions, for lights that imitate real luminaires
+///
+/// The profile's brightest direction gets the light's intensity. Its 0° plane is turned
+/// towards +x, or +z for lights pointing along the x axis, and then by `rotation` around the
+/// direction of the light.
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Photometry {
+	pub profile: IesProfile,
+	#[serde(default)]
+	pub rotation: f64,
+}
+
+impl Photometry {
+	/// Fraction of the peak intensity emitted towards `direction`, for a light pointing along `nadir`
+	fn intensity(&self, nadir: Vector3, direction: Vector3) -> f64 {
+		let nadir = nadir.normalize();
+		let reference = if nadir.x.abs() > 0.999 { Vector3::new(0.0, 0.0, 1.0) } else { Vector3::new(1.0, 0.0, 0.0) };
+		let local = Frame::new(nadir, reference).to_local(direction);
+		let (sin, cos) = self.rotation.to_radians().sin_cos();
+		let local = Vector3::new(local.x * cos + local.z * sin, local.y, local.z * cos - local.x * sin);
+
+		self.profile.intensity(local)
+	}
+}
+
+/// Share of a point light's power an optional profile lets through
+fn average_factor(ies: &Option<Photometry>) -> f64 {
+	ies.as_ref().map_or(1.0, |ies| ies.profile.average())
+}
+
+/// Fraction of the intensity a light with an optional profile emits towards `direction`
+fn photometric_factor(ies: &Option<Photometry>, nadir: Vector3, direction: Vector3) -> f64 {
+	ies.as_ref().map_or(1.0, |ies| ies.intensity(nadir, direction))
+}
+
+/// Light arriving from a single direction, like the sun
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DirectionalLight {
+	/// Direction the light travels in
+	pub direction: Vector3,
+	#[serde(default)]
+	pub color: EmissionColor,
+	/// Irradiance on a surface facing the light, which the color is scaled by
+	pub irradiance: f64,
+	/// Apparent size of the light, 0.53 for the sun, which softens shadows
+	#[serde(default)]
+	pub angular_diameter: f64,
+}
+
+impl SpotLight {
+	/// Fraction of the intensity emitted towards a direction, smoothly fading out at the edge of the cone
+	fn falloff(&self, direction: Vector3) -> f64 {
+		let cos_theta = self.direction.normalize().dot(direction);
+		let cos_edge = self.cone_angle.to_radians().cos();
+		let cos_falloff_start = (self.cone_angle - self.falloff_angle).max(0.0).to_radians().cos();
+
+		if cos_theta >= cos_falloff_start {
+			1.0
+		} else if cos_theta <= cos_edge {
+			0.0
+		} else {
+			let t = (cos_theta - cos_edge) / (cos_falloff_start - cos_edge);
+			t * t * (3.0 - 2.0 * t)
+		}
+	}
+}
+
+impl DirectionalLight {
+	/// Cosine of the angle between the center and the edge of the light's disk
+	pub(crate) fn cos_max(&self) -> f64 {
+		(self.angular_diameter.max(0.0) / 2.0).to_radians().cos()
+	}
+
+	/// Frame around the direction towards the light
+	fn frame(&self) -> Frame {
+		Frame::new(-self.direction.normalize(), Vector3::new(0.0, 0.0, 0.0))
+	}
+}
+
+pub(crate) fn default_intensity() -> f64 {
+	1.0
+}
+
+/// Direction through a position in an unrotated equirectangular image, v = 0 at the top
+pub(crate) fn equirectangular_direction(p: Vector2) -> Vector3 {
+	let phi = (p.x - 0.5) * 2.0 * PI;
+	let theta = p.y * PI;
+
+	Vector3::new(theta.sin() * phi.cos(), theta.cos(), theta.sin() * phi.sin())
+}
+
+/// An environment light as written in a project file
+#[derive(Clone, Serialize, Deserialize)]
+pub struct EnvironmentMap {
+	/// An equirectangular image, usually an `.hdr` or `.exr`, relative to the project file
+	pub texture: Texture,
+	/// Scales the texels, which are read as radiance
+	#[serde(default = "default_intensity")]
+	pub intensity: f64,
+	/// Turns the environment about the up (y) axis
+	#[serde(default)]
+	pub rotation: f64,
+}
+
+///
+/// An equirectangular environment map around the scene
+///
+/// The image covers all directions, its columns wrapping around the up axis and its rows
+/// going from straight up at the top to straight down at the bottom. Texels aren't filtered,
+/// so directions can be sampled exactly proportional to the luminance they carry.
+///
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(from = "EnvironmentMap", into = "EnvironmentMap")]
+pub struct EnvironmentLight {
+	pub map: EnvironmentMap,
+	/// Picks texels proportional to their luminance times the solid angle they cover
+	distribution: Arc<Distribution2D>,
+}
+
+impl From<EnvironmentMap> for EnvironmentLight {
+	fn from(map: EnvironmentMap) -> EnvironmentLight {
+		let (width, height) = (map.texture.width, map.texture.height);
+		let mut func = Vec::with_capacity(width * height);
+		for y in 0..height {
+			let sin_theta = (PI * (y as f64 + 0.5) / height as f64).sin();
+			func.extend((0..width).map(|x| luminance(map.texture.texel(x as isize, y as isize)).max(0.0) * sin_theta));
+		}
+
+		EnvironmentLight {
+			distribution: Arc::new(Distribution2D::new(&func, width, height)),
+			map,
+		}
+	}
+}
+
+impl From<EnvironmentLight> for EnvironmentMap {
+	fn from(light: EnvironmentLight) -> EnvironmentMap {
+		light.map
+	}
+}
+
+impl fmt::Debug for EnvironmentLight {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"EnvironmentLight {{ texture: {:?}, intensity: {}, rotation: {} }}",
+			self.map.texture, self.map.intensity, self.map.rotation
+		)
+	}
+}
+
+impl EnvironmentLight {
+	/// Position of a direction in the image, both in [0, 1] with v = 0 at the top
+	fn image_coordinates(&self, direction: Vector3) -> Vector2 {
+		let phi = direction.z.atan2(direction.x) - self.map.rotation.to_radians();
+		let theta = direction.y.clamp(-1.0, 1.0).acos();
+
+		Vector2::new((phi / (2.0 * PI) + 0.5).rem_euclid(1.0), theta / PI)
+	}
+
+	fn direction(&self, p: Vector2) -> Vector3 {
+		equirectangular_direction(Vector2::new(p.x + self.map.rotation / 360.0, p.y))
+	}
+
+	fn radiance(&self, direction: Vector3) -> Vector3 {
+		let p = self.image_coordinates(direction);
+		let texture = &self.map.texture;
+		let x = ((p.x * texture.width as f64) as isize).min(texture.width as isize - 1);
+		let y = ((p.y * texture.height as f64) as isize).min(texture.height as isize - 1);
+
+		texture.texel(x, y) * self.map.intensity
+	}
+
+	/// The density over the image, converted to solid angle
+	fn pdf(&self, direction: Vector3) -> f64 {
+		let p = self.image_coordinates(direction);
+		let sin_theta = (p.y * PI).sin();
+		if sin_theta <= 0.0 {
+			return 0.0;
+		}
+
+		self.distribution.pdf(p) / (2.0 * PI * PI * sin_theta)
+	}
+
+	fn sample(&self, u: Vector2) -> Vector3 {
+		self.direction(self.distribution.sample(u).0)
+	}
+}
+
+impl Light {
+	/// Bounds for the light tree, None for lights at infinity
+	pub fn bounds(&self) -> Option<LightBounds> {
+		match self {
+			Light::Point(light) => Some(LightBounds {
+				bounds: AABB::point(light.position),
+				power: 4.0 * PI * light.intensity * luminance(light.color.rgb()) * average_factor(&light.ies),
+				normals: DirectionCone::entire_sphere(),
+				cos_theta_e: 0.0,
+				two_sided: false,
+			}),
+			Light::Spot(light) => {
+				let falloff_angle = light.falloff_angle.max(0.0).min(light.cone_angle);
+				Some(LightBounds {
+					bounds: AABB::point(light.position),
+					power: 4.0 * PI * light.intensity * luminance(light.color.rgb()) * average_factor(&light.ies),
+					normals: DirectionCone::new(light.direction, (light.cone_angle - falloff_angle).to_radians().cos()),
+					cos_theta_e: falloff_angle.to_radians().cos(),
+					two_sided: false,
+				})
+			}
+			_ => None,
+		}
+	}
+
+	/// Lights without a size can't be seen or hit by rays
+	pub fn is_delta(&self) -> bool {
+		match self {
+			Light::Directional(light) => light.angular_diameter <= 0.0,
+			Light::Environment(_) | Light::Sky(_) => false,
+			_ => true,
+		}
+	}
+
+	/// Samples the light as seen from `point`, None if it doesn't reach the point
+	pub fn sample(&self, point: Vector3, u: Vector2) -> Option<LightSample> {
+		let (direction, distance, radiance) = match self {
+			Light::Point(light) => {
+				let to_light = light.position - point;
+				let distance = to_light.magnitude();
+				let direction = to_light / distance;
+				let intensity = light.intensity * photometric_factor(&light.ies, light.direction, -direction);
+				(direction, distance, light.color.rgb() * (intensity / (distance * distance)))
+			}
+			Light::Spot(light) => {
+				let to_light = light.position - point;
+				let distance = to_light.magnitude();
+				let direction = to_light / distance;
+				let intensity =
+					light.intensity * light.falloff(-direction) * photometric_factor(&light.ies, light.direction, -direction);
+				(direction, distance, light.color.rgb() * (intensity / (distance * distance)))
+			}
+			Light::Directional(light) if self.is_delta() => {
+				(-light.direction.normalize(), f64::INFINITY, light.color.rgb() * light.irradiance)
+			}
+			Light::Directional(light) => {
+				let direction = light.frame().to_world(sampling::uniform_sample_cone(u, light.cos_max()));
+				(direction, f64::INFINITY, self.radiance(direction))
+			}
+			Light::Environment(light) => {
+				let direction = light.sample(u);
+				(direction, f64::INFINITY, light.radiance(direction))
+			}
+			Light::Sky(light) => {
+				let direction = light.sample(point, u)?;
+				(direction, f64::INFINITY, light.radiance(direction))
+			}
+		};
+		if distance.is_nan() || distance <= 0.0 || radiance == Vector3::new(0.0, 0.0, 0.0) {
+			return None;
+		}
+
+		Some(LightSample {
+			direction,
+			distance,
+			radiance,
+			pdf: if self.is_delta() { 1.0 } else { self.pdf(direction) },
+			delta: self.is_delta(),
+		})
+	}
+
+	/// Density of `sample` choosing a direction, zero for lights without a size
+	pub fn pdf(&self, direction: Vector3) -> f64 {
+		match self {
+			Light::Directional(light) if !self.is_delta() && light.frame().to_local(direction).y >= light.cos_max() => {
+				sampling::uniform_cone_pdf(light.cos_max())
+			}
+			Light::Environment(light) => light.pdf(direction),
+			Light::Sky(light) => light.pdf(direction),
+			_ => 0.0,
+		}
+	}
+
+	/// Radiance arriving from a direction towards the light, for rays that don't hit any object
+	pub fn radiance(&self, direction: Vector3) -> Vector3 {
+		match self {
+			// Spread evenly over the disk, in the small angle approximation of the irradiance
+			Light::Directional(light) if !self.is_delta() && light.frame().to_local(direction).y >= light.cos_max() => {
+				light.color.rgb() * (light.irradiance * sampling::uniform_cone_pdf(light.cos_max()))
+			}
+			Light::Environment(light) => light.radiance(direction),
+			Light::Sky(light) => light.radiance(direction),
+			_ => Vector3::new(0.0, 0.0, 0.0),
+		}
+	}
+}
+
+///
+/// An emissive object that is sampled directly
+///
+/// Spheres are sampled within the cone they cover as seen from the shading point, quads and
+/// meshes by area. Triangles of meshes are picked proportional to the power they emit.
+///
+#[derive(Debug, Clone)]
+pub struct Emitter {
+	/// Index into `Scene::objects`
+	pub object: usize,
+	/// Picks the triangles of emissive meshes
+	triangles: Option<AliasTable>,
+}
+
+/// Power a triangle emits up to a constant factor, judged from the radiance at its center
+fn triangle_power(emission: &Emission, triangle: &Triangle) -> f64 {
+	let normal = (triangle.1.position - triangle.0.position).cross(triangle.2.position - triangle.0.position);
+	let surface = SurfaceProperties {
+		normal,
+		geometric_normal: normal,
+		uv: (triangle.0.uv + triangle.1.uv + triangle.2.uv) / 3.0,
+		tangent: triangle.0.tangent,
+		handedness: 1.0,
+	};
+
+	triangle.area() * luminance(emission.radiance(&surface, 1.0, 1.0))
+}
+
+/// A cone around the face normals of the triangles, oriented like their vertex normals
+fn mesh_normals(triangles: &[Triangle]) -> DirectionCone {
+	let normals: Vec<Vector3> = triangles
+		.iter()
+		.map(|triangle| {
+			let normal = (triangle.1.position - triangle.0.position).cross(triangle.2.position - triangle.0.position);
+			if normal.dot(triangle.0.normal + triangle.1.normal + triangle.2.normal) < 0.0 {
+				-normal
+			} else {
+				normal
+			}
+		})
+		.collect();
+
+	// Weighted by area, as the cross products are twice the triangles' areas
+	let axis = normals.iter().fold(Vector3::new(0.0, 0.0, 0.0), |sum, normal| sum + normal);
+	if axis.magnitude2().is_nan() || axis.magnitude2() <= 0.0 {
+		return DirectionCone::entire_sphere();
+	}
+	let axis = axis.normalize();
+	let cos_theta = normals
+		.iter()
+		.filter(|normal| normal.magnitude2() > 0.0)
+		.fold(1.0_f64, |cos_theta, normal| cos_theta.min(axis.dot(normal.normalize())));
+
+	DirectionCone::new(axis, cos_theta)
+}
+
+impl Emitter {
+	///
+	/// None if the object doesn't emit light or can't be sampled
+	///
+	/// That includes meshes without area or power, e.g. with only degenerate triangles. Paths
+	/// hitting them still see their emission, it just isn't sampled directly.
+	///
+	pub fn new(index: usize, object: &Object) -> Option<Emitter> {
+		let emission = match object.material.base() {
+			Material::Emission(emission) if object.is_light() => emission,
+			_ => return None,
+		};
+		let area = object.geometry.surface_area();
+		if area.is_nan() || area <= 0.0 {
+			return None;
+		}
+
+		let triangles = match &object.geometry {
+			Geometry::Grid(grid) => {
+				let power: Vec<f64> = grid.mesh.triangles.iter().map(|triangle| triangle_power(emission, triangle)).collect();
+				let total: f64 = power.iter().sum();
+				if total.is_nan() || total <= 0.0 {
+					return None;
+				}
+				Some(AliasTable::new(&power))
+			}
+			_ => None,
+		};
+
+		Some(Emitter { object: index, triangles })
+	}
+
+	/// Bounds for the light tree, with the power a Lambertian surface of the emitter's radiance emits
+	pub fn bounds(&self, object: &Object) -> LightBounds {
+		let emission = match object.material.base() {
+			Material::Emission(emission) => emission,
+			_ => unreachable!("Emitters are emissive"),
+		};
+		let sides = if emission.two_sided { 2.0 } else { 1.0 };
+		let area = object.geometry.surface_area();
+		let average_power = PI * sides * area * luminance(emission.average_radiance(area));
+
+		let (bounds, power, normals) = match &object.geometry {
+			Geometry::Sphere(sphere) => {
+				let extent = Vector3::new(sphere.radius, sphere.radius, sphere.radius);
+				let bounds = AABB { min: sphere.origin - extent, max: sphere.origin + extent };
+				(bounds, average_power, DirectionCone::entire_sphere())
+			}
+			Geometry::Quad(quad) => {
+				let bounds = [quad.edge_u, quad.edge_v, quad.edge_u + quad.edge_v]
+					.iter()
+					.fold(AABB::point(quad.origin), |bounds, edge| bounds.union(&AABB::point(quad.origin + edge)));
+				(bounds, average_power, DirectionCone::new(quad.edge_u.cross(quad.edge_v), 1.0))
+			}
+			Geometry::Grid(grid) => {
+				let power = grid.mesh.triangles.iter().map(|triangle| triangle_power(emission, triangle)).sum::<f64>();
+				(grid.mesh.bounding_box, PI * sides * power, mesh_normals(&grid.mesh.triangles))
+			}
+			Geometry::Plane(_) => unreachable!("Planes aren't sampled"),
+		};
+
+		LightBounds {
+			bounds,
+			power,
+			normals,
+			cos_theta_e: 0.0,
+			two_sided: emission.two_sided,
+		}
+	}
+
+	/// Samples a point on the emitter, None if it emits nothing towards `point`
+	pub fn sample(&self, object: &Object, point: Vector3, u: Vector2) -> Option<LightSample> {
+		let emission = match object.material.base() {
+			Material::Emission(emission) => emission,
+			_ => return None,
+		};
+
+		let (position, subobject_index) = match (&object.geometry, &self.triangles) {
+			(Geometry::Sphere(sphere), _) => (sample_sphere(sphere, point, u), 0),
+			(Geometry::Quad(quad), _) => (quad.sample_point(u), 0),
+			(Geometry::Grid(grid), Some(triangles)) => {
+				let (index, _, ux) = triangles.sample(u.x);
+				(grid.mesh.triangles[index].sample_point(Vector2::new(ux, u.y)), index)
+			}
+			_ => return None,
+		};
+
+		let to_light = position - point;
+		let distance = to_light.magnitude();
+		if distance.is_nan() || distance <= 0.0 {
+			return None;
+		}
+		let direction = to_light / distance;
+
+		let hit = Hit::with_child(Ray::new(point, direction), distance, subobject_index);
+		let surface = object.geometry.get_surface_properties(hit);
+		let cos_theta = surface.geometric_normal.dot(-direction);
+		let radiance = emission.radiance(&surface, cos_theta, object.geometry.surface_area());
+		if cos_theta == 0.0 || radiance == Vector3::new(0.0, 0.0, 0.0) {
+			return None;
+		}
+
+		Some(LightSample {
+			direction,
+			distance,
+			radiance,
+			pdf: self.pdf(object, hit, surface.geometric_normal),
+			delta: false,
+		})
+	}
+
+	///
+	/// Density of `sample` choosing the point a ray hit the emitter at, with respect to solid
+	/// angle at the ray's origin
+	///
+	/// `normal` is the geometric normal at the hit.
+	///
+	pub fn pdf(&self, object: &Object, hit: Hit, normal: Vector3) -> f64 {
+		let point = hit.ray.origin;
+		if let Geometry::Sphere(sphere) = &object.geometry {
+			if let Some(cos_max) = sphere_cone(sphere, point) {
+				return sampling::uniform_cone_pdf(cos_max);
+			}
+		}
+
+		let cos_theta = normal.dot(hit.ray.direction).abs();
+		if cos_theta.is_nan() || cos_theta <= 0.0 {
+			return 0.0;
+		}
+		let area_pdf = match (&object.geometry, &self.triangles) {
+			(Geometry::Grid(grid), Some(triangles)) => {
+				triangles.probability(hit.subobject_index) / grid.mesh.triangles[hit.subobject_index].area()
+			}
+			(geometry, _) => 1.0 / geometry.surface_area(),
+		};
+
+		area_pdf * hit.distance * hit.distance / cos_theta
+	}
+}
+
+/// Cosine of the half angle of the cone a sphere covers as seen from a point, None from inside the sphere
+fn sphere_cone(sphere: &Sphere, point: Vector3) -> Option<f64> {
+	let distance2 = (sphere.origin - point).magnitude2();
+	let radius2 = sphere.radius * sphere.radius;
+	if distance2 <= radius2 {
+		return None;
+	}
+
+	Some((1.0 - radius2 / distance2).max(0.0).sqrt())
+}
+
+/// A point on the part of a sphere visible from `point`, uniform in solid angle, or uniform in area from inside
+fn sample_sphere(sphere: &Sphere, point: Vector3, u: Vector2) -> Vector3 {
+	let cos_max = match sphere_cone(sphere, point) {
+		Some(cos_max) => cos_max,
+		None => return sphere.sample_point(u),
+	};
+
+	let to_center = sphere.origin - point;
+	let distance = to_center.magnitude();
+	let local = sampling::uniform_sample_cone(u, cos_max);
+	let direction = Frame::new(to_center / distance, Vector3::new(0.0, 0.0, 0.0)).to_world(local);
+
+	// Distance to the near side of the sphere along the direction, grazing directions at the
+	// edge of the cone touch it where the root vanishes
+	let sin2 = 1.0 - local.y * local.y;
+	let along = distance * local.y - (sphere.radius * sphere.radius - distance * distance * sin2).max(0.0).sqrt();
+
+	point + direction * along
+}
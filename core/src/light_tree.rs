@@ -0,0 +1,397 @@
+//!
+//! Picking lights proportional to their estimated contribution at a shading point
+//!
+//! Lights with a position are kept in a bounding volume hierarchy, whose nodes bound where
+//! their lights are, how much power they emit and in which directions. Sampling walks down the
+//! tree, choosing between the two children of a node by how much they could light the point.
+//! Follows the light BVH of pbrt-v4, after Conty Estevez and Kulla 2018, "Importance Sampling
+//! of Many Lights with Adaptive Tree Splitting". Lights at infinity are picked uniformly.
+//!
+
+use crate::{geometry::AABB, math::prelude::*};
+
+/// Buckets the centroids are sorted into along each axis when looking for the best split
+const SPLIT_BUCKETS: usize = 12;
+
+/// Directions within an angle around an axis
+#[derive(Debug, Clone, Copy)]
+pub struct DirectionCone {
+	pub axis: Vector3,
+	pub cos_theta: f64,
+}
+
+impl DirectionCone {
+	pub fn new(axis: Vector3, cos_theta: f64) -> DirectionCone {
+		DirectionCone { axis: axis.normalize(), cos_theta }
+	}
+
+	pub fn entire_sphere() -> DirectionCone {
+		DirectionCone { axis: Vector3::new(0.0, 0.0, 1.0), cos_theta: -1.0 }
+	}
+
+	/// The smallest cone containing both cones
+	pub fn union(&self, other: &DirectionCone) -> DirectionCone {
+		let theta_a = self.cos_theta.clamp(-1.0, 1.0).acos();
+		let theta_b = other.cos_theta.clamp(-1.0, 1.0).acos();
+		let theta_d = self.axis.dot(other.axis).clamp(-1.0, 1.0).acos();
+		if (theta_d + theta_b).min(PI) <= theta_a {
+			return *self;
+		}
+		if (theta_d + theta_a).min(PI) <= theta_b {
+			return *other;
+		}
+
+		let theta_o = (theta_a + theta_d + theta_b) / 2.0;
+		let rotation_axis = self.axis.cross(other.axis);
+		if theta_o >= PI || rotation_axis.magnitude2() == 0.0 {
+			return DirectionCone::entire_sphere();
+		}
+
+		// Turn the axis of this cone towards the other one, Rodrigues' rotation formula
+		let k = rotation_axis.normalize();
+		let angle = theta_o - theta_a;
+		let v = self.axis;
+		let axis = v * angle.cos() + k.cross(v) * angle.sin() + k * (k.dot(v) * (1.0 - angle.cos()));
+
+		DirectionCone::new(axis, theta_o.cos())
+	}
+}
+
+///
+/// Where a light or a group of lights is, and how much and in which directions it emits
+///
+/// Surfaces have their normals in `normals` and emit up to 90° around them, so their
+/// `cos_theta_e` is zero. Point lights have normals in all directions.
+///
+#[derive(Debug, Clone, Copy)]
+pub struct LightBounds {
+	pub bounds: AABB,
+	/// Total emitted power, comparable between all lights
+	pub power: f64,
+	/// Directions the light is emitted around
+	pub normals: DirectionCone,
+	/// Cosine of the angle around `normals` light leaves at
+	pub cos_theta_e: f64,
+	/// Whether light also leaves opposite to `normals`
+	pub two_sided: bool,
+}
+
+/// cos(max(0, a - b)) from the sines and cosines of a and b
+fn cos_sub_clamped(sin_a: f64, cos_a: f64, sin_b: f64, cos_b: f64) -> f64 {
+	if cos_a > cos_b {
+		1.0
+	} else {
+		cos_a * cos_b + sin_a * sin_b
+	}
+}
+
+/// sin(max(0, a - b)) from the sines and cosines of a and b
+fn sin_sub_clamped(sin_a: f64, cos_a: f64, sin_b: f64, cos_b: f64) -> f64 {
+	if cos_a > cos_b {
+		0.0
+	} else {
+		sin_a * cos_b - cos_a * sin_b
+	}
+}
+
+fn sin_from_cos(cos_theta: f64) -> f64 {
+	(1.0 - cos_theta * cos_theta).max(0.0).sqrt()
+}
+
+impl LightBounds {
+	pub fn union(&self, other: &LightBounds) -> LightBounds {
+		LightBounds {
+			bounds: self.bounds.union(&other.bounds),
+			power: self.power + other.power,
+			normals: self.normals.union(&other.normals),
+			cos_theta_e: self.cos_theta_e.min(other.cos_theta_e),
+			two_sided: self.two_sided || other.two_sided,
+		}
+	}
+
+	///
+	/// Upper bound on the light arriving at `point`, up to a factor shared by all lights
+	///
+	/// The power falls off with the squared distance to the center of the bounds, and only
+	/// counts if some direction the lights could emit into points towards `point`.
+	///
+	pub fn importance(&self, point: Vector3) -> f64 {
+		if self.bounds.contains(point) {
+			return self.power / (self.bounds.diagonal().magnitude2() / 4.0).max(1e-8);
+		}
+
+		let center = self.bounds.center();
+		let radius2 = self.bounds.diagonal().magnitude2() / 4.0;
+		let distance2 = point.distance2(center).max(radius2).max(1e-8);
+
+		let wi = (point - center).normalize();
+		let mut cos_theta_w = self.normals.axis.dot(wi);
+		if self.two_sided {
+			cos_theta_w = cos_theta_w.abs();
+		}
+		let sin_theta_w = sin_from_cos(cos_theta_w);
+
+		// Half the angle the bounds cover as seen from the point
+		let cos_theta_b = if point.distance2(center) <= radius2 {
+			-1.0
+		} else {
+			(1.0 - radius2 / point.distance2(center)).max(0.0).sqrt()
+		};
+		let sin_theta_b = sin_from_cos(cos_theta_b);
+
+		// Smallest angle between the emitted directions and the direction to the point
+		let cos_theta_o = self.normals.cos_theta;
+		let sin_theta_o = sin_from_cos(cos_theta_o);
+		let cos_theta_x = cos_sub_clamped(sin_theta_w, cos_theta_w, sin_theta_o, cos_theta_o);
+		let sin_theta_x = sin_sub_clamped(sin_theta_w, cos_theta_w, sin_theta_o, cos_theta_o);
+		let cos_theta_p = cos_sub_clamped(sin_theta_x, cos_theta_x, sin_theta_b, cos_theta_b);
+		if cos_theta_p <= self.cos_theta_e {
+			return 0.0;
+		}
+
+		self.power * cos_theta_p / distance2
+	}
+
+	/// Cost of a node with these bounds, weighing power by the area and the spread of directions
+	fn cost(&self, parent: &AABB, axis: usize) -> f64 {
+		let theta_o = self.normals.cos_theta.clamp(-1.0, 1.0).acos();
+		let theta_e = self.cos_theta_e.clamp(-1.0, 1.0).acos();
+		let theta_w = (theta_o + theta_e).min(PI);
+		let sin_theta_o = sin_from_cos(self.normals.cos_theta);
+		let solid_angle = 2.0 * PI * (1.0 - self.normals.cos_theta)
+			+ PI / 2.0
+				* (2.0 * theta_w * sin_theta_o - (theta_o - 2.0 * theta_w).cos() - 2.0 * theta_o * sin_theta_o
+					+ self.normals.cos_theta);
+
+		// Splitting along a thin axis of the parent is penalized
+		let diagonal = parent.diagonal();
+		let aspect = diagonal.x.max(diagonal.y).max(diagonal.z) / diagonal[axis];
+
+		self.power * solid_angle * aspect * self.bounds.surface_area()
+	}
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+	Leaf(LightBounds, usize),
+	/// The first child directly follows its parent, the second is at the index
+	Interior(LightBounds, usize),
+}
+
+impl Node {
+	fn bounds(&self) -> &LightBounds {
+		match self {
+			Node::Leaf(bounds, _) | Node::Interior(bounds, _) => bounds,
+		}
+	}
+}
+
+///
+/// Chooses which light to sample at a shading point
+///
+/// Lights are identified by their index in the slice the tree was built from.
+///
+#[derive(Debug, Clone, Default)]
+pub struct LightTree {
+	nodes: Vec<Node>,
+	parents: Vec<usize>,
+	/// Node of each light, None for the lights that aren't in the tree
+	leaves: Vec<Option<usize>>,
+	/// Lights at infinity, picked uniformly next to the tree
+	infinite: Vec<usize>,
+}
+
+impl LightTree {
+	/// None stands for a light at infinity, lights that emit no power are never picked
+	pub fn new(lights: &[Option<LightBounds>]) -> LightTree {
+		let mut tree = LightTree {
+			leaves: vec![None; lights.len()],
+			..Default::default()
+		};
+
+		let mut bounded = Vec::new();
+		for (index, light) in lights.iter().enumerate() {
+			match light {
+				Some(bounds) if bounds.power > 0.0 => bounded.push((index, *bounds)),
+				Some(_) => (),
+				None => tree.infinite.push(index),
+			}
+		}
+		if !bounded.is_empty() {
+			tree.build(&mut bounded, 0);
+		}
+
+		tree
+	}
+
+	fn build(&mut self, lights: &mut [(usize, LightBounds)], parent: usize) -> usize {
+		let index = self.nodes.len();
+		self.parents.push(parent);
+
+		if let [(light, bounds)] = lights {
+			self.nodes.push(Node::Leaf(*bounds, *light));
+			self.leaves[*light] = Some(index);
+			return index;
+		}
+
+		let bounds = lights[1..].iter().fold(lights[0].1, |bounds, (_, light)| bounds.union(light));
+		let centroids = lights
+			.iter()
+			.fold(AABB::empty(), |centroids, (_, light)| centroids.union(&AABB::point(light.bounds.center())));
+
+		let middle = match Self::find_split(lights, &bounds.bounds, &centroids) {
+			Some((axis, split)) => partition(lights, |(_, light)| centroid_bucket(light, &centroids, axis) <= split),
+			None => 0,
+		};
+		// Lights that can't be told apart are split in half
+		let middle = if middle == 0 || middle == lights.len() { lights.len() / 2 } else { middle };
+
+		self.nodes.push(Node::Interior(bounds, 0));
+		let (first, second) = lights.split_at_mut(middle);
+		self.build(first, index);
+		let second = self.build(second, index);
+		self.nodes[index] = Node::Interior(bounds, second);
+
+		index
+	}
+
+	/// The axis and the last bucket on the first side of the cheapest split
+	fn find_split(lights: &[(usize, LightBounds)], bounds: &AABB, centroids: &AABB) -> Option<(usize, usize)> {
+		let mut best = None;
+		let mut best_cost = F_MAX;
+
+		for axis in 0..3 {
+			if centroids.max[axis] <= centroids.min[axis] || bounds.diagonal()[axis] <= 0.0 {
+				continue;
+			}
+
+			let mut buckets: Vec<Option<LightBounds>> = vec![None; SPLIT_BUCKETS];
+			for (_, light) in lights {
+				let bucket = &mut buckets[centroid_bucket(light, centroids, axis)];
+				*bucket = Some(bucket.map_or(*light, |bucket| bucket.union(light)));
+			}
+
+			for split in 0..SPLIT_BUCKETS - 1 {
+				let side = |buckets: &[Option<LightBounds>]| {
+					buckets.iter().flatten().fold(None, |union: Option<LightBounds>, light| {
+						Some(union.map_or(*light, |union| union.union(light)))
+					})
+				};
+				let cost = match (side(&buckets[..=split]), side(&buckets[split + 1..])) {
+					(Some(first), Some(second)) => first.cost(bounds, axis) + second.cost(bounds, axis),
+					_ => continue,
+				};
+				if cost < best_cost {
+					best_cost = cost;
+					best = Some((axis, split));
+				}
+			}
+		}
+
+		best
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.nodes.is_empty() && self.infinite.is_empty()
+	}
+
+	/// Probability of picking any one light at infinity
+	fn infinite_probability(&self) -> f64 {
+		let choices = self.infinite.len() + if self.nodes.is_empty() { 0 } else { 1 };
+		1.0 / choices as f64
+	}
+
+	/// Picks a light for `point` with its probability, None if no light can reach the point
+	pub fn sample(&self, point: Vector3, u: f64) -> Option<(usize, f64)> {
+		if self.is_empty() {
+			return None;
+		}
+
+		let infinite_probability = self.infinite_probability();
+		let all_infinite = infinite_probability * self.infinite.len() as f64;
+		if u < all_infinite {
+			let index = ((u / infinite_probability) as usize).min(self.infinite.len() - 1);
+			return Some((self.infinite[index], infinite_probability));
+		}
+
+		let mut u = ((u - all_infinite) / (1.0 - all_infinite)).min(1.0 - f64::EPSILON);
+		let mut probability = 1.0 - all_infinite;
+		let mut index = 0;
+		loop {
+			match &self.nodes[index] {
+				Node::Leaf(bounds, light) => {
+					return if bounds.importance(point) > 0.0 { Some((*light, probability)) } else { None };
+				}
+				Node::Interior(_, second) => {
+					let first_importance = self.nodes[index + 1].bounds().importance(point);
+					let second_importance = self.nodes[*second].bounds().importance(point);
+					let importance = first_importance + second_importance;
+					if importance.is_nan() || importance <= 0.0 {
+						return None;
+					}
+
+					let p_first = first_importance / importance;
+					if u < p_first {
+						u /= p_first;
+						probability *= p_first;
+						index += 1;
+					} else {
+						u = ((u - p_first) / (1.0 - p_first)).min(1.0 - f64::EPSILON);
+						probability *= 1.0 - p_first;
+						index = *second;
+					}
+				}
+			}
+		}
+	}
+
+	/// Probability of `sample` picking a light for `point`
+	pub fn probability(&self, point: Vector3, light: usize) -> f64 {
+		if self.infinite.contains(&light) {
+			return self.infinite_probability();
+		}
+		let mut node = match self.leaves.get(light) {
+			Some(Some(node)) => *node,
+			_ => return 0.0,
+		};
+		let importance = self.nodes[node].bounds().importance(point);
+		if importance.is_nan() || importance <= 0.0 {
+			return 0.0;
+		}
+
+		// Walk up from the light's leaf, through the choices that lead to it
+		let mut probability = 1.0 - self.infinite_probability() * self.infinite.len() as f64;
+		while node != 0 {
+			let parent = self.parents[node];
+			let second = match &self.nodes[parent] {
+				Node::Interior(_, second) => *second,
+				Node::Leaf(..) => unreachable!(),
+			};
+			let first_importance = self.nodes[parent + 1].bounds().importance(point);
+			let second_importance = self.nodes[second].bounds().importance(point);
+			let importance = if node == second { second_importance } else { first_importance };
+			probability *= importance / (first_importance + second_importance);
+			node = parent;
+		}
+
+		probability
+	}
+}
+
+fn centroid_bucket(light: &LightBounds, centroids: &AABB, axis: usize) -> usize {
+	let t = (light.bounds.center()[axis] - centroids.min[axis]) / (centroids.max[axis] - centroids.min[axis]);
+	((t * SPLIT_BUCKETS as f64) as usize).min(SPLIT_BUCKETS - 1)
+}
+
+/// Moves the items the predicate holds for to the front, returns how many there are
+fn partition<T>(items: &mut [T], predicate: impl Fn(&T) -> bool) -> usize {
+	let mut first = 0;
+	for i in 0..items.len() {
+		if predicate(&items[i]) {
+			items.swap(first, i);
+			first += 1;
+		}
+	}
+
+	first
+}
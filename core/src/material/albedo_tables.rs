@@ -0,0 +1,1744 @@
+//! Generated by `cargo run --release -p core --bin albedo_tables`, don't edit by hand
+
+// Measured values can land close to well known constants
+#![allow(clippy::approx_constant)]
+
+pub const CONDUCTOR_RESOLUTION: usize = 32;
+pub const DIELECTRIC_RESOLUTION: usize = 16;
+pub const SHEEN_RESOLUTION: usize = 32;
+pub const DIFFUSE_RESOLUTION: usize = 32;
+
+/// Albedo of GGX reflection with a Fresnel term of one, indexed by [roughness][cos]
+#[rustfmt::skip]
+pub static CONDUCTOR_ALBEDO: [f64; 1024] = [
+	0.997973, 0.999885, 0.999959, 0.999979, 0.999988, 0.999992, 0.999994, 0.999996,
+	0.999997, 0.999997, 0.999998, 0.999998, 0.999999, 0.999999, 0.999999, 0.999999,
+	0.999999, 0.999999, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000,
+	1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000,
+	0.988305, 0.999406, 0.999800, 0.999899, 0.999940, 0.999960, 0.999972, 0.999979,
+	0.999984, 0.999987, 0.999990, 0.999992, 0.999993, 0.999994, 0.999995, 0.999996,
+	0.999997, 0.999997, 0.999998, 0.999998, 0.999998, 0.999999, 0.999999, 0.999999,
+	0.999999, 0.999999, 0.999999, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000,
+	0.932292, 0.990015, 0.996514, 0.999047, 0.999510, 0.999684, 0.999779, 0.999837,
+	0.999876, 0.999903, 0.999922, 0.999937, 0.999948, 0.999957, 0.999964, 0.999969,
+	0.999974, 0.999978, 0.999981, 0.999984, 0.999987, 0.999989, 0.999990, 0.999992,
+	0.999993, 0.999995, 0.999996, 0.999997, 0.999998, 0.999998, 0.999999, 1.000000,
+	0.893227, 0.963317, 0.985988, 0.993120, 0.995917, 0.997314, 0.998825, 0.999301,
+	0.999493, 0.999610, 0.999692, 0.999751, 0.999796, 0.999831, 0.999859, 0.999881,
+	0.999900, 0.999915, 0.999928, 0.999939, 0.999948, 0.999956, 0.999963, 0.999969,
+	0.999974, 0.999979, 0.999983, 0.999987, 0.999990, 0.999994, 0.999996, 0.999999,
+	0.897721, 0.926168, 0.963780, 0.980462, 0.988288, 0.992219, 0.994743, 0.995988,
+	0.996862, 0.997666, 0.998714, 0.999177, 0.999377, 0.999501, 0.999591, 0.999660,
+	0.999714, 0.999759, 0.999796, 0.999827, 0.999854, 0.999876, 0.999896, 0.999913,
+	0.999928, 0.999941, 0.999953, 0.999964, 0.999973, 0.999981, 0.999989, 0.999996,
+	0.915845, 0.899720, 0.934638, 0.959560, 0.973934, 0.982307, 0.987363, 0.990629,
+	0.992802, 0.994505, 0.995484, 0.996185, 0.996769, 0.997310, 0.997886, 0.998694,
+	0.999136, 0.999346, 0.999476, 0.999569, 0.999642, 0.999701, 0.999750, 0.999792,
+	0.999828, 0.999860, 0.999888, 0.999912, 0.999934, 0.999954, 0.999971, 0.999987,
+	0.932971, 0.890893, 0.909351, 0.934521, 0.953657, 0.966662, 0.975376, 0.981303,
+	0.985433, 0.988468, 0.990595, 0.992178, 0.993655, 0.994661, 0.995354, 0.995886,
+	0.996337, 0.996743, 0.997139, 0.997552, 0.998026, 0.998730, 0.999173, 0.999403,
+	0.999545, 0.999644, 0.999721, 0.999783, 0.999835, 0.999880, 0.999919, 0.999954,
+	0.946074, 0.893536, 0.893600, 0.911450, 0.930615, 0.946398, 0.958384, 0.967257,
+	0.973834, 0.978763, 0.982533, 0.985353, 0.987677, 0.989446, 0.990797, 0.991939,
+	0.993099, 0.993944, 0.994546, 0.995015, 0.995397, 0.995728, 0.996030, 0.996316,
+	0.996602, 0.996905, 0.997249, 0.997702, 0.998502, 0.999075, 0.999388, 0.999576,
+	0.955506, 0.900444, 0.886092, 0.893365, 0.907858, 0.922944, 0.936191, 0.947085,
+	0.955773, 0.962646, 0.968090, 0.972430, 0.975862, 0.978714, 0.980955, 0.982820,
+	0.984466, 0.985729, 0.986749, 0.987630, 0.988517, 0.989377, 0.989992, 0.990443,
+	0.990787, 0.991059, 0.991282, 0.991469, 0.991628, 0.991766, 0.991888, 0.991995,
+	0.962110, 0.909621, 0.887694, 0.885458, 0.893325, 0.905087, 0.917433, 0.928846,
+	0.938812, 0.947254, 0.954293, 0.960154, 0.965005, 0.969028, 0.972424, 0.975224,
+	0.977651, 0.979680, 0.981361, 0.982868, 0.984246, 0.985349, 0.986265, 0.987053,
+	0.987778, 0.988529, 0.989398, 0.990061, 0.990561, 0.990947, 0.991251, 0.991499,
+	0.966660, 0.918367, 0.891972, 0.882142, 0.883004, 0.889743, 0.899117, 0.909203,
+	0.918950, 0.927877, 0.935812, 0.942732, 0.948714, 0.953853, 0.958270, 0.962080,
+	0.965326, 0.968170, 0.970598, 0.972689, 0.974595, 0.976202, 0.977568, 0.978784,
+	0.979995, 0.981020, 0.981847, 0.982523, 0.983084, 0.983559, 0.983984, 0.984469,
+	0.969560, 0.925235, 0.896439, 0.881659, 0.876972, 0.878828, 0.884577, 0.892319,
+	0.900820, 0.909325, 0.917432, 0.924919, 0.931698, 0.937763, 0.943152, 0.947934,
+	0.952144, 0.955858, 0.959151, 0.962049, 0.964599, 0.966921, 0.968927, 0.970704,
+	0.972389, 0.973861, 0.975125, 0.976239, 0.977277, 0.978427, 0.979468, 0.980334,
+	0.971616, 0.930102, 0.901246, 0.883281, 0.873881, 0.870994, 0.872772, 0.877369,
+	0.883601, 0.890640, 0.897941, 0.905141, 0.912015, 0.918439, 0.924368, 0.929789,
+	0.934715, 0.939153, 0.943182, 0.946788, 0.950061, 0.952977, 0.955653, 0.958026,
+	0.960166, 0.962174, 0.963936, 0.965502, 0.966958, 0.968430, 0.969704, 0.970795,
+	0.973213, 0.933459, 0.904749, 0.884794, 0.872495, 0.865979, 0.863889, 0.865191,
+	0.868759, 0.873730, 0.879526, 0.885717, 0.892011, 0.898203, 0.904179, 0.909846,
+	0.915158, 0.920089, 0.924658, 0.928869, 0.932723, 0.936280, 0.939513, 0.942498,
+	0.945216, 0.947686, 0.950010, 0.952097, 0.953979, 0.955789, 0.957496, 0.958996,
+	0.974073, 0.935638, 0.906984, 0.885821, 0.871258, 0.861942, 0.856801, 0.855039,
+	0.855874, 0.858509, 0.862377, 0.867053, 0.872221, 0.877632, 0.883121, 0.888551,
+	0.893828, 0.898883, 0.903692, 0.908238, 0.912494, 0.916492, 0.920209, 0.923679,
+	0.926887, 0.929898, 0.932655, 0.935245, 0.937649, 0.939833, 0.941889, 0.943918,
+	0.974266, 0.936350, 0.907826, 0.886000, 0.869666, 0.858167, 0.850450, 0.846143,
+	0.844363, 0.844562, 0.846271, 0.849082, 0.852693, 0.856888, 0.861472, 0.866245,
+	0.871083, 0.875895, 0.880612, 0.885199, 0.889611, 0.893833, 0.897857, 0.901668,
+	0.905282, 0.908684, 0.911899, 0.914932, 0.917749, 0.920442, 0.922940, 0.925220,
+	0.973895, 0.935902, 0.907412, 0.884865, 0.867284, 0.854015, 0.844318, 0.837680,
+	0.833499, 0.831422, 0.831069, 0.832012, 0.833960, 0.836699, 0.840007, 0.843713,
+	0.847691, 0.851851, 0.856118, 0.860416, 0.864674, 0.868848, 0.872922, 0.876857,
+	0.880660, 0.884295, 0.887788, 0.891108, 0.894267, 0.897286, 0.900102, 0.902764,
+	0.973039, 0.934700, 0.905623, 0.882449, 0.863841, 0.849077, 0.837708, 0.829080,
+	0.822938, 0.818782, 0.816342, 0.815369, 0.815541, 0.816650, 0.818494, 0.820918,
+	0.823819, 0.827078, 0.830583, 0.834249, 0.838024, 0.841851, 0.845692, 0.849492,
+	0.853243, 0.856904, 0.860482, 0.863945, 0.867295, 0.870537, 0.873620, 0.876575,
+	0.971771, 0.932427, 0.902811, 0.878784, 0.859194, 0.843196, 0.830263, 0.820015,
+	0.812048, 0.806061, 0.801735, 0.798918, 0.797304, 0.796705, 0.796990, 0.798027,
+	0.799642, 0.801734, 0.804217, 0.806990, 0.809984, 0.813151, 0.816452, 0.819820,
+	0.823236, 0.826653, 0.830065, 0.833445, 0.836772, 0.840072, 0.843301, 0.846450,
+	0.970123, 0.929542, 0.898922, 0.873935, 0.853307, 0.836121, 0.821865, 0.810125,
+	0.800545, 0.792870, 0.786860, 0.782294, 0.778958, 0.776736, 0.775446, 0.774930,
+	0.775114, 0.775895, 0.777162, 0.778847, 0.780860, 0.783145, 0.785662, 0.788353,
+	0.791178, 0.794098, 0.797095, 0.800162, 0.803240, 0.806321, 0.809408, 0.812448,
+	0.968281, 0.925896, 0.894031, 0.867966, 0.846204, 0.827847, 0.812326, 0.799214,
+	0.788187, 0.778982, 0.771400, 0.765221, 0.760291, 0.756451, 0.753602, 0.751577,
+	0.750293, 0.749680, 0.749622, 0.750055, 0.750902, 0.752105, 0.753635, 0.755425,
+	0.757431, 0.759633, 0.761980, 0.764448, 0.767006, 0.769641, 0.772344, 0.775106,
+	0.966363, 0.921692, 0.888298, 0.860966, 0.837977, 0.818405, 0.801625, 0.787213,
+	0.774826, 0.764216, 0.755164, 0.747491, 0.741063, 0.735710, 0.731346, 0.727831,
+	0.725106, 0.723060, 0.721621, 0.720729, 0.720327, 0.720338, 0.720734, 0.721445,
+	0.722453, 0.723710, 0.725180, 0.726854, 0.728691, 0.730660, 0.732737, 0.734930,
+	0.964181, 0.916972, 0.881885, 0.853025, 0.828706, 0.807861, 0.789803, 0.774110,
+	0.760411, 0.748471, 0.738055, 0.728994, 0.721146, 0.714373, 0.708560, 0.703635,
+	0.699474, 0.696030, 0.693224, 0.690984, 0.689271, 0.688015, 0.687185, 0.686719,
+	0.686600, 0.686775, 0.687232, 0.687924, 0.688833, 0.689954, 0.691224, 0.692643,
+	0.961758, 0.911867, 0.874749, 0.844251, 0.818461, 0.796265, 0.776923, 0.759945,
+	0.744980, 0.731748, 0.720029, 0.709654, 0.700467, 0.692346, 0.685195, 0.678903,
+	0.673394, 0.668604, 0.664451, 0.660892, 0.657870, 0.655335, 0.653235, 0.651555,
+	0.650240, 0.649258, 0.648594, 0.648189, 0.648066, 0.648146, 0.648458, 0.648953,
+	0.959094, 0.906298, 0.866967, 0.834669, 0.807347, 0.783743, 0.763068, 0.744798,
+	0.728559, 0.714081, 0.701117, 0.689489, 0.679041, 0.669659, 0.661234, 0.653667,
+	0.646878, 0.640805, 0.635377, 0.630544, 0.626253, 0.622466, 0.619135, 0.616220,
+	0.613689, 0.611532, 0.609696, 0.608154, 0.606905, 0.605908, 0.605155, 0.604615,
+	0.956210, 0.900367, 0.858623, 0.824388, 0.795443, 0.770350, 0.748308, 0.728753,
+	0.711263, 0.695543, 0.681369, 0.668548, 0.656908, 0.646328, 0.636718, 0.627959,
+	0.619973, 0.612696, 0.606070, 0.600046, 0.594560, 0.589581, 0.585059, 0.580966,
+	0.577274, 0.573947, 0.570960, 0.568290, 0.565912, 0.563804, 0.561954, 0.560337,
+	0.953304, 0.894031, 0.849777, 0.813595, 0.782934, 0.756289, 0.732817, 0.711924,
+	0.693165, 0.676255, 0.660910, 0.646932, 0.634155, 0.622469, 0.611729, 0.601854,
+	0.592768, 0.584393, 0.576664, 0.569531, 0.562940, 0.556859, 0.551239, 0.546051,
+	0.541256, 0.536834, 0.532756, 0.529000, 0.525547, 0.522373, 0.519461, 0.516792,
+	0.950287, 0.887435, 0.840504, 0.802291, 0.769813, 0.741569, 0.716657, 0.694432,
+	0.674439, 0.656335, 0.639846, 0.624758, 0.610921, 0.598171, 0.586397, 0.575500,
+	0.565399, 0.556009, 0.547275, 0.539144, 0.531556, 0.524468, 0.517856, 0.511673,
+	0.505888, 0.500477, 0.495408, 0.490666, 0.486225, 0.482066, 0.478173, 0.474529,
+	0.947106, 0.880521, 0.830844, 0.790517, 0.756169, 0.726295, 0.699943, 0.676389,
+	0.655162, 0.635896, 0.618304, 0.602176, 0.587323, 0.573585, 0.560854, 0.549021,
+	0.537992, 0.527693, 0.518061, 0.509036, 0.500561, 0.492603, 0.485112, 0.478055,
+	0.471403, 0.465123, 0.459191, 0.453584, 0.448282, 0.443261, 0.438509, 0.434006,
+	0.943760, 0.873356, 0.820863, 0.778354, 0.742085, 0.710625, 0.682808, 0.657932,
+	0.635489, 0.615095, 0.596451, 0.579320, 0.563511, 0.548862, 0.535251, 0.522568,
+	0.510710, 0.499604, 0.489182, 0.479376, 0.470141, 0.461420, 0.453182, 0.445384,
+	0.437996, 0.430989, 0.424327, 0.418000, 0.411982, 0.406245, 0.400780, 0.395565,
+	0.940275, 0.865985, 0.810648, 0.765851, 0.727704, 0.694601, 0.665319, 0.639139,
+	0.615521, 0.594046, 0.574398, 0.556324, 0.539628, 0.524147, 0.509744, 0.496295,
+	0.483706, 0.471898, 0.460787, 0.450319, 0.440437, 0.431089, 0.422229, 0.413827,
+	0.405845, 0.398247, 0.391014, 0.384113, 0.377525, 0.371231, 0.365209, 0.359444,
+	0.936809, 0.858413, 0.800203, 0.753082, 0.713049, 0.678297, 0.647585, 0.620156,
+	0.595397, 0.572878, 0.552280, 0.533334, 0.515831, 0.499593, 0.484474, 0.470356,
+	0.457131, 0.444719, 0.433036, 0.422021, 0.411606, 0.401750, 0.392405, 0.383529,
+	0.375087, 0.367043, 0.359378, 0.352053, 0.345053, 0.338353, 0.331937, 0.325784,
+];
+
+/// Cosine weighted average of each row of CONDUCTOR_ALBEDO
+#[rustfmt::skip]
+pub static CONDUCTOR_AVERAGE_ALBEDO: [f64; 32] = [
+	0.999997, 0.999981, 0.999851, 0.999515, 0.998806, 0.997495, 0.995285, 0.991412,
+	0.982812, 0.977776, 0.967623, 0.958191, 0.944948, 0.929826, 0.912464, 0.892859,
+	0.871191, 0.847537, 0.821920, 0.794562, 0.765661, 0.735483, 0.704293, 0.672374,
+	0.640015, 0.607499, 0.575109, 0.543096, 0.511692, 0.481099, 0.451488, 0.422998,
+];
+
+/// Albedo of GGX reflection with Schlick's Fresnel term for f0 = 0, indexed by [roughness][cos]
+#[rustfmt::skip]
+pub static SCHLICK_WEIGHT_ALBEDO: [f64; 1024] = [
+	0.921282, 0.786193, 0.665645, 0.560270, 0.468660, 0.389437, 0.321306, 0.263061,
+	0.213586, 0.171850, 0.136910, 0.107899, 0.084028, 0.064583, 0.048916, 0.036449,
+	0.026664, 0.019104, 0.013364, 0.009096, 0.005995, 0.003804, 0.002306, 0.001323,
+	0.000707, 0.000346, 0.000150, 0.000055, 0.000016, 0.000003, 0.000000, 0.000000,
+	0.908284, 0.784682, 0.664973, 0.559897, 0.468435, 0.389297, 0.321218, 0.263007,
+	0.213553, 0.171833, 0.136902, 0.107897, 0.084030, 0.064586, 0.048921, 0.036454,
+	0.026669, 0.019108, 0.013368, 0.009099, 0.005997, 0.003806, 0.002308, 0.001324,
+	0.000708, 0.000346, 0.000150, 0.000055, 0.000016, 0.000003, 0.000000, 0.000000,
+	0.832042, 0.768638, 0.658271, 0.556898, 0.466675, 0.388200, 0.320530, 0.262581,
+	0.213301, 0.171696, 0.136841, 0.107884, 0.084047, 0.064621, 0.048963, 0.036498,
+	0.026711, 0.019146, 0.013401, 0.009125, 0.006018, 0.003822, 0.002319, 0.001331,
+	0.000713, 0.000349, 0.000152, 0.000056, 0.000016, 0.000003, 0.000000, 0.000000,
+	0.747699, 0.726543, 0.639606, 0.546325, 0.460338, 0.384262, 0.318456, 0.261377,
+	0.212601, 0.171324, 0.136684, 0.107866, 0.084114, 0.064734, 0.049098, 0.036638,
+	0.026844, 0.019265, 0.013503, 0.009210, 0.006085, 0.003873, 0.002356, 0.001357,
+	0.000730, 0.000360, 0.000158, 0.000059, 0.000017, 0.000003, 0.000000, 0.000000,
+	0.686226, 0.661623, 0.603338, 0.525597, 0.447890, 0.376402, 0.313151, 0.257848,
+	0.210356, 0.170038, 0.136216, 0.107797, 0.084243, 0.064969, 0.049382, 0.036933,
+	0.027127, 0.019522, 0.013725, 0.009395, 0.006234, 0.003988, 0.002442, 0.001418,
+	0.000771, 0.000385, 0.000173, 0.000067, 0.000021, 0.000005, 0.000001, 0.000000,
+	0.629812, 0.593022, 0.552261, 0.492571, 0.426697, 0.362750, 0.304130, 0.252072,
+	0.206704, 0.167810, 0.134675, 0.106860, 0.083787, 0.064861, 0.049529, 0.037303,
+	0.027565, 0.019945, 0.014105, 0.009719, 0.006500, 0.004198, 0.002602, 0.001535,
+	0.000853, 0.000439, 0.000206, 0.000086, 0.000031, 0.000009, 0.000002, 0.000000,
+	0.572411, 0.530644, 0.495748, 0.450094, 0.396949, 0.342382, 0.290350, 0.242831,
+	0.200613, 0.163863, 0.132277, 0.105529, 0.083239, 0.064750, 0.049626, 0.037435,
+	0.027754, 0.020185, 0.014371, 0.009992, 0.006769, 0.004474, 0.002844, 0.001726,
+	0.000994, 0.000539, 0.000273, 0.000128, 0.000055, 0.000022, 0.000008, 0.000002,
+	0.515262, 0.474547, 0.441084, 0.403855, 0.361275, 0.316194, 0.271665, 0.229767,
+	0.191667, 0.157862, 0.128442, 0.103195, 0.081905, 0.064124, 0.049474, 0.037594,
+	0.028130, 0.020638, 0.014812, 0.010376, 0.007072, 0.004672, 0.002978, 0.001822,
+	0.001063, 0.000589, 0.000309, 0.000158, 0.000088, 0.000049, 0.000026, 0.000012,
+	0.460430, 0.423149, 0.390895, 0.358447, 0.323395, 0.286382, 0.249115, 0.213219,
+	0.179822, 0.149595, 0.122835, 0.099572, 0.079653, 0.062886, 0.048923, 0.037488,
+	0.028272, 0.020919, 0.015163, 0.010747, 0.007442, 0.005019, 0.003269, 0.002045,
+	0.001222, 0.000692, 0.000368, 0.000181, 0.000082, 0.000033, 0.000012, 0.000003,
+	0.409273, 0.375920, 0.345779, 0.316648, 0.286769, 0.255941, 0.224888, 0.194590,
+	0.165933, 0.139536, 0.115765, 0.094786, 0.076579, 0.061028, 0.047959, 0.037115,
+	0.028276, 0.021164, 0.015532, 0.011168, 0.007853, 0.005370, 0.003560, 0.002281,
+	0.001407, 0.000837, 0.000483, 0.000265, 0.000137, 0.000066, 0.000029, 0.000010,
+	0.362552, 0.332813, 0.305202, 0.278818, 0.252699, 0.226493, 0.200403, 0.174928,
+	0.150616, 0.127945, 0.107252, 0.088721, 0.072422, 0.058317, 0.046303, 0.036229,
+	0.027898, 0.021130, 0.015709, 0.011443, 0.008163, 0.005676, 0.003835, 0.002513,
+	0.001598, 0.000976, 0.000568, 0.000312, 0.000160, 0.000076, 0.000032, 0.000011,
+	0.320393, 0.293813, 0.268782, 0.244936, 0.221838, 0.199207, 0.177041, 0.155534,
+	0.134984, 0.115693, 0.097917, 0.081820, 0.067487, 0.054928, 0.044095, 0.034897,
+	0.027198, 0.020857, 0.015719, 0.011622, 0.008414, 0.005959, 0.004110, 0.002755,
+	0.001794, 0.001126, 0.000677, 0.000388, 0.000211, 0.000111, 0.000054, 0.000022,
+	0.282812, 0.258890, 0.236321, 0.214885, 0.194380, 0.174629, 0.155555, 0.137224,
+	0.119783, 0.103398, 0.088228, 0.074389, 0.061953, 0.050941, 0.041337, 0.033082,
+	0.026092, 0.020259, 0.015473, 0.011603, 0.008535, 0.006141, 0.004318, 0.002954,
+	0.001962, 0.001263, 0.000781, 0.000461, 0.000259, 0.000138, 0.000066, 0.000026,
+	0.249687, 0.227899, 0.207582, 0.188324, 0.170110, 0.152752, 0.136204, 0.120476,
+	0.105596, 0.091649, 0.078720, 0.066878, 0.056171, 0.046617, 0.038209, 0.030908,
+	0.024657, 0.019379, 0.014993, 0.011403, 0.008511, 0.006227, 0.004452, 0.003107,
+	0.002107, 0.001384, 0.000878, 0.000533, 0.000306, 0.000165, 0.000081, 0.000033,
+	0.220584, 0.200604, 0.182266, 0.165003, 0.148779, 0.133475, 0.119042, 0.105428,
+	0.092655, 0.080739, 0.069706, 0.059589, 0.050411, 0.042177, 0.034880, 0.028493,
+	0.022972, 0.018263, 0.014302, 0.011022, 0.008344, 0.006197, 0.004505, 0.003199,
+	0.002211, 0.001484, 0.000960, 0.000596, 0.000352, 0.000193, 0.000095, 0.000040,
+	0.195081, 0.176713, 0.160103, 0.144598, 0.130127, 0.116602, 0.103936, 0.092107,
+	0.081073, 0.070834, 0.061388, 0.052733, 0.044873, 0.037801, 0.031505, 0.025958,
+	0.021127, 0.016971, 0.013440, 0.010483, 0.008041, 0.006056, 0.004470, 0.003226,
+	0.002270, 0.001551, 0.001024, 0.000649, 0.000390, 0.000219, 0.000111, 0.000046,
+	0.172809, 0.155829, 0.140774, 0.126812, 0.113880, 0.101885, 0.090746, 0.080402,
+	0.070829, 0.061986, 0.053864, 0.046440, 0.039698, 0.033630, 0.028211, 0.023415,
+	0.019214, 0.015576, 0.012461, 0.009828, 0.007630, 0.005822, 0.004358, 0.003193,
+	0.002283, 0.001587, 0.001067, 0.000689, 0.000423, 0.000242, 0.000124, 0.000052,
+	0.153409, 0.137707, 0.123966, 0.111357, 0.099772, 0.089098, 0.079259, 0.070189,
+	0.061838, 0.054172, 0.047154, 0.040762, 0.034968, 0.029752, 0.025088, 0.020952,
+	0.017316, 0.014150, 0.011420, 0.009093, 0.007135, 0.005508, 0.004176, 0.003101,
+	0.002250, 0.001589, 0.001087, 0.000714, 0.000446, 0.000260, 0.000137, 0.000059,
+	0.136551, 0.121948, 0.109370, 0.097952, 0.087541, 0.078016, 0.069293, 0.061303,
+	0.053994, 0.047316, 0.041227, 0.035703, 0.030707, 0.026214, 0.022198, 0.018632,
+	0.015489, 0.012741, 0.010362, 0.008322, 0.006591, 0.005140, 0.003941, 0.002963,
+	0.002179, 0.001560, 0.001083, 0.000724, 0.000460, 0.000274, 0.000147, 0.000065,
+	0.121908, 0.108249, 0.096740, 0.086345, 0.076950, 0.068423, 0.060664, 0.053602,
+	0.047173, 0.041329, 0.036029, 0.031235, 0.026913, 0.023032, 0.019568, 0.016489,
+	0.013773, 0.011393, 0.009325, 0.007544, 0.006024, 0.004740, 0.003671, 0.002790,
+	0.002076, 0.001506, 0.001060, 0.000719, 0.000465, 0.000281, 0.000154, 0.000070,
+	0.109196, 0.096368, 0.085779, 0.076294, 0.067789, 0.060123, 0.053196, 0.046929,
+	0.041256, 0.036125, 0.031490, 0.027314, 0.023560, 0.020199, 0.017202, 0.014541,
+	0.012192, 0.010132, 0.008337, 0.006785, 0.005456, 0.004327, 0.003380, 0.002594,
+	0.001950, 0.001431, 0.001021, 0.000702, 0.000461, 0.000283, 0.000158, 0.000073,
+	0.098169, 0.086060, 0.076275, 0.067589, 0.059860, 0.052946, 0.046736, 0.041154,
+	0.036127, 0.031604, 0.027537, 0.023886, 0.020616, 0.017695, 0.015095, 0.012790,
+	0.010756, 0.008970, 0.007414, 0.006065, 0.004905, 0.003916, 0.003082, 0.002384,
+	0.001810, 0.001342, 0.000968, 0.000674, 0.000449, 0.000280, 0.000159, 0.000076,
+	0.088596, 0.077139, 0.068073, 0.060046, 0.052993, 0.046736, 0.041148, 0.036157,
+	0.031687, 0.027687, 0.024104, 0.020901, 0.018041, 0.015494, 0.013232, 0.011231,
+	0.009466, 0.007917, 0.006566, 0.005394, 0.004383, 0.003519, 0.002787, 0.002172,
+	0.001662, 0.001244, 0.000906, 0.000638, 0.000430, 0.000273, 0.000157, 0.000077,
+	0.080283, 0.069454, 0.060939, 0.053515, 0.047048, 0.041351, 0.036313, 0.031832,
+	0.027841, 0.024291, 0.021122, 0.018302, 0.015793, 0.013565, 0.011592, 0.009849,
+	0.008315, 0.006970, 0.005797, 0.004778, 0.003898, 0.003145, 0.002504, 0.001964,
+	0.001513, 0.001141, 0.000839, 0.000597, 0.000407, 0.000262, 0.000154, 0.000076,
+	0.073046, 0.062763, 0.054717, 0.047812, 0.041870, 0.036688, 0.032121, 0.028083,
+	0.024510, 0.021345, 0.018536, 0.016044, 0.013835, 0.011881, 0.010155, 0.008634,
+	0.007297, 0.006127, 0.005106, 0.004219, 0.003454, 0.002797, 0.002238, 0.001764,
+	0.001367, 0.001039, 0.000770, 0.000553, 0.000381, 0.000248, 0.000148, 0.000075,
+	0.066740, 0.056920, 0.049283, 0.042827, 0.037361, 0.032614, 0.028468, 0.024833,
+	0.021624, 0.018788, 0.016287, 0.014083, 0.012132, 0.010411, 0.008899, 0.007568,
+	0.006400, 0.005378, 0.004489, 0.003717, 0.003051, 0.002479, 0.001990, 0.001576,
+	0.001228, 0.000939, 0.000701, 0.000507, 0.000353, 0.000233, 0.000141, 0.000073,
+	0.061231, 0.051810, 0.044525, 0.038523, 0.033474, 0.029105, 0.025314, 0.022004,
+	0.019105, 0.016570, 0.014341, 0.012376, 0.010648, 0.009134, 0.007801, 0.006632,
+	0.005611, 0.004720, 0.003943, 0.003270, 0.002689, 0.002191, 0.001765, 0.001403,
+	0.001098, 0.000844, 0.000634, 0.000462, 0.000324, 0.000216, 0.000132, 0.000070,
+	0.056411, 0.047327, 0.040353, 0.034772, 0.030059, 0.026023, 0.022551, 0.019544,
+	0.016927, 0.014643, 0.012643, 0.010892, 0.009361, 0.008019, 0.006843, 0.005816,
+	0.004921, 0.004139, 0.003461, 0.002874, 0.002367, 0.001932, 0.001560, 0.001244,
+	0.000978, 0.000755, 0.000570, 0.000418, 0.000296, 0.000199, 0.000123, 0.000067,
+	0.052190, 0.043388, 0.036682, 0.031472, 0.027054, 0.023315, 0.020137, 0.017395,
+	0.015021, 0.012959, 0.011165, 0.009602, 0.008239, 0.007048, 0.006009, 0.005104,
+	0.004316, 0.003631, 0.003037, 0.002524, 0.002081, 0.001702, 0.001377, 0.001101,
+	0.000868, 0.000672, 0.000510, 0.000377, 0.000268, 0.000182, 0.000114, 0.000063,
+	0.048482, 0.039914, 0.033450, 0.028560, 0.024402, 0.020963, 0.018033, 0.015521,
+	0.013361, 0.011495, 0.009880, 0.008479, 0.007261, 0.006201, 0.005281, 0.004482,
+	0.003788, 0.003186, 0.002665, 0.002216, 0.001829, 0.001497, 0.001213, 0.000972,
+	0.000768, 0.000597, 0.000455, 0.000338, 0.000242, 0.000165, 0.000105, 0.000058,
+	0.045211, 0.036838, 0.030633, 0.025985, 0.022093, 0.018893, 0.016176, 0.013870,
+	0.011903, 0.010213, 0.008756, 0.007497, 0.006407, 0.005464, 0.004648, 0.003940,
+	0.003327, 0.002797, 0.002340, 0.001945, 0.001607, 0.001316, 0.001068, 0.000857,
+	0.000679, 0.000530, 0.000405, 0.000302, 0.000218, 0.000150, 0.000096, 0.000054,
+	0.042316, 0.034119, 0.028150, 0.023700, 0.020055, 0.017057, 0.014541, 0.012430,
+	0.010631, 0.009092, 0.007773, 0.006640, 0.005664, 0.004822, 0.004095, 0.003467,
+	0.002924, 0.002457, 0.002055, 0.001709, 0.001411, 0.001157, 0.000940, 0.000756,
+	0.000600, 0.000469, 0.000359, 0.000269, 0.000195, 0.000135, 0.000087, 0.000050,
+];
+
+/// Cosine weighted average of each row of SCHLICK_WEIGHT_ALBEDO
+#[rustfmt::skip]
+pub static SCHLICK_WEIGHT_AVERAGE_ALBEDO: [f64; 32] = [
+	0.047693, 0.047665, 0.047452, 0.046956, 0.046083, 0.044763, 0.043001, 0.040838,
+	0.038351, 0.035721, 0.032927, 0.030125, 0.027351, 0.024682, 0.022156, 0.019801,
+	0.017635, 0.015662, 0.013881, 0.012284, 0.010861, 0.009598, 0.008483, 0.007499,
+	0.006634, 0.005874, 0.005208, 0.004623, 0.004110, 0.003660, 0.003265, 0.002918,
+];
+
+/// Albedo of a rough dielectric seen from the side with the lower index of refraction, indexed by [eta][roughness][cos]
+#[rustfmt::skip]
+pub static DIELECTRIC_ALBEDO_ENTERING: [f64; 4096] = [
+	0.999818, 0.999989, 0.999997, 0.999998, 0.999999, 0.999999, 0.999999, 0.999999,
+	1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000,
+	0.966296, 0.997472, 0.999695, 0.999868, 0.999908, 0.999929, 0.999944, 0.999957,
+	0.999966, 0.999974, 0.999980, 0.999986, 0.999990, 0.999993, 0.999996, 0.999999,
+	0.916826, 0.980064, 0.994319, 0.997607, 0.998735, 0.999211, 0.999523, 0.999660,
+	0.999738, 0.999799, 0.999848, 0.999888, 0.999921, 0.999949, 0.999972, 0.999991,
+	0.925545, 0.951891, 0.979954, 0.990282, 0.994693, 0.996644, 0.997784, 0.998354,
+	0.998746, 0.999165, 0.999356, 0.999510, 0.999668, 0.999804, 0.999892, 0.999967,
+	0.933548, 0.933242, 0.959476, 0.976682, 0.985879, 0.990722, 0.993511, 0.995446,
+	0.996581, 0.997569, 0.998159, 0.998738, 0.999113, 0.999402, 0.999705, 0.999848,
+	0.925144, 0.917639, 0.938044, 0.957540, 0.971167, 0.980437, 0.985687, 0.989609,
+	0.992423, 0.994321, 0.995792, 0.996985, 0.997909, 0.998677, 0.999217, 0.999674,
+	0.904513, 0.893481, 0.912568, 0.933845, 0.951105, 0.964105, 0.973407, 0.980060,
+	0.985219, 0.988764, 0.991775, 0.993940, 0.995830, 0.997217, 0.998456, 0.999411,
+	0.877596, 0.860738, 0.880715, 0.904437, 0.925936, 0.942852, 0.956270, 0.966542,
+	0.974307, 0.980589, 0.985253, 0.989228, 0.992242, 0.994919, 0.997006, 0.998717,
+	0.848797, 0.823478, 0.843264, 0.869788, 0.895103, 0.916799, 0.934138, 0.948421,
+	0.959922, 0.969291, 0.976625, 0.982556, 0.987430, 0.991502, 0.994917, 0.997735,
+	0.820875, 0.786199, 0.804490, 0.832268, 0.860560, 0.886168, 0.907922, 0.926182,
+	0.941662, 0.953845, 0.964809, 0.973574, 0.980968, 0.986884, 0.992257, 0.996367,
+	0.794685, 0.751868, 0.767176, 0.794952, 0.824209, 0.852520, 0.877907, 0.899848,
+	0.919418, 0.935928, 0.949588, 0.961690, 0.971922, 0.980688, 0.988014, 0.994271,
+	0.770787, 0.721369, 0.733420, 0.759081, 0.788695, 0.818129, 0.845991, 0.871103,
+	0.893920, 0.914197, 0.931556, 0.947372, 0.961019, 0.972720, 0.983019, 0.991667,
+	0.749122, 0.694846, 0.703395, 0.726961, 0.755391, 0.785046, 0.813705, 0.841111,
+	0.866470, 0.889972, 0.910915, 0.929807, 0.947337, 0.962960, 0.976288, 0.988523,
+	0.729546, 0.671981, 0.677499, 0.698579, 0.725212, 0.753827, 0.782675, 0.810972,
+	0.837924, 0.863937, 0.888104, 0.910418, 0.931104, 0.950783, 0.968508, 0.984625,
+	0.711792, 0.652167, 0.655391, 0.673998, 0.698380, 0.725427, 0.753477, 0.781597,
+	0.809643, 0.837102, 0.863552, 0.889161, 0.913869, 0.936748, 0.959057, 0.979813,
+	0.695848, 0.635127, 0.636564, 0.652688, 0.674920, 0.699926, 0.726567, 0.754308,
+	0.782346, 0.810443, 0.838530, 0.866524, 0.894330, 0.921507, 0.948353, 0.973939,
+	0.999792, 0.999986, 0.999996, 0.999999, 0.999999, 0.999999, 1.000000, 1.000000,
+	1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000,
+	0.963215, 0.996908, 0.999613, 0.999883, 0.999938, 0.999958, 0.999968, 0.999974,
+	0.999979, 0.999983, 0.999987, 0.999990, 0.999993, 0.999995, 0.999997, 0.999999,
+	0.909173, 0.975384, 0.992664, 0.996992, 0.998544, 0.999221, 0.999661, 0.999788,
+	0.999835, 0.999869, 0.999897, 0.999921, 0.999943, 0.999961, 0.999978, 0.999993,
+	0.921542, 0.942840, 0.974871, 0.988330, 0.993857, 0.996470, 0.997688, 0.998419,
+	0.998899, 0.999181, 0.999413, 0.999610, 0.999742, 0.999843, 0.999913, 0.999972,
+	0.939749, 0.927482, 0.952608, 0.972423, 0.983839, 0.989709, 0.993187, 0.995296,
+	0.996560, 0.997466, 0.998065, 0.998566, 0.999006, 0.999276, 0.999519, 0.999741,
+	0.946051, 0.923948, 0.936773, 0.955001, 0.969155, 0.978837, 0.984937, 0.989293,
+	0.992254, 0.994220, 0.995460, 0.996796, 0.997559, 0.998255, 0.998904, 0.999331,
+	0.941971, 0.918477, 0.923935, 0.938327, 0.952515, 0.964157, 0.973191, 0.979765,
+	0.984987, 0.988451, 0.991428, 0.993526, 0.995163, 0.996606, 0.997647, 0.998525,
+	0.930326, 0.904072, 0.907615, 0.919980, 0.933651, 0.947016, 0.957959, 0.967224,
+	0.974235, 0.980013, 0.984404, 0.988111, 0.991283, 0.993673, 0.995615, 0.997335,
+	0.914086, 0.882184, 0.884236, 0.896874, 0.911326, 0.926239, 0.939222, 0.950815,
+	0.960355, 0.968455, 0.975163, 0.980775, 0.985133, 0.989387, 0.992381, 0.995050,
+	0.895451, 0.855164, 0.856213, 0.868809, 0.884703, 0.901302, 0.916635, 0.930761,
+	0.943106, 0.953835, 0.963138, 0.970506, 0.977786, 0.983206, 0.988300, 0.992253,
+	0.875861, 0.826537, 0.824594, 0.837026, 0.854031, 0.872564, 0.890303, 0.906973,
+	0.922262, 0.936011, 0.947951, 0.958163, 0.967591, 0.975829, 0.982710, 0.988110,
+	0.856260, 0.798008, 0.792658, 0.803421, 0.820805, 0.840632, 0.860444, 0.879811,
+	0.897792, 0.914267, 0.929198, 0.943323, 0.954688, 0.966206, 0.975406, 0.983063,
+	0.837165, 0.770581, 0.761711, 0.770962, 0.787660, 0.807687, 0.828922, 0.849971,
+	0.870450, 0.889828, 0.907734, 0.924049, 0.940237, 0.953645, 0.966027, 0.976695,
+	0.818783, 0.745310, 0.733152, 0.739990, 0.755575, 0.775547, 0.796939, 0.818812,
+	0.841086, 0.862918, 0.883806, 0.903143, 0.921712, 0.939619, 0.955529, 0.969019,
+	0.801390, 0.722019, 0.706840, 0.711857, 0.725753, 0.744354, 0.765551, 0.787898,
+	0.811149, 0.834375, 0.857633, 0.879686, 0.901641, 0.922427, 0.942661, 0.959801,
+	0.784840, 0.700808, 0.683571, 0.686407, 0.698619, 0.715755, 0.736033, 0.757901,
+	0.781267, 0.805247, 0.829865, 0.854316, 0.878986, 0.903332, 0.927233, 0.949071,
+	0.999785, 0.999984, 0.999996, 0.999998, 0.999999, 1.000000, 1.000000, 1.000000,
+	1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000,
+	0.962383, 0.996663, 0.999576, 0.999874, 0.999939, 0.999963, 0.999974, 0.999980,
+	0.999984, 0.999987, 0.999990, 0.999992, 0.999994, 0.999996, 0.999998, 0.999999,
+	0.907179, 0.973915, 0.991983, 0.996858, 0.998477, 0.999186, 0.999676, 0.999831,
+	0.999874, 0.999901, 0.999921, 0.999939, 0.999955, 0.999969, 0.999982, 0.999994,
+	0.919817, 0.939270, 0.972626, 0.987046, 0.993388, 0.996073, 0.997453, 0.998363,
+	0.998892, 0.999166, 0.999383, 0.999616, 0.999750, 0.999869, 0.999926, 0.999976,
+	0.940075, 0.924154, 0.949459, 0.970196, 0.982228, 0.988926, 0.992615, 0.994980,
+	0.996508, 0.997376, 0.997925, 0.998546, 0.998913, 0.999216, 0.999372, 0.999626,
+	0.950111, 0.924067, 0.934538, 0.952751, 0.966969, 0.977176, 0.984156, 0.988517,
+	0.991644, 0.993729, 0.995326, 0.996413, 0.997353, 0.998062, 0.998516, 0.999087,
+	0.950411, 0.923263, 0.925399, 0.937628, 0.951318, 0.962878, 0.972084, 0.978820,
+	0.983863, 0.987787, 0.990438, 0.992668, 0.994654, 0.995803, 0.996964, 0.997930,
+	0.943765, 0.915666, 0.914165, 0.922927, 0.935441, 0.946932, 0.957342, 0.966028,
+	0.973145, 0.978753, 0.983123, 0.987001, 0.989587, 0.992244, 0.994082, 0.995767,
+	0.932415, 0.900046, 0.897364, 0.904774, 0.916558, 0.928411, 0.940384, 0.950371,
+	0.959268, 0.967113, 0.973480, 0.978719, 0.983433, 0.987020, 0.990249, 0.992669,
+	0.918064, 0.878941, 0.874333, 0.881478, 0.893381, 0.906277, 0.919277, 0.931490,
+	0.942434, 0.952259, 0.960644, 0.968055, 0.974328, 0.979608, 0.984610, 0.988294,
+	0.902141, 0.854228, 0.846807, 0.853461, 0.865583, 0.880103, 0.894686, 0.908704,
+	0.921959, 0.933772, 0.944787, 0.954199, 0.962932, 0.970484, 0.977297, 0.982521,
+	0.885284, 0.828277, 0.817576, 0.822659, 0.834801, 0.849596, 0.865978, 0.881994,
+	0.897356, 0.912220, 0.925294, 0.937882, 0.948894, 0.959060, 0.968071, 0.975177,
+	0.868274, 0.802465, 0.787810, 0.790880, 0.802068, 0.817288, 0.834619, 0.852205,
+	0.869981, 0.886766, 0.902701, 0.917949, 0.932339, 0.944841, 0.956231, 0.966143,
+	0.851594, 0.777459, 0.758844, 0.759663, 0.769643, 0.784524, 0.801931, 0.820301,
+	0.839660, 0.858991, 0.877353, 0.894553, 0.911528, 0.928202, 0.942472, 0.955360,
+	0.835233, 0.753751, 0.731652, 0.730396, 0.738473, 0.752559, 0.769355, 0.788062,
+	0.808279, 0.828485, 0.849097, 0.869426, 0.888957, 0.908045, 0.926102, 0.942270,
+	0.819519, 0.731778, 0.706748, 0.702900, 0.709471, 0.721947, 0.738063, 0.756441,
+	0.776497, 0.797363, 0.819321, 0.841500, 0.863861, 0.885724, 0.906846, 0.927130,
+	0.999782, 0.999983, 0.999996, 0.999998, 0.999999, 1.000000, 1.000000, 1.000000,
+	1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000,
+	0.961991, 0.996586, 0.999547, 0.999866, 0.999937, 0.999964, 0.999977, 0.999983,
+	0.999987, 0.999990, 0.999992, 0.999994, 0.999995, 0.999997, 0.999998, 0.999999,
+	0.906097, 0.973195, 0.991613, 0.996610, 0.998318, 0.999027, 0.999666, 0.999846,
+	0.999892, 0.999917, 0.999935, 0.999950, 0.999963, 0.999974, 0.999985, 0.999995,
+	0.918998, 0.937692, 0.971366, 0.986385, 0.992902, 0.995970, 0.997479, 0.998420,
+	0.998834, 0.999152, 0.999366, 0.999594, 0.999778, 0.999877, 0.999935, 0.999977,
+	0.940201, 0.922645, 0.947427, 0.968637, 0.980960, 0.988286, 0.992184, 0.994798,
+	0.996280, 0.997251, 0.997866, 0.998452, 0.998785, 0.999078, 0.999330, 0.999446,
+	0.951683, 0.923599, 0.933290, 0.950827, 0.965549, 0.976158, 0.983332, 0.987971,
+	0.991193, 0.993343, 0.995001, 0.996187, 0.997022, 0.997734, 0.998283, 0.998697,
+	0.954057, 0.925102, 0.925680, 0.936840, 0.950033, 0.961925, 0.970811, 0.977878,
+	0.983066, 0.986763, 0.989784, 0.992035, 0.993565, 0.995188, 0.996094, 0.997028,
+	0.949801, 0.920408, 0.916907, 0.924127, 0.934737, 0.946271, 0.956417, 0.964862,
+	0.971906, 0.977398, 0.982164, 0.985599, 0.988367, 0.990696, 0.993082, 0.994392,
+	0.940855, 0.908609, 0.903287, 0.908355, 0.917803, 0.929144, 0.939678, 0.949291,
+	0.957816, 0.965006, 0.971633, 0.976474, 0.981157, 0.984921, 0.987845, 0.990195,
+	0.928909, 0.890571, 0.883737, 0.887679, 0.897004, 0.907864, 0.919507, 0.930681,
+	0.940632, 0.949801, 0.958007, 0.965171, 0.971232, 0.976428, 0.981188, 0.984640,
+	0.915052, 0.868897, 0.858863, 0.862258, 0.871399, 0.883068, 0.895618, 0.908209,
+	0.920439, 0.931355, 0.941348, 0.950404, 0.958669, 0.966061, 0.971846, 0.976915,
+	0.900031, 0.844679, 0.831456, 0.833087, 0.841923, 0.854251, 0.867412, 0.881619,
+	0.895430, 0.908940, 0.920546, 0.932431, 0.942813, 0.952017, 0.960636, 0.967809,
+	0.884584, 0.820120, 0.802646, 0.802075, 0.810117, 0.821988, 0.836369, 0.851838,
+	0.867438, 0.882515, 0.896905, 0.911022, 0.924099, 0.936355, 0.946731, 0.955919,
+	0.869015, 0.795880, 0.774176, 0.771058, 0.777364, 0.788841, 0.803472, 0.819408,
+	0.836148, 0.852837, 0.870175, 0.886002, 0.901658, 0.916717, 0.929906, 0.942423,
+	0.853617, 0.772293, 0.746633, 0.740992, 0.745667, 0.756290, 0.770238, 0.786175,
+	0.803338, 0.821405, 0.840451, 0.858758, 0.876844, 0.894147, 0.910995, 0.925789,
+	0.838595, 0.749792, 0.720579, 0.712962, 0.715560, 0.724647, 0.737485, 0.752740,
+	0.770020, 0.789056, 0.808764, 0.828806, 0.848713, 0.869118, 0.888826, 0.906961,
+	0.999780, 0.999983, 0.999996, 0.999998, 0.999999, 1.000000, 1.000000, 1.000000,
+	1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000,
+	0.961779, 0.996555, 0.999528, 0.999860, 0.999935, 0.999964, 0.999977, 0.999984,
+	0.999988, 0.999991, 0.999993, 0.999994, 0.999996, 0.999997, 0.999998, 0.999999,
+	0.905636, 0.972847, 0.991505, 0.996468, 0.998292, 0.999024, 0.999668, 0.999852,
+	0.999901, 0.999925, 0.999943, 0.999956, 0.999967, 0.999978, 0.999987, 0.999996,
+	0.918731, 0.936981, 0.970643, 0.985926, 0.992591, 0.995646, 0.997299, 0.998303,
+	0.998795, 0.999088, 0.999388, 0.999569, 0.999779, 0.999889, 0.999938, 0.999978,
+	0.940318, 0.921591, 0.946279, 0.967937, 0.980476, 0.987714, 0.991856, 0.994513,
+	0.995874, 0.997134, 0.997753, 0.998156, 0.998681, 0.998973, 0.999098, 0.999384,
+	0.952585, 0.923172, 0.932187, 0.949431, 0.964646, 0.975498, 0.982732, 0.987423,
+	0.990618, 0.992755, 0.994415, 0.995777, 0.996714, 0.997294, 0.997907, 0.998368,
+	0.956100, 0.926150, 0.925464, 0.935780, 0.949279, 0.960791, 0.970032, 0.977093,
+	0.982223, 0.986081, 0.989049, 0.991208, 0.992982, 0.994349, 0.995686, 0.996337,
+	0.953252, 0.923254, 0.918481, 0.924049, 0.934560, 0.945529, 0.955248, 0.963570,
+	0.970664, 0.976298, 0.980592, 0.984326, 0.987257, 0.989735, 0.991628, 0.993173,
+	0.945913, 0.913655, 0.906636, 0.910261, 0.918696, 0.928346, 0.938594, 0.948168,
+	0.956313, 0.963260, 0.969584, 0.974734, 0.979063, 0.982606, 0.985657, 0.988120,
+	0.935537, 0.897698, 0.889104, 0.891380, 0.898923, 0.908640, 0.918740, 0.929349,
+	0.938831, 0.947664, 0.955227, 0.962105, 0.968239, 0.973343, 0.977521, 0.981370,
+	0.923084, 0.877907, 0.866233, 0.867417, 0.874719, 0.884551, 0.895515, 0.906799,
+	0.917662, 0.928018, 0.937708, 0.946407, 0.953902, 0.960647, 0.967102, 0.972083,
+	0.909344, 0.855311, 0.839929, 0.839734, 0.846045, 0.856307, 0.867968, 0.880154,
+	0.892807, 0.904782, 0.916525, 0.926867, 0.937481, 0.945914, 0.953713, 0.960533,
+	0.894839, 0.831826, 0.812127, 0.809251, 0.814565, 0.824628, 0.836609, 0.850277,
+	0.863917, 0.877812, 0.891136, 0.904666, 0.916065, 0.927288, 0.937643, 0.946782,
+	0.880126, 0.808109, 0.783954, 0.778320, 0.782012, 0.791067, 0.803358, 0.817250,
+	0.832160, 0.847352, 0.862757, 0.878158, 0.892310, 0.906135, 0.919158, 0.929920,
+	0.865535, 0.784721, 0.756355, 0.747758, 0.749512, 0.757298, 0.768837, 0.783089,
+	0.798546, 0.814489, 0.831191, 0.848191, 0.864655, 0.880886, 0.896571, 0.910542,
+	0.850994, 0.762325, 0.729991, 0.718699, 0.718268, 0.724539, 0.735241, 0.748638,
+	0.763857, 0.780500, 0.797981, 0.815914, 0.834791, 0.853563, 0.872094, 0.888887,
+	0.999779, 0.999983, 0.999995, 0.999998, 0.999999, 1.000000, 1.000000, 1.000000,
+	1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000,
+	0.961754, 0.996539, 0.999519, 0.999855, 0.999933, 0.999963, 0.999977, 0.999985,
+	0.999989, 0.999991, 0.999993, 0.999995, 0.999996, 0.999997, 0.999999, 1.000000,
+	0.905365, 0.972708, 0.991328, 0.996435, 0.998271, 0.999016, 0.999668, 0.999855,
+	0.999904, 0.999930, 0.999947, 0.999960, 0.999971, 0.999980, 0.999988, 0.999996,
+	0.918674, 0.936475, 0.970392, 0.985796, 0.992511, 0.995554, 0.997290, 0.998221,
+	0.998748, 0.999042, 0.999401, 0.999520, 0.999774, 0.999889, 0.999942, 0.999977,
+	0.940407, 0.921254, 0.945782, 0.967265, 0.980196, 0.987244, 0.991459, 0.994164,
+	0.995810, 0.996986, 0.997540, 0.998057, 0.998584, 0.998737, 0.998978, 0.999137,
+	0.953215, 0.923072, 0.931796, 0.948872, 0.964061, 0.974744, 0.981861, 0.986994,
+	0.990160, 0.992573, 0.994193, 0.995498, 0.996440, 0.997177, 0.997759, 0.998034,
+	0.957454, 0.926842, 0.925357, 0.935402, 0.948533, 0.960013, 0.969184, 0.976274,
+	0.981676, 0.985242, 0.988417, 0.990664, 0.992385, 0.993614, 0.994819, 0.995887,
+	0.955550, 0.925033, 0.919414, 0.924261, 0.934363, 0.944452, 0.954330, 0.962437,
+	0.969475, 0.975293, 0.979484, 0.983290, 0.986239, 0.988261, 0.990429, 0.991703,
+	0.949197, 0.916833, 0.908816, 0.911191, 0.918636, 0.928012, 0.937390, 0.946717,
+	0.954749, 0.961588, 0.967582, 0.972312, 0.976813, 0.980504, 0.983685, 0.986132,
+	0.939892, 0.902414, 0.892246, 0.893613, 0.900047, 0.908513, 0.918116, 0.927630,
+	0.936883, 0.945111, 0.952452, 0.959193, 0.964975, 0.969923, 0.974402, 0.977723,
+	0.928480, 0.883809, 0.870818, 0.870551, 0.876526, 0.884854, 0.894965, 0.905187,
+	0.915798, 0.925165, 0.934120, 0.942065, 0.950089, 0.956451, 0.962052, 0.967219,
+	0.915621, 0.862263, 0.845461, 0.843469, 0.848347, 0.856994, 0.867304, 0.878452,
+	0.890137, 0.901091, 0.911716, 0.922004, 0.930849, 0.939517, 0.947254, 0.953296,
+	0.901873, 0.839430, 0.818338, 0.813717, 0.817025, 0.825061, 0.835767, 0.847676,
+	0.860688, 0.872950, 0.885078, 0.897348, 0.908899, 0.919230, 0.929364, 0.937298,
+	0.887775, 0.816030, 0.790521, 0.782690, 0.784563, 0.791479, 0.801683, 0.814097,
+	0.827676, 0.841546, 0.855554, 0.869073, 0.882726, 0.895742, 0.907632, 0.918241,
+	0.873561, 0.793006, 0.762833, 0.752045, 0.751302, 0.757198, 0.766681, 0.778778,
+	0.792356, 0.807155, 0.822577, 0.837846, 0.853085, 0.869075, 0.882973, 0.896492,
+	0.859450, 0.770452, 0.736130, 0.722370, 0.719713, 0.723655, 0.731890, 0.743165,
+	0.756724, 0.771752, 0.787738, 0.804083, 0.821579, 0.838639, 0.855749, 0.871936,
+	0.999779, 0.999983, 0.999995, 0.999998, 0.999999, 1.000000, 1.000000, 1.000000,
+	1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000,
+	0.961740, 0.996530, 0.999514, 0.999852, 0.999931, 0.999963, 0.999977, 0.999985,
+	0.999989, 0.999992, 0.999994, 0.999995, 0.999997, 0.999998, 0.999999, 1.000000,
+	0.905345, 0.972651, 0.991273, 0.996404, 0.998196, 0.999007, 0.999664, 0.999855,
+	0.999905, 0.999932, 0.999949, 0.999962, 0.999972, 0.999981, 0.999989, 0.999996,
+	0.918660, 0.936310, 0.970140, 0.985627, 0.992361, 0.995517, 0.997242, 0.998151,
+	0.998748, 0.999044, 0.999273, 0.999524, 0.999745, 0.999889, 0.999941, 0.999976,
+	0.940538, 0.920992, 0.945482, 0.966933, 0.979598, 0.986953, 0.991289, 0.993825,
+	0.995642, 0.996743, 0.997523, 0.998044, 0.998390, 0.998744, 0.998916, 0.999011,
+	0.953660, 0.923043, 0.931333, 0.948399, 0.963498, 0.974119, 0.981427, 0.986545,
+	0.989799, 0.992282, 0.993746, 0.995127, 0.995942, 0.996793, 0.997367, 0.997817,
+	0.958386, 0.927406, 0.925241, 0.934965, 0.947946, 0.959395, 0.968462, 0.975512,
+	0.980824, 0.984497, 0.987715, 0.989751, 0.991622, 0.993018, 0.994038, 0.995071,
+	0.957130, 0.926316, 0.919963, 0.924336, 0.933593, 0.943724, 0.953331, 0.961524,
+	0.968082, 0.973850, 0.978282, 0.981824, 0.984734, 0.987244, 0.989357, 0.990609,
+	0.951485, 0.919135, 0.910246, 0.911737, 0.918454, 0.927447, 0.936578, 0.945384,
+	0.953178, 0.959889, 0.965831, 0.970822, 0.975233, 0.978596, 0.981562, 0.983962,
+	0.942949, 0.905747, 0.894640, 0.894788, 0.900237, 0.908334, 0.917148, 0.926085,
+	0.934844, 0.943000, 0.950131, 0.956478, 0.961914, 0.966909, 0.970998, 0.974620,
+	0.932227, 0.887998, 0.874166, 0.872394, 0.877413, 0.884671, 0.893885, 0.903447,
+	0.913196, 0.922187, 0.930738, 0.938439, 0.945337, 0.952105, 0.957646, 0.961879,
+	0.920011, 0.867476, 0.849495, 0.846056, 0.849550, 0.856791, 0.866047, 0.876106,
+	0.886760, 0.897103, 0.907499, 0.916749, 0.925345, 0.933707, 0.940784, 0.947068,
+	0.906885, 0.845142, 0.822937, 0.816681, 0.818550, 0.825080, 0.834596, 0.844967,
+	0.856401, 0.867908, 0.879368, 0.890681, 0.901613, 0.911262, 0.920633, 0.928637,
+	0.893292, 0.822196, 0.795290, 0.785822, 0.785695, 0.791277, 0.799662, 0.810414,
+	0.822665, 0.835517, 0.848202, 0.861144, 0.873814, 0.886109, 0.897164, 0.907486,
+	0.879539, 0.799408, 0.767593, 0.755021, 0.752559, 0.756409, 0.763932, 0.774057,
+	0.786741, 0.800094, 0.813991, 0.828088, 0.842429, 0.856809, 0.870696, 0.883019,
+	0.865768, 0.776894, 0.740646, 0.724846, 0.720218, 0.722015, 0.728271, 0.737784,
+	0.749516, 0.763138, 0.777749, 0.793143, 0.808827, 0.825225, 0.841018, 0.856083,
+	0.999779, 0.999983, 0.999995, 0.999998, 0.999999, 1.000000, 1.000000, 1.000000,
+	1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000,
+	0.961756, 0.996529, 0.999511, 0.999850, 0.999930, 0.999962, 0.999976, 0.999984,
+	0.999989, 0.999992, 0.999994, 0.999995, 0.999997, 0.999998, 0.999999, 1.000000,
+	0.905512, 0.972652, 0.991240, 0.996388, 0.998122, 0.999000, 0.999654, 0.999851,
+	0.999905, 0.999933, 0.999950, 0.999963, 0.999973, 0.999982, 0.999989, 0.999996,
+	0.918770, 0.936348, 0.970152, 0.985580, 0.992314, 0.995491, 0.997221, 0.998136,
+	0.998743, 0.999044, 0.999275, 0.999527, 0.999745, 0.999891, 0.999940, 0.999975,
+	0.940694, 0.921022, 0.945326, 0.966672, 0.979333, 0.986733, 0.991165, 0.993598,
+	0.995431, 0.996377, 0.997399, 0.997865, 0.998266, 0.998613, 0.998733, 0.998883,
+	0.954024, 0.923029, 0.931194, 0.948066, 0.963027, 0.973637, 0.980978, 0.986087,
+	0.989395, 0.991856, 0.993366, 0.994700, 0.995601, 0.996383, 0.996890, 0.997474,
+	0.959078, 0.927776, 0.925191, 0.934691, 0.947646, 0.958891, 0.967803, 0.974548,
+	0.980089, 0.984024, 0.986892, 0.989349, 0.991109, 0.992514, 0.993612, 0.994478,
+	0.958230, 0.927193, 0.920246, 0.924242, 0.933162, 0.943269, 0.952425, 0.960501,
+	0.967282, 0.972843, 0.977288, 0.980695, 0.983846, 0.986319, 0.988077, 0.989560,
+	0.953115, 0.920740, 0.911089, 0.911920, 0.918390, 0.926826, 0.935602, 0.944040,
+	0.951342, 0.958183, 0.964023, 0.969022, 0.972895, 0.976801, 0.979693, 0.982121,
+	0.945135, 0.908078, 0.896273, 0.895416, 0.900324, 0.907682, 0.916192, 0.924544,
+	0.932991, 0.940440, 0.947273, 0.953533, 0.959067, 0.963978, 0.967963, 0.971504,
+	0.935014, 0.891212, 0.876576, 0.873713, 0.877552, 0.884114, 0.892710, 0.901293,
+	0.910549, 0.919114, 0.927413, 0.934591, 0.941528, 0.947753, 0.953144, 0.957740,
+	0.923391, 0.871353, 0.852588, 0.847767, 0.850193, 0.856181, 0.864780, 0.873704,
+	0.883521, 0.893334, 0.902520, 0.911658, 0.919652, 0.927270, 0.934396, 0.940760,
+	0.910684, 0.849590, 0.826517, 0.818724, 0.819400, 0.824542, 0.832842, 0.841968,
+	0.852471, 0.863397, 0.873551, 0.884097, 0.894661, 0.903957, 0.912629, 0.920467,
+	0.897528, 0.826962, 0.798963, 0.788024, 0.786464, 0.790468, 0.797480, 0.807112,
+	0.817562, 0.829687, 0.841485, 0.853527, 0.865060, 0.876559, 0.887251, 0.896667,
+	0.884129, 0.804302, 0.771203, 0.757158, 0.752915, 0.755164, 0.761306, 0.770097,
+	0.780712, 0.792919, 0.805764, 0.819158, 0.832658, 0.845609, 0.858890, 0.870222,
+	0.870657, 0.781869, 0.744048, 0.726425, 0.719868, 0.720229, 0.725078, 0.732963,
+	0.742891, 0.754739, 0.767954, 0.782016, 0.796524, 0.812129, 0.827015, 0.841556,
+	0.999780, 0.999983, 0.999995, 0.999998, 0.999999, 1.000000, 1.000000, 1.000000,
+	1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000,
+	0.961785, 0.996531, 0.999509, 0.999848, 0.999928, 0.999961, 0.999976, 0.999984,
+	0.999989, 0.999992, 0.999994, 0.999995, 0.999997, 0.999998, 0.999999, 1.000000,
+	0.905638, 0.972693, 0.991228, 0.996322, 0.998112, 0.998992, 0.999649, 0.999845,
+	0.999902, 0.999931, 0.999950, 0.999963, 0.999974, 0.999982, 0.999990, 0.999996,
+	0.918941, 0.936631, 0.970128, 0.985546, 0.992257, 0.995422, 0.997139, 0.998123,
+	0.998662, 0.998979, 0.999211, 0.999438, 0.999746, 0.999882, 0.999939, 0.999974,
+	0.940933, 0.921192, 0.945353, 0.966620, 0.979315, 0.986538, 0.991028, 0.993484,
+	0.995316, 0.996230, 0.997085, 0.997663, 0.998196, 0.998365, 0.998663, 0.998755,
+	0.954380, 0.923421, 0.931364, 0.947981, 0.962742, 0.973324, 0.980645, 0.985813,
+	0.989092, 0.991568, 0.993110, 0.994498, 0.995368, 0.996164, 0.996727, 0.997134,
+	0.959716, 0.928226, 0.925271, 0.934587, 0.947343, 0.958103, 0.967123, 0.973934,
+	0.979458, 0.983231, 0.986141, 0.988816, 0.990149, 0.991860, 0.992672, 0.993830,
+	0.959177, 0.928015, 0.920581, 0.924173, 0.932926, 0.942390, 0.951613, 0.959588,
+	0.966249, 0.971685, 0.976057, 0.979542, 0.982616, 0.985025, 0.986914, 0.988473,
+	0.954495, 0.922041, 0.911811, 0.912040, 0.917983, 0.925892, 0.934601, 0.942650,
+	0.949918, 0.956531, 0.962375, 0.967554, 0.971206, 0.974788, 0.977454, 0.980145,
+	0.946958, 0.910057, 0.897652, 0.895860, 0.899910, 0.906973, 0.914899, 0.922941,
+	0.930973, 0.938300, 0.944713, 0.950626, 0.956637, 0.961031, 0.965124, 0.968383,
+	0.937286, 0.893757, 0.878445, 0.874628, 0.877517, 0.883432, 0.891190, 0.899320,
+	0.907783, 0.916064, 0.924022, 0.931070, 0.937334, 0.943622, 0.948424, 0.953055,
+	0.926061, 0.874316, 0.854936, 0.849109, 0.850408, 0.855347, 0.862996, 0.871258,
+	0.880334, 0.889384, 0.898259, 0.906575, 0.914904, 0.922063, 0.928852, 0.934521,
+	0.913791, 0.853021, 0.829061, 0.820119, 0.819531, 0.823428, 0.830475, 0.839086,
+	0.848585, 0.858630, 0.868603, 0.878242, 0.887879, 0.896960, 0.904931, 0.912209,
+	0.900953, 0.830792, 0.801604, 0.789322, 0.786473, 0.789086, 0.795248, 0.803497,
+	0.813283, 0.823962, 0.834587, 0.846442, 0.856656, 0.867331, 0.878250, 0.886675,
+	0.887856, 0.808054, 0.773869, 0.758405, 0.752732, 0.753286, 0.758147, 0.765825,
+	0.775725, 0.786462, 0.797605, 0.810283, 0.822924, 0.835230, 0.847219, 0.858487,
+	0.874579, 0.785683, 0.746538, 0.727514, 0.719170, 0.717625, 0.721292, 0.727947,
+	0.736578, 0.747130, 0.759100, 0.772022, 0.785297, 0.799762, 0.814070, 0.827457,
+	0.999780, 0.999983, 0.999995, 0.999998, 0.999999, 0.999999, 1.000000, 1.000000,
+	1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000,
+	0.961893, 0.996536, 0.999508, 0.999847, 0.999927, 0.999960, 0.999975, 0.999983,
+	0.999988, 0.999992, 0.999994, 0.999995, 0.999997, 0.999998, 0.999999, 1.000000,
+	0.905817, 0.972739, 0.991298, 0.996367, 0.998102, 0.998984, 0.999636, 0.999841,
+	0.999900, 0.999930, 0.999949, 0.999963, 0.999974, 0.999982, 0.999990, 0.999996,
+	0.919102, 0.936748, 0.970168, 0.985514, 0.992210, 0.995391, 0.997063, 0.998105,
+	0.998648, 0.998909, 0.999206, 0.999436, 0.999734, 0.999882, 0.999936, 0.999972,
+	0.941218, 0.921337, 0.945409, 0.966513, 0.979215, 0.986425, 0.990885, 0.993366,
+	0.995156, 0.996203, 0.996939, 0.997593, 0.997973, 0.998233, 0.998472, 0.998688,
+	0.954738, 0.923588, 0.931305, 0.947744, 0.962514, 0.973072, 0.980282, 0.985447,
+	0.988819, 0.991151, 0.992908, 0.993973, 0.995011, 0.995709, 0.996379, 0.996793,
+	0.960209, 0.928557, 0.925441, 0.934317, 0.946922, 0.957683, 0.966550, 0.973469,
+	0.978929, 0.982800, 0.985663, 0.988137, 0.989697, 0.991296, 0.992424, 0.993191,
+	0.959894, 0.928631, 0.920783, 0.923945, 0.932343, 0.941952, 0.950954, 0.958692,
+	0.965145, 0.970807, 0.975040, 0.978589, 0.981477, 0.983832, 0.985624, 0.987569,
+	0.955632, 0.923009, 0.912426, 0.912169, 0.917484, 0.925277, 0.933462, 0.941525,
+	0.948694, 0.955037, 0.960398, 0.965194, 0.969535, 0.972829, 0.975819, 0.978291,
+	0.948374, 0.911534, 0.898597, 0.896277, 0.899925, 0.905914, 0.913617, 0.921460,
+	0.928965, 0.935811, 0.942314, 0.948507, 0.953657, 0.957955, 0.962222, 0.965670,
+	0.939072, 0.895777, 0.879891, 0.875370, 0.877490, 0.882583, 0.889733, 0.897361,
+	0.905244, 0.913164, 0.920807, 0.927150, 0.933533, 0.939775, 0.944476, 0.948834,
+	0.928215, 0.876641, 0.856562, 0.849939, 0.850451, 0.854389, 0.861307, 0.868985,
+	0.877294, 0.885730, 0.893811, 0.902111, 0.909551, 0.916384, 0.923205, 0.929106,
+	0.916249, 0.855723, 0.830961, 0.821200, 0.819479, 0.822597, 0.828245, 0.836196,
+	0.844559, 0.854418, 0.863081, 0.872688, 0.881614, 0.890076, 0.897741, 0.904570,
+	0.903688, 0.833749, 0.803839, 0.790310, 0.786210, 0.787861, 0.792748, 0.799819,
+	0.808514, 0.818842, 0.828734, 0.838709, 0.848978, 0.859000, 0.868587, 0.877606,
+	0.890851, 0.811192, 0.775917, 0.759124, 0.752374, 0.751675, 0.755156, 0.761610,
+	0.769953, 0.780169, 0.790599, 0.801748, 0.813479, 0.824968, 0.836280, 0.847138,
+	0.877757, 0.788883, 0.748525, 0.728080, 0.718384, 0.715538, 0.717488, 0.722920,
+	0.730362, 0.739619, 0.750592, 0.762521, 0.774816, 0.787828, 0.801283, 0.814230,
+	0.999781, 0.999983, 0.999995, 0.999998, 0.999999, 0.999999, 1.000000, 1.000000,
+	1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000,
+	0.961972, 0.996542, 0.999509, 0.999846, 0.999926, 0.999959, 0.999974, 0.999983,
+	0.999988, 0.999991, 0.999994, 0.999995, 0.999997, 0.999998, 0.999999, 1.000000,
+	0.906064, 0.972794, 0.991321, 0.996360, 0.998092, 0.998977, 0.999630, 0.999836,
+	0.999897, 0.999928, 0.999948, 0.999962, 0.999973, 0.999982, 0.999990, 0.999996,
+	0.919322, 0.936910, 0.970264, 0.985547, 0.992180, 0.995356, 0.996978, 0.998032,
+	0.998568, 0.998896, 0.999200, 0.999431, 0.999706, 0.999875, 0.999935, 0.999970,
+	0.941445, 0.921716, 0.945437, 0.966551, 0.979132, 0.986236, 0.990683, 0.993250,
+	0.995015, 0.996095, 0.996775, 0.997367, 0.997903, 0.998152, 0.998282, 0.998499,
+	0.955014, 0.924082, 0.931501, 0.947716, 0.962257, 0.972800, 0.979996, 0.985165,
+	0.988562, 0.990855, 0.992636, 0.993845, 0.994869, 0.995662, 0.996201, 0.996572,
+	0.960700, 0.928958, 0.925707, 0.934271, 0.946542, 0.957225, 0.966021, 0.972885,
+	0.978316, 0.982048, 0.985112, 0.987649, 0.989270, 0.990623, 0.991883, 0.992780,
+	0.960562, 0.929277, 0.921020, 0.924033, 0.931929, 0.941105, 0.950285, 0.957885,
+	0.964127, 0.969623, 0.973932, 0.977406, 0.980366, 0.982734, 0.984444, 0.986170,
+	0.956509, 0.923835, 0.912917, 0.912433, 0.917196, 0.924464, 0.932478, 0.940222,
+	0.947238, 0.953393, 0.958854, 0.963727, 0.967699, 0.970833, 0.974151, 0.976615,
+	0.949559, 0.912734, 0.899465, 0.896368, 0.899723, 0.905097, 0.912129, 0.919497,
+	0.926958, 0.933769, 0.940067, 0.945502, 0.951015, 0.955502, 0.959282, 0.962527,
+	0.940578, 0.897448, 0.880921, 0.875747, 0.877280, 0.881560, 0.888134, 0.895274,
+	0.902855, 0.910230, 0.917467, 0.923822, 0.929785, 0.935366, 0.940442, 0.945006,
+	0.929993, 0.878622, 0.857929, 0.850585, 0.850293, 0.853536, 0.859580, 0.866754,
+	0.873961, 0.882360, 0.889667, 0.897795, 0.904629, 0.911827, 0.917483, 0.923472,
+	0.918302, 0.857996, 0.832602, 0.821950, 0.819100, 0.821521, 0.826337, 0.833382,
+	0.841047, 0.849719, 0.858296, 0.866550, 0.875122, 0.883142, 0.891040, 0.897396,
+	0.906011, 0.836219, 0.805395, 0.790998, 0.785949, 0.786264, 0.790308, 0.796508,
+	0.803919, 0.813346, 0.822561, 0.832091, 0.841618, 0.851079, 0.860142, 0.868298,
+	0.893350, 0.813741, 0.777477, 0.759497, 0.751673, 0.750097, 0.752162, 0.757508,
+	0.764682, 0.773680, 0.783446, 0.793989, 0.804179, 0.815573, 0.826599, 0.836322,
+	0.880408, 0.791358, 0.750038, 0.728167, 0.717248, 0.713166, 0.713801, 0.718087,
+	0.724731, 0.732918, 0.742449, 0.753200, 0.764841, 0.776734, 0.789744, 0.801641,
+	0.999782, 0.999983, 0.999995, 0.999998, 0.999999, 0.999999, 1.000000, 1.000000,
+	1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000,
+	0.962063, 0.996551, 0.999510, 0.999845, 0.999925, 0.999958, 0.999974, 0.999982,
+	0.999988, 0.999991, 0.999993, 0.999995, 0.999997, 0.999998, 0.999999, 1.000000,
+	0.906199, 0.972958, 0.991388, 0.996353, 0.998144, 0.998969, 0.999624, 0.999831,
+	0.999893, 0.999925, 0.999947, 0.999961, 0.999973, 0.999982, 0.999990, 0.999996,
+	0.919560, 0.937179, 0.970334, 0.985528, 0.992150, 0.995325, 0.996953, 0.998010,
+	0.998551, 0.998882, 0.999193, 0.999366, 0.999703, 0.999873, 0.999931, 0.999968,
+	0.941652, 0.922120, 0.945650, 0.966579, 0.979068, 0.986152, 0.990603, 0.993184,
+	0.994916, 0.995931, 0.996752, 0.997281, 0.997829, 0.998084, 0.998272, 0.998370,
+	0.955338, 0.924504, 0.931588, 0.947814, 0.962225, 0.972621, 0.979775, 0.984791,
+	0.988257, 0.990542, 0.992417, 0.993592, 0.994453, 0.995350, 0.995921, 0.996348,
+	0.961136, 0.929247, 0.925867, 0.934325, 0.946339, 0.956949, 0.965525, 0.972401,
+	0.977668, 0.981511, 0.984646, 0.986942, 0.988678, 0.990001, 0.991062, 0.992067,
+	0.961177, 0.929924, 0.921273, 0.923961, 0.931753, 0.940531, 0.949392, 0.957099,
+	0.963105, 0.968844, 0.972989, 0.976420, 0.979113, 0.981941, 0.983849, 0.985386,
+	0.957258, 0.924673, 0.913312, 0.912559, 0.917043, 0.923904, 0.931362, 0.938994,
+	0.945816, 0.951628, 0.957123, 0.961621, 0.966005, 0.969127, 0.972538, 0.974755,
+	0.950607, 0.913725, 0.900236, 0.896486, 0.899330, 0.904328, 0.910940, 0.918063,
+	0.925137, 0.931829, 0.937780, 0.943099, 0.948032, 0.952772, 0.956580, 0.959843,
+	0.941855, 0.898787, 0.881680, 0.876051, 0.877040, 0.880767, 0.886765, 0.893200,
+	0.900382, 0.907622, 0.913965, 0.920588, 0.926414, 0.931659, 0.936925, 0.941329,
+	0.931595, 0.880221, 0.859011, 0.850921, 0.849747, 0.852389, 0.857795, 0.863983,
+	0.871083, 0.878660, 0.885650, 0.893118, 0.899891, 0.906344, 0.912026, 0.917806,
+	0.920101, 0.859756, 0.833912, 0.822267, 0.818614, 0.820286, 0.824287, 0.830520,
+	0.837438, 0.845351, 0.853275, 0.861271, 0.868905, 0.876849, 0.884161, 0.890659,
+	0.908047, 0.838161, 0.806557, 0.791295, 0.785297, 0.784571, 0.787716, 0.793197,
+	0.799915, 0.807873, 0.816356, 0.825399, 0.834120, 0.843129, 0.851765, 0.859578,
+	0.895402, 0.815825, 0.778700, 0.759610, 0.750590, 0.747757, 0.749307, 0.753449,
+	0.759633, 0.767948, 0.776720, 0.786333, 0.796032, 0.806094, 0.816461, 0.826121,
+	0.882600, 0.793414, 0.751031, 0.727917, 0.715793, 0.710518, 0.710164, 0.713161,
+	0.718796, 0.726053, 0.734888, 0.744422, 0.754943, 0.766550, 0.778722, 0.789981,
+	0.999783, 0.999983, 0.999995, 0.999998, 0.999999, 0.999999, 1.000000, 1.000000,
+	1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000,
+	0.962141, 0.996559, 0.999518, 0.999844, 0.999924, 0.999957, 0.999973, 0.999982,
+	0.999987, 0.999991, 0.999993, 0.999995, 0.999997, 0.999998, 0.999999, 1.000000,
+	0.906693, 0.973034, 0.991398, 0.996347, 0.998135, 0.998961, 0.999617, 0.999827,
+	0.999890, 0.999923, 0.999945, 0.999960, 0.999972, 0.999982, 0.999989, 0.999996,
+	0.920012, 0.937423, 0.970458, 0.985576, 0.992122, 0.995294, 0.996926, 0.997967,
+	0.998537, 0.998838, 0.999120, 0.999361, 0.999700, 0.999864, 0.999927, 0.999967,
+	0.941927, 0.922469, 0.945744, 0.966630, 0.978984, 0.986023, 0.990470, 0.993064,
+	0.994655, 0.995831, 0.996544, 0.997100, 0.997617, 0.997952, 0.998204, 0.998242,
+	0.955664, 0.924636, 0.931631, 0.947833, 0.961985, 0.972277, 0.979483, 0.984526,
+	0.987943, 0.990135, 0.991987, 0.993283, 0.994098, 0.994795, 0.995495, 0.996008,
+	0.961489, 0.929654, 0.926093, 0.934496, 0.946059, 0.956534, 0.965113, 0.971782,
+	0.977035, 0.980990, 0.983825, 0.986329, 0.987946, 0.989528, 0.990645, 0.991553,
+	0.961634, 0.930290, 0.921341, 0.923999, 0.931419, 0.940019, 0.948617, 0.956031,
+	0.962131, 0.967610, 0.971900, 0.975255, 0.978148, 0.980965, 0.982925, 0.984406,
+	0.957924, 0.925299, 0.913631, 0.912533, 0.916847, 0.923167, 0.930355, 0.937811,
+	0.944420, 0.950301, 0.955762, 0.960484, 0.964180, 0.967721, 0.970555, 0.972904,
+	0.951513, 0.914552, 0.900778, 0.896681, 0.898956, 0.903526, 0.909735, 0.916325,
+	0.923147, 0.929651, 0.935335, 0.940884, 0.945779, 0.950058, 0.954213, 0.957343,
+	0.942957, 0.899802, 0.882400, 0.876181, 0.876632, 0.879972, 0.885202, 0.891316,
+	0.897950, 0.904593, 0.911032, 0.916998, 0.922538, 0.927921, 0.932592, 0.937458,
+	0.932831, 0.881535, 0.860043, 0.851480, 0.849486, 0.851531, 0.855909, 0.861594,
+	0.868298, 0.874959, 0.881871, 0.888930, 0.895408, 0.901514, 0.907414, 0.912560,
+	0.921582, 0.861294, 0.834903, 0.822562, 0.818057, 0.818621, 0.822163, 0.827487,
+	0.834126, 0.841094, 0.848363, 0.855931, 0.863625, 0.870507, 0.877456, 0.883985,
+	0.909714, 0.839830, 0.807653, 0.791471, 0.784610, 0.782935, 0.785219, 0.789702,
+	0.795491, 0.802960, 0.810764, 0.818768, 0.826911, 0.835733, 0.843876, 0.851622,
+	0.897216, 0.817567, 0.779821, 0.759633, 0.749723, 0.745630, 0.746072, 0.749214,
+	0.754631, 0.762016, 0.770230, 0.778541, 0.787920, 0.797378, 0.807250, 0.816325,
+	0.884550, 0.795317, 0.751905, 0.727805, 0.714557, 0.708233, 0.706545, 0.708418,
+	0.712961, 0.719476, 0.727484, 0.736230, 0.746118, 0.756937, 0.767932, 0.779170,
+	0.999784, 0.999983, 0.999995, 0.999998, 0.999999, 0.999999, 1.000000, 1.000000,
+	1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000,
+	0.962287, 0.996568, 0.999519, 0.999843, 0.999923, 0.999956, 0.999972, 0.999981,
+	0.999987, 0.999990, 0.999993, 0.999995, 0.999996, 0.999998, 0.999999, 1.000000,
+	0.907036, 0.973100, 0.991471, 0.996344, 0.998190, 0.998953, 0.999610, 0.999821,
+	0.999886, 0.999920, 0.999943, 0.999959, 0.999971, 0.999981, 0.999989, 0.999996,
+	0.920245, 0.937751, 0.970634, 0.985682, 0.992089, 0.995266, 0.996900, 0.997937,
+	0.998519, 0.998826, 0.999111, 0.999352, 0.999669, 0.999861, 0.999926, 0.999965,
+	0.942115, 0.922737, 0.945952, 0.966596, 0.978816, 0.985832, 0.990255, 0.992858,
+	0.994490, 0.995604, 0.996322, 0.996961, 0.997478, 0.997749, 0.997890, 0.998113,
+	0.955858, 0.924963, 0.931656, 0.947647, 0.961845, 0.971964, 0.979154, 0.984291,
+	0.987702, 0.989838, 0.991756, 0.992993, 0.994041, 0.994650, 0.995339, 0.995852,
+	0.961664, 0.930257, 0.926231, 0.934508, 0.945745, 0.956055, 0.964565, 0.971111,
+	0.976547, 0.980468, 0.983241, 0.985692, 0.987516, 0.989064, 0.990400, 0.991082,
+	0.961956, 0.930773, 0.921646, 0.923966, 0.931170, 0.939399, 0.947832, 0.955285,
+	0.961316, 0.966498, 0.971041, 0.974338, 0.977273, 0.979780, 0.981679, 0.983467,
+	0.958550, 0.925717, 0.913781, 0.912328, 0.916363, 0.922386, 0.929458, 0.936599,
+	0.943037, 0.948905, 0.954063, 0.958849, 0.962552, 0.966097, 0.968871, 0.971359,
+	0.952185, 0.915198, 0.900988, 0.896586, 0.898412, 0.902635, 0.908676, 0.914880,
+	0.921289, 0.927365, 0.933420, 0.938198, 0.943117, 0.947612, 0.951603, 0.954784,
+	0.943860, 0.900624, 0.882667, 0.876189, 0.876140, 0.878767, 0.883590, 0.889458,
+	0.895727, 0.902167, 0.908002, 0.914087, 0.919564, 0.924565, 0.929196, 0.933531,
+	0.933866, 0.882540, 0.860753, 0.851251, 0.848734, 0.850100, 0.854094, 0.859179,
+	0.865544, 0.871952, 0.878004, 0.885185, 0.890708, 0.896937, 0.902406, 0.907578,
+	0.922795, 0.862712, 0.835582, 0.822502, 0.817330, 0.817258, 0.820098, 0.824529,
+	0.830655, 0.837079, 0.843770, 0.850979, 0.857823, 0.864602, 0.871041, 0.877259,
+	0.911061, 0.841186, 0.808256, 0.791504, 0.783803, 0.781383, 0.782657, 0.786287,
+	0.791936, 0.798566, 0.805414, 0.812981, 0.820670, 0.828642, 0.836358, 0.843757,
+	0.898691, 0.819041, 0.780331, 0.759467, 0.748701, 0.743697, 0.743197, 0.745554,
+	0.749943, 0.756553, 0.763678, 0.771767, 0.780149, 0.789096, 0.798401, 0.807066,
+	0.886182, 0.796722, 0.752416, 0.727408, 0.713222, 0.705904, 0.703365, 0.704258,
+	0.707701, 0.713136, 0.720239, 0.728124, 0.737279, 0.747315, 0.758160, 0.768545,
+	0.999785, 0.999983, 0.999995, 0.999998, 0.999999, 0.999999, 1.000000, 1.000000,
+	1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000,
+	0.962447, 0.996579, 0.999520, 0.999842, 0.999922, 0.999955, 0.999971, 0.999980,
+	0.999986, 0.999990, 0.999993, 0.999995, 0.999996, 0.999998, 0.999999, 1.000000,
+	0.907424, 0.973291, 0.991485, 0.996398, 0.998182, 0.998946, 0.999604, 0.999817,
+	0.999881, 0.999917, 0.999941, 0.999958, 0.999970, 0.999981, 0.999989, 0.999996,
+	0.920453, 0.938132, 0.970981, 0.985669, 0.992066, 0.995235, 0.996876, 0.997918,
+	0.998443, 0.998809, 0.999042, 0.999324, 0.999664, 0.999850, 0.999922, 0.999963,
+	0.942526, 0.923104, 0.946305, 0.966689, 0.978736, 0.985683, 0.990192, 0.992721,
+	0.994440, 0.995555, 0.996285, 0.996879, 0.997267, 0.997677, 0.997880, 0.997985,
+	0.956079, 0.925345, 0.931961, 0.947645, 0.961731, 0.971772, 0.978817, 0.983955,
+	0.987263, 0.989558, 0.991471, 0.992695, 0.993669, 0.994542, 0.995039, 0.995513,
+	0.961990, 0.930488, 0.926318, 0.934452, 0.945582, 0.955591, 0.964142, 0.970688,
+	0.975965, 0.979689, 0.982761, 0.985113, 0.987073, 0.988298, 0.989922, 0.990640,
+	0.962418, 0.931025, 0.921768, 0.923845, 0.930824, 0.938958, 0.947163, 0.954327,
+	0.960323, 0.965531, 0.969788, 0.973388, 0.976235, 0.978742, 0.980817, 0.982414,
+	0.958997, 0.926074, 0.914069, 0.912256, 0.915862, 0.921607, 0.928444, 0.935381,
+	0.941882, 0.947454, 0.952499, 0.957110, 0.961086, 0.964317, 0.967392, 0.969965,
+	0.952804, 0.915826, 0.901289, 0.896490, 0.897939, 0.901770, 0.907404, 0.913685,
+	0.919725, 0.925392, 0.931382, 0.936246, 0.940942, 0.945234, 0.948967, 0.952462,
+	0.944607, 0.901479, 0.883232, 0.876481, 0.875556, 0.877629, 0.882190, 0.887328,
+	0.893458, 0.899392, 0.904913, 0.911199, 0.916378, 0.921175, 0.925912, 0.929728,
+	0.934745, 0.883540, 0.861404, 0.851320, 0.848078, 0.848878, 0.852243, 0.856892,
+	0.862637, 0.868594, 0.874579, 0.880312, 0.886824, 0.892062, 0.897576, 0.902494,
+	0.923835, 0.863917, 0.836161, 0.822549, 0.816801, 0.815772, 0.818094, 0.821944,
+	0.827012, 0.833049, 0.839734, 0.846011, 0.852782, 0.859039, 0.864942, 0.870971,
+	0.912239, 0.842445, 0.808949, 0.791483, 0.782720, 0.779645, 0.780320, 0.782937,
+	0.787498, 0.793660, 0.800515, 0.807267, 0.814094, 0.821976, 0.829288, 0.836094,
+	0.900025, 0.820313, 0.781026, 0.759182, 0.747607, 0.741831, 0.740198, 0.741758,
+	0.745371, 0.751345, 0.757811, 0.765226, 0.772956, 0.781201, 0.790015, 0.798209,
+	0.887543, 0.797933, 0.752869, 0.726991, 0.711873, 0.703463, 0.700015, 0.699734,
+	0.702503, 0.707091, 0.713368, 0.720817, 0.729210, 0.738760, 0.748405, 0.758421,
+	0.999786, 0.999983, 0.999995, 0.999998, 0.999999, 0.999999, 1.000000, 1.000000,
+	1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000,
+	0.962572, 0.996592, 0.999522, 0.999842, 0.999921, 0.999954, 0.999970, 0.999980,
+	0.999986, 0.999990, 0.999993, 0.999995, 0.999996, 0.999998, 0.999999, 1.000000,
+	0.907667, 0.973499, 0.991494, 0.996403, 0.998174, 0.998938, 0.999597, 0.999812,
+	0.999877, 0.999914, 0.999939, 0.999956, 0.999969, 0.999980, 0.999989, 0.999996,
+	0.920747, 0.938835, 0.971169, 0.985723, 0.992111, 0.995201, 0.996806, 0.997896,
+	0.998426, 0.998797, 0.999018, 0.999317, 0.999651, 0.999847, 0.999918, 0.999961,
+	0.942789, 0.923496, 0.946808, 0.966621, 0.978640, 0.985622, 0.989973, 0.992494,
+	0.994264, 0.995353, 0.996137, 0.996732, 0.997190, 0.997538, 0.997687, 0.997858,
+	0.956329, 0.925761, 0.932153, 0.947927, 0.961550, 0.971696, 0.978670, 0.983603,
+	0.986902, 0.989256, 0.991005, 0.992441, 0.993397, 0.994196, 0.994815, 0.995300,
+	0.962268, 0.930737, 0.926522, 0.934307, 0.945334, 0.955344, 0.963800, 0.970261,
+	0.975409, 0.979171, 0.982172, 0.984506, 0.986444, 0.987861, 0.989127, 0.990069,
+	0.962703, 0.931253, 0.922031, 0.923814, 0.930538, 0.938466, 0.946397, 0.953674,
+	0.959513, 0.964737, 0.968904, 0.972393, 0.975196, 0.977864, 0.979964, 0.981569,
+	0.959455, 0.926467, 0.914351, 0.912040, 0.915495, 0.920960, 0.927532, 0.934297,
+	0.940579, 0.945985, 0.951209, 0.955448, 0.959356, 0.962791, 0.965836, 0.968436,
+	0.953296, 0.916436, 0.901679, 0.896518, 0.897412, 0.901011, 0.906328, 0.912195,
+	0.917924, 0.923746, 0.928820, 0.934120, 0.938703, 0.942899, 0.946725, 0.950137,
+	0.945297, 0.902135, 0.883502, 0.876568, 0.874845, 0.876679, 0.880820, 0.885680,
+	0.891326, 0.897172, 0.902344, 0.907855, 0.913115, 0.917964, 0.922533, 0.926388,
+	0.935551, 0.884313, 0.861785, 0.851263, 0.847292, 0.847760, 0.850427, 0.854797,
+	0.859885, 0.865667, 0.871048, 0.876693, 0.882253, 0.887978, 0.893036, 0.897781,
+	0.924758, 0.864861, 0.836506, 0.822320, 0.816044, 0.814308, 0.815972, 0.819329,
+	0.824038, 0.829220, 0.835349, 0.841333, 0.847468, 0.853836, 0.859413, 0.864784,
+	0.913254, 0.843462, 0.809469, 0.791290, 0.781759, 0.777876, 0.777875, 0.780099,
+	0.783918, 0.789249, 0.795006, 0.801516, 0.808004, 0.814887, 0.822106, 0.828596,
+	0.901161, 0.821413, 0.781509, 0.758845, 0.746087, 0.739812, 0.737711, 0.738436,
+	0.741360, 0.746083, 0.751913, 0.758605, 0.765573, 0.773520, 0.781468, 0.789714,
+	0.888816, 0.798957, 0.753203, 0.726330, 0.710304, 0.701303, 0.696834, 0.695929,
+	0.697884, 0.701401, 0.706906, 0.713642, 0.721273, 0.729889, 0.739410, 0.749005,
+];
+
+/// Albedo of a rough dielectric seen from the side with the higher index of refraction, indexed by [eta][roughness][cos]
+#[rustfmt::skip]
+pub static DIELECTRIC_ALBEDO_EXITING: [f64; 4096] = [
+	0.999738, 0.999972, 0.999990, 0.999995, 0.999997, 0.999969, 0.999996, 0.999998,
+	0.999999, 0.999999, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000,
+	0.953466, 0.993459, 0.997799, 0.999202, 0.999298, 0.998070, 0.999696, 0.999859,
+	0.999918, 0.999948, 0.999965, 0.999977, 0.999985, 0.999991, 0.999995, 0.999999,
+	0.871696, 0.953873, 0.979932, 0.988290, 0.990433, 0.989842, 0.997205, 0.998829,
+	0.999343, 0.999594, 0.999732, 0.999821, 0.999883, 0.999928, 0.999962, 0.999989,
+	0.838512, 0.880116, 0.928947, 0.952557, 0.962004, 0.970942, 0.985988, 0.992123,
+	0.994643, 0.996053, 0.997505, 0.999178, 0.999520, 0.999717, 0.999855, 0.999957,
+	0.792679, 0.809218, 0.852037, 0.885455, 0.908714, 0.936005, 0.962467, 0.976827,
+	0.984339, 0.989266, 0.992972, 0.995416, 0.997421, 0.999002, 0.999543, 0.999761,
+	0.722007, 0.738231, 0.769918, 0.804699, 0.843493, 0.887416, 0.924980, 0.950272,
+	0.965853, 0.976314, 0.984243, 0.989576, 0.993223, 0.995276, 0.998079, 0.999371,
+	0.635539, 0.660322, 0.691440, 0.732507, 0.783044, 0.834629, 0.879318, 0.914173,
+	0.938545, 0.956737, 0.970912, 0.980517, 0.986788, 0.991073, 0.993400, 0.995027,
+	0.547230, 0.582409, 0.625249, 0.675625, 0.732849, 0.786353, 0.833276, 0.873329,
+	0.905798, 0.931457, 0.952223, 0.966866, 0.977021, 0.984603, 0.989186, 0.991935,
+	0.464985, 0.516628, 0.573463, 0.633664, 0.692719, 0.745618, 0.792499, 0.834137,
+	0.870952, 0.902954, 0.929909, 0.950166, 0.964209, 0.974589, 0.982237, 0.986088,
+	0.395962, 0.466683, 0.536097, 0.602484, 0.661707, 0.712054, 0.757170, 0.799176,
+	0.837778, 0.873058, 0.904113, 0.929130, 0.948275, 0.962793, 0.972908, 0.979295,
+	0.344001, 0.435559, 0.512606, 0.581560, 0.637066, 0.684668, 0.727854, 0.768182,
+	0.807803, 0.843833, 0.877832, 0.906305, 0.929546, 0.947882, 0.961792, 0.969833,
+	0.305302, 0.414159, 0.499656, 0.564794, 0.617349, 0.661829, 0.702569, 0.741751,
+	0.779522, 0.816578, 0.851525, 0.882541, 0.908508, 0.930197, 0.947352, 0.957727,
+	0.279788, 0.404902, 0.491241, 0.552111, 0.600230, 0.642067, 0.680694, 0.718344,
+	0.754714, 0.791417, 0.825670, 0.857765, 0.886543, 0.911000, 0.931207, 0.943451,
+	0.264293, 0.401936, 0.485741, 0.542395, 0.586673, 0.625198, 0.661334, 0.696844,
+	0.732265, 0.767169, 0.801818, 0.833574, 0.863377, 0.890520, 0.913000, 0.925718,
+	0.255955, 0.403649, 0.482970, 0.534763, 0.574692, 0.610612, 0.644308, 0.677576,
+	0.711361, 0.744913, 0.778441, 0.810469, 0.841282, 0.869279, 0.893002, 0.905128,
+	0.253403, 0.407484, 0.481717, 0.528232, 0.564760, 0.597605, 0.629350, 0.660925,
+	0.692108, 0.724756, 0.757021, 0.788493, 0.818838, 0.847680, 0.872119, 0.881865,
+	0.999738, 0.999972, 0.999990, 0.999995, 0.999997, 0.999998, 0.999999, 0.999999,
+	0.999999, 0.999998, 0.999999, 0.999999, 1.000000, 1.000000, 1.000000, 1.000000,
+	0.955358, 0.995107, 0.999028, 0.999597, 0.999769, 0.999854, 0.999604, 0.999767,
+	0.999321, 0.999816, 0.999923, 0.999959, 0.999977, 0.999987, 0.999994, 0.999998,
+	0.882138, 0.958266, 0.983133, 0.990983, 0.994371, 0.996036, 0.997419, 0.997598,
+	0.995702, 0.998559, 0.999398, 0.999680, 0.999818, 0.999898, 0.999950, 0.999986,
+	0.872481, 0.897149, 0.941390, 0.964827, 0.976507, 0.982996, 0.985569, 0.986845,
+	0.985805, 0.992109, 0.995422, 0.997007, 0.998559, 0.999418, 0.999796, 0.999944,
+	0.861693, 0.851217, 0.884211, 0.915914, 0.937373, 0.950429, 0.958398, 0.961990,
+	0.966558, 0.978294, 0.985901, 0.990049, 0.992570, 0.994256, 0.995737, 0.997648,
+	0.833153, 0.817219, 0.832228, 0.857561, 0.881638, 0.899532, 0.912757, 0.924257,
+	0.937868, 0.954954, 0.968813, 0.977215, 0.982363, 0.986349, 0.989583, 0.991327,
+	0.789156, 0.777113, 0.782956, 0.799451, 0.818270, 0.837538, 0.856032, 0.874887,
+	0.897642, 0.921048, 0.941257, 0.955414, 0.965700, 0.973332, 0.979140, 0.985528,
+	0.735172, 0.727942, 0.731744, 0.742492, 0.757238, 0.775453, 0.796721, 0.822673,
+	0.851330, 0.879327, 0.904376, 0.924980, 0.941140, 0.953908, 0.964765, 0.973875,
+	0.674669, 0.672144, 0.675829, 0.685734, 0.700721, 0.720490, 0.743955, 0.773919,
+	0.803970, 0.834173, 0.862560, 0.887965, 0.909944, 0.928063, 0.944276, 0.958014,
+	0.612015, 0.612120, 0.620320, 0.633392, 0.651450, 0.673800, 0.700901, 0.729598,
+	0.760723, 0.791151, 0.820135, 0.847879, 0.874320, 0.896881, 0.918034, 0.936478,
+	0.551101, 0.555310, 0.569739, 0.587342, 0.610190, 0.636376, 0.665050, 0.693165,
+	0.723025, 0.751318, 0.780125, 0.808203, 0.836866, 0.862993, 0.886866, 0.909054,
+	0.495926, 0.506431, 0.525350, 0.549434, 0.577651, 0.606530, 0.634682, 0.662853,
+	0.690674, 0.717524, 0.744853, 0.773077, 0.800431, 0.826615, 0.851350, 0.876258,
+	0.447203, 0.465678, 0.489633, 0.521225, 0.551660, 0.581319, 0.610010, 0.636715,
+	0.663646, 0.688991, 0.714406, 0.740192, 0.766189, 0.790677, 0.814217, 0.837845,
+	0.403800, 0.431031, 0.464862, 0.498939, 0.531878, 0.561597, 0.589319, 0.615350,
+	0.640108, 0.664491, 0.687716, 0.711701, 0.734968, 0.756045, 0.774281, 0.795020,
+	0.369425, 0.405409, 0.446730, 0.483582, 0.516461, 0.546456, 0.572266, 0.597043,
+	0.621043, 0.643595, 0.665276, 0.687391, 0.707653, 0.723253, 0.734745, 0.748678,
+	0.342378, 0.388177, 0.433941, 0.472468, 0.505145, 0.533173, 0.558296, 0.581454,
+	0.604291, 0.625388, 0.645362, 0.665514, 0.682179, 0.693067, 0.696126, 0.699877,
+	0.999738, 0.999972, 0.999990, 0.999995, 0.999997, 0.999998, 0.999999, 0.999999,
+	0.999999, 1.000000, 0.999992, 0.999999, 1.000000, 1.000000, 1.000000, 1.000000,
+	0.955907, 0.995107, 0.999028, 0.999597, 0.999769, 0.999854, 0.999901, 0.999931,
+	0.999688, 0.999893, 0.999443, 0.999910, 0.999962, 0.999981, 0.999991, 0.999998,
+	0.885318, 0.959562, 0.983739, 0.991953, 0.995129, 0.996437, 0.997605, 0.998681,
+	0.998663, 0.998486, 0.997082, 0.999295, 0.999698, 0.999854, 0.999934, 0.999982,
+	0.883563, 0.902070, 0.944649, 0.967205, 0.978840, 0.985100, 0.988656, 0.991163,
+	0.991696, 0.991119, 0.990398, 0.995530, 0.998061, 0.999199, 0.999657, 0.999923,
+	0.883678, 0.863730, 0.893049, 0.922974, 0.943811, 0.957733, 0.966024, 0.971575,
+	0.974078, 0.973937, 0.977261, 0.985608, 0.990462, 0.992441, 0.993536, 0.993876,
+	0.869405, 0.841451, 0.850882, 0.872912, 0.895336, 0.913822, 0.927406, 0.936552,
+	0.942913, 0.947696, 0.955832, 0.968450, 0.977181, 0.981807, 0.984570, 0.987444,
+	0.842623, 0.817758, 0.815472, 0.826642, 0.843508, 0.860634, 0.875524, 0.888058,
+	0.898309, 0.909796, 0.924932, 0.941461, 0.954882, 0.963838, 0.970319, 0.974859,
+	0.805396, 0.784770, 0.778475, 0.782154, 0.791677, 0.804124, 0.817413, 0.831241,
+	0.846104, 0.863729, 0.883943, 0.904556, 0.922789, 0.936629, 0.947155, 0.956608,
+	0.761445, 0.743066, 0.736472, 0.736568, 0.741291, 0.749522, 0.761283, 0.775545,
+	0.793563, 0.813453, 0.837490, 0.860080, 0.881582, 0.900328, 0.915460, 0.930139,
+	0.713887, 0.695887, 0.689345, 0.688828, 0.691418, 0.699325, 0.710332, 0.724605,
+	0.744345, 0.765880, 0.788770, 0.811833, 0.834505, 0.855794, 0.875580, 0.895496,
+	0.662541, 0.645661, 0.640161, 0.640684, 0.645525, 0.654030, 0.666242, 0.681469,
+	0.699929, 0.720716, 0.742237, 0.764750, 0.785996, 0.807126, 0.829063, 0.852934,
+	0.613029, 0.598631, 0.593499, 0.596694, 0.603636, 0.615395, 0.629655, 0.645021,
+	0.663015, 0.681923, 0.701274, 0.720422, 0.738554, 0.757792, 0.778922, 0.802895,
+	0.566953, 0.551776, 0.550899, 0.557049, 0.568530, 0.581079, 0.598984, 0.614032,
+	0.631734, 0.647904, 0.664995, 0.680630, 0.694529, 0.708791, 0.726243, 0.748581,
+	0.521558, 0.510816, 0.515346, 0.524449, 0.537792, 0.554525, 0.571043, 0.587896,
+	0.603311, 0.618909, 0.633346, 0.645849, 0.654130, 0.662243, 0.674084, 0.690224,
+	0.483472, 0.476219, 0.483046, 0.497693, 0.514711, 0.531389, 0.547583, 0.564754,
+	0.580232, 0.594912, 0.606046, 0.615565, 0.618486, 0.619750, 0.623396, 0.630771,
+	0.449808, 0.446276, 0.458817, 0.476341, 0.495269, 0.513303, 0.529939, 0.545983,
+	0.560172, 0.573607, 0.582577, 0.588817, 0.586313, 0.579526, 0.574563, 0.572068,
+	0.999738, 0.999972, 0.999990, 0.999995, 0.999997, 0.999998, 0.999999, 0.999999,
+	0.999999, 1.000000, 1.000000, 0.999971, 0.999999, 1.000000, 1.000000, 1.000000,
+	0.956152, 0.995107, 0.999028, 0.999597, 0.999769, 0.999854, 0.999901, 0.999931,
+	0.999950, 0.999842, 0.999728, 0.999355, 0.999927, 0.999973, 0.999989, 0.999997,
+	0.887387, 0.959542, 0.984200, 0.991880, 0.995207, 0.996893, 0.998577, 0.998887,
+	0.999230, 0.999139, 0.998957, 0.997481, 0.999421, 0.999784, 0.999913, 0.999978,
+	0.888952, 0.904503, 0.946409, 0.968718, 0.979894, 0.986334, 0.989689, 0.991892,
+	0.993161, 0.993685, 0.993379, 0.991867, 0.997005, 0.998914, 0.999560, 0.999876,
+	0.894997, 0.869875, 0.896912, 0.925714, 0.946359, 0.959671, 0.968468, 0.974258,
+	0.977612, 0.979531, 0.979347, 0.979943, 0.987200, 0.990828, 0.992017, 0.991879,
+	0.889156, 0.853494, 0.859391, 0.879566, 0.901239, 0.919285, 0.933199, 0.943117,
+	0.950167, 0.953692, 0.956652, 0.961338, 0.971078, 0.978396, 0.982711, 0.984757,
+	0.871499, 0.838640, 0.831416, 0.839424, 0.854618, 0.871001, 0.886054, 0.897988,
+	0.908148, 0.915503, 0.922693, 0.932463, 0.946039, 0.957626, 0.965014, 0.969388,
+	0.843861, 0.815150, 0.802747, 0.802643, 0.810044, 0.820730, 0.832543, 0.844318,
+	0.855475, 0.866319, 0.877774, 0.893363, 0.910300, 0.925982, 0.937943, 0.946323,
+	0.810414, 0.783914, 0.770059, 0.765116, 0.766777, 0.771134, 0.779164, 0.789074,
+	0.800424, 0.812286, 0.828024, 0.845114, 0.864630, 0.883227, 0.899770, 0.912555,
+	0.772572, 0.745949, 0.731509, 0.724284, 0.721817, 0.724143, 0.728335, 0.736460,
+	0.746699, 0.759810, 0.776049, 0.792848, 0.811865, 0.832060, 0.851366, 0.868147,
+	0.730856, 0.703352, 0.688686, 0.681355, 0.677863, 0.677316, 0.681357, 0.688064,
+	0.698955, 0.711020, 0.725150, 0.740540, 0.756914, 0.775354, 0.795188, 0.814029,
+	0.688682, 0.659357, 0.644957, 0.637180, 0.634543, 0.635315, 0.639249, 0.646816,
+	0.656499, 0.666231, 0.679119, 0.689825, 0.702064, 0.716816, 0.734276, 0.752464,
+	0.645356, 0.616849, 0.601841, 0.595099, 0.594433, 0.595863, 0.601156, 0.610497,
+	0.619129, 0.628282, 0.637147, 0.643138, 0.650018, 0.660012, 0.672629, 0.686151,
+	0.605329, 0.575051, 0.562020, 0.557600, 0.558435, 0.563934, 0.569495, 0.578693,
+	0.586567, 0.595598, 0.599989, 0.601971, 0.602675, 0.606318, 0.611700, 0.618950,
+	0.567330, 0.537362, 0.526109, 0.525153, 0.528294, 0.534253, 0.543557, 0.551289,
+	0.559653, 0.564850, 0.568499, 0.564121, 0.559099, 0.555628, 0.553608, 0.551656,
+	0.532597, 0.502541, 0.496203, 0.497247, 0.502280, 0.510417, 0.519307, 0.527057,
+	0.534689, 0.539997, 0.540346, 0.531802, 0.519310, 0.508724, 0.498575, 0.488177,
+	0.999738, 0.999972, 0.999990, 0.999995, 0.999997, 0.999998, 0.999999, 0.999999,
+	0.999999, 1.000000, 1.000000, 1.000000, 0.999997, 0.999999, 1.000000, 1.000000,
+	0.956518, 0.995107, 0.999028, 0.999597, 0.999769, 0.999854, 0.999901, 0.999931,
+	0.999950, 0.999964, 0.999852, 0.999912, 0.999730, 0.999957, 0.999986, 0.999997,
+	0.888424, 0.960013, 0.983981, 0.991401, 0.994611, 0.996515, 0.998338, 0.999224,
+	0.999543, 0.999177, 0.999263, 0.999126, 0.998222, 0.999662, 0.999887, 0.999974,
+	0.892450, 0.905893, 0.947200, 0.969160, 0.980659, 0.986682, 0.989994, 0.992750,
+	0.993920, 0.994603, 0.994812, 0.994497, 0.994487, 0.998431, 0.999447, 0.999844,
+	0.902302, 0.873434, 0.899200, 0.927410, 0.947820, 0.961332, 0.969828, 0.975699,
+	0.979463, 0.981935, 0.982621, 0.981610, 0.983033, 0.988905, 0.990700, 0.991786,
+	0.901344, 0.861835, 0.864800, 0.884056, 0.904955, 0.922676, 0.936573, 0.946522,
+	0.953808, 0.958647, 0.960491, 0.961969, 0.966191, 0.975418, 0.981036, 0.982793,
+	0.889453, 0.850965, 0.840859, 0.847505, 0.861602, 0.877437, 0.892167, 0.903740,
+	0.914001, 0.920828, 0.925945, 0.930963, 0.940068, 0.952127, 0.961512, 0.966579,
+	0.868225, 0.833983, 0.818220, 0.815532, 0.820792, 0.830323, 0.842126, 0.852961,
+	0.863377, 0.871935, 0.880399, 0.889697, 0.902565, 0.918020, 0.931446, 0.940379,
+	0.842564, 0.809541, 0.791309, 0.783403, 0.782519, 0.785762, 0.791796, 0.799972,
+	0.808267, 0.817325, 0.826313, 0.838944, 0.854886, 0.871913, 0.888680, 0.901709,
+	0.810370, 0.778608, 0.758999, 0.747664, 0.742988, 0.741107, 0.743828, 0.747115,
+	0.753052, 0.760908, 0.771606, 0.784010, 0.798923, 0.817183, 0.835428, 0.851198,
+	0.776981, 0.742441, 0.722226, 0.709127, 0.700768, 0.696755, 0.695685, 0.697156,
+	0.701563, 0.708790, 0.716863, 0.727515, 0.740244, 0.755701, 0.773262, 0.789752,
+	0.740165, 0.702844, 0.681392, 0.667703, 0.658903, 0.653667, 0.651827, 0.652482,
+	0.656418, 0.660967, 0.664479, 0.672142, 0.680414, 0.692428, 0.706151, 0.720348,
+	0.702353, 0.663330, 0.639754, 0.625916, 0.618150, 0.614087, 0.612169, 0.612556,
+	0.614103, 0.616309, 0.618198, 0.619582, 0.623693, 0.630023, 0.638072, 0.646524,
+	0.665075, 0.623862, 0.600773, 0.587098, 0.579663, 0.576271, 0.576581, 0.576123,
+	0.578145, 0.579829, 0.575969, 0.572584, 0.570638, 0.569883, 0.571342, 0.572815,
+	0.630554, 0.585342, 0.564149, 0.551176, 0.545128, 0.544004, 0.543431, 0.545075,
+	0.545843, 0.543889, 0.538149, 0.529070, 0.521581, 0.514394, 0.508229, 0.501700,
+	0.595018, 0.551606, 0.529984, 0.518996, 0.515700, 0.514453, 0.516243, 0.517750,
+	0.517771, 0.515395, 0.503871, 0.490503, 0.476564, 0.462789, 0.449202, 0.435054,
+	0.999738, 0.999972, 0.999990, 0.999995, 0.999997, 0.999998, 0.999999, 0.999999,
+	0.999999, 1.000000, 1.000000, 1.000000, 1.000000, 0.999999, 1.000000, 1.000000,
+	0.957006, 0.995107, 0.999028, 0.999597, 0.999769, 0.999854, 0.999901, 0.999931,
+	0.999950, 0.999964, 0.999974, 0.999795, 0.999906, 0.999923, 0.999981, 0.999996,
+	0.889339, 0.960624, 0.984164, 0.991645, 0.994680, 0.996098, 0.998208, 0.998973,
+	0.999488, 0.999674, 0.999607, 0.999338, 0.998915, 0.999388, 0.999851, 0.999969,
+	0.894852, 0.907163, 0.947390, 0.969524, 0.980927, 0.987103, 0.990500, 0.993016,
+	0.994404, 0.995017, 0.995593, 0.995846, 0.995596, 0.997482, 0.999297, 0.999816,
+	0.907561, 0.876286, 0.900833, 0.928843, 0.948778, 0.961947, 0.970921, 0.976629,
+	0.980429, 0.982984, 0.983965, 0.984270, 0.982894, 0.986221, 0.990271, 0.991694,
+	0.909675, 0.866996, 0.868504, 0.886954, 0.907243, 0.924796, 0.938550, 0.948551,
+	0.955670, 0.960936, 0.963383, 0.964769, 0.965429, 0.972116, 0.979195, 0.982457,
+	0.901672, 0.860150, 0.847819, 0.852722, 0.865938, 0.881122, 0.895071, 0.907513,
+	0.917535, 0.924918, 0.929888, 0.933197, 0.938329, 0.947884, 0.958504, 0.964650,
+	0.885987, 0.847117, 0.828557, 0.824000, 0.828717, 0.837169, 0.848126, 0.858856,
+	0.868963, 0.876976, 0.883482, 0.890804, 0.899789, 0.912412, 0.926650, 0.937055,
+	0.863567, 0.827542, 0.806817, 0.796399, 0.792836, 0.795317, 0.799926, 0.806910,
+	0.814425, 0.821180, 0.828302, 0.838011, 0.850215, 0.865076, 0.881632, 0.895604,
+	0.838329, 0.800649, 0.778072, 0.764481, 0.756616, 0.753084, 0.752941, 0.755390,
+	0.759514, 0.764245, 0.770503, 0.780143, 0.792976, 0.807496, 0.824806, 0.841204,
+	0.809351, 0.769854, 0.744964, 0.729347, 0.718584, 0.711107, 0.708046, 0.707396,
+	0.706816, 0.709247, 0.712503, 0.720662, 0.730220, 0.743235, 0.758689, 0.774872,
+	0.776755, 0.734721, 0.707882, 0.690782, 0.678140, 0.668682, 0.663456, 0.660170,
+	0.658295, 0.656548, 0.658365, 0.661847, 0.668113, 0.676400, 0.687514, 0.700207,
+	0.743876, 0.698345, 0.669796, 0.650388, 0.637709, 0.626999, 0.621780, 0.616922,
+	0.613744, 0.609665, 0.606666, 0.606000, 0.606891, 0.610271, 0.615313, 0.621834,
+	0.711181, 0.660487, 0.630616, 0.610632, 0.598333, 0.588865, 0.582202, 0.577748,
+	0.573668, 0.568272, 0.559724, 0.553791, 0.549211, 0.546558, 0.544727, 0.543999,
+	0.677306, 0.625378, 0.592689, 0.574341, 0.560908, 0.552965, 0.547238, 0.543030,
+	0.537788, 0.527541, 0.516935, 0.505376, 0.495745, 0.486887, 0.478363, 0.469680,
+	0.646901, 0.589022, 0.558916, 0.539989, 0.528418, 0.521094, 0.516303, 0.512425,
+	0.505769, 0.495515, 0.478198, 0.462119, 0.446790, 0.432296, 0.417229, 0.401670,
+	0.999738, 0.999972, 0.999990, 0.999995, 0.999997, 0.999998, 0.999999, 0.999999,
+	0.999999, 1.000000, 1.000000, 1.000000, 1.000000, 0.999997, 1.000000, 1.000000,
+	0.957006, 0.995107, 0.999028, 0.999597, 0.999769, 0.999854, 0.999901, 0.999931,
+	0.999950, 0.999964, 0.999974, 0.999982, 0.999986, 0.999759, 0.999975, 0.999995,
+	0.889705, 0.960746, 0.984286, 0.991889, 0.994985, 0.996403, 0.997924, 0.998882,
+	0.999259, 0.999634, 0.999480, 0.999600, 0.999369, 0.998489, 0.999800, 0.999963,
+	0.896698, 0.907767, 0.948303, 0.969930, 0.980885, 0.987384, 0.990837, 0.993215,
+	0.994528, 0.995218, 0.996024, 0.996484, 0.996747, 0.995832, 0.999088, 0.999788,
+	0.911108, 0.878204, 0.902306, 0.929643, 0.949600, 0.962561, 0.971168, 0.976761,
+	0.980852, 0.983457, 0.984897, 0.985459, 0.984767, 0.984340, 0.989703, 0.991603,
+	0.915782, 0.870935, 0.870947, 0.888654, 0.908880, 0.925959, 0.939510, 0.949417,
+	0.956810, 0.961724, 0.965198, 0.966935, 0.967043, 0.969819, 0.977692, 0.982133,
+	0.910605, 0.866565, 0.852458, 0.856508, 0.868743, 0.883693, 0.897601, 0.910102,
+	0.919700, 0.927185, 0.932825, 0.936110, 0.939319, 0.945152, 0.956069, 0.963821,
+	0.898506, 0.857093, 0.836413, 0.830395, 0.833268, 0.841911, 0.851921, 0.862705,
+	0.872214, 0.880393, 0.886705, 0.892642, 0.899359, 0.909354, 0.922991, 0.935107,
+	0.880572, 0.840134, 0.816931, 0.804399, 0.800170, 0.801562, 0.806473, 0.812305,
+	0.819027, 0.825098, 0.831686, 0.838990, 0.848419, 0.861309, 0.876574, 0.891910,
+	0.858134, 0.817852, 0.792412, 0.775635, 0.766612, 0.761963, 0.761394, 0.762530,
+	0.764572, 0.767124, 0.772204, 0.779293, 0.789132, 0.801961, 0.817785, 0.834994,
+	0.832885, 0.790000, 0.762082, 0.742746, 0.731346, 0.722055, 0.716065, 0.713613,
+	0.711527, 0.710482, 0.712347, 0.716950, 0.725247, 0.735573, 0.749338, 0.765689,
+	0.804867, 0.759074, 0.728419, 0.707453, 0.692095, 0.680642, 0.671249, 0.666051,
+	0.660416, 0.655538, 0.653329, 0.656418, 0.659339, 0.666380, 0.675685, 0.687998,
+	0.775979, 0.725438, 0.691658, 0.668955, 0.652052, 0.639272, 0.628651, 0.621390,
+	0.613379, 0.605228, 0.599325, 0.596099, 0.595619, 0.596983, 0.600475, 0.606494,
+	0.745563, 0.688877, 0.654415, 0.629504, 0.612787, 0.599711, 0.589675, 0.579223,
+	0.569941, 0.559123, 0.548502, 0.540736, 0.535090, 0.530496, 0.527245, 0.526177,
+	0.714283, 0.653416, 0.618361, 0.592758, 0.575172, 0.561722, 0.551119, 0.542700,
+	0.530849, 0.513397, 0.501559, 0.489545, 0.478447, 0.467846, 0.458331, 0.450089,
+	0.685723, 0.620477, 0.582464, 0.556950, 0.539696, 0.527904, 0.517842, 0.506979,
+	0.495295, 0.478100, 0.459567, 0.442327, 0.426513, 0.410681, 0.395327, 0.380933,
+	0.999738, 0.999972, 0.999990, 0.999995, 0.999997, 0.999998, 0.999999, 0.999999,
+	0.999999, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000,
+	0.957006, 0.995107, 0.999028, 0.999597, 0.999769, 0.999854, 0.999901, 0.999931,
+	0.999950, 0.999964, 0.999974, 0.999982, 0.999854, 0.999947, 0.999965, 0.999995,
+	0.890314, 0.960929, 0.984531, 0.991950, 0.995107, 0.996525, 0.998107, 0.998576,
+	0.999226, 0.999447, 0.999724, 0.999647, 0.999443, 0.999081, 0.999719, 0.999957,
+	0.898074, 0.908501, 0.948546, 0.970112, 0.981429, 0.987341, 0.990986, 0.993545,
+	0.994634, 0.995664, 0.996125, 0.996668, 0.997360, 0.995973, 0.998775, 0.999757,
+	0.913526, 0.879477, 0.903042, 0.930318, 0.949848, 0.963024, 0.971466, 0.977223,
+	0.980868, 0.983946, 0.985459, 0.985853, 0.986106, 0.984097, 0.988955, 0.991506,
+	0.920411, 0.873449, 0.872223, 0.889918, 0.909973, 0.926926, 0.940538, 0.950160,
+	0.957564, 0.962711, 0.966362, 0.968489, 0.969103, 0.969222, 0.976420, 0.981819,
+	0.917768, 0.870769, 0.855571, 0.859040, 0.870918, 0.885267, 0.899513, 0.911474,
+	0.921447, 0.928661, 0.934136, 0.938750, 0.940775, 0.944195, 0.954070, 0.963112,
+	0.907636, 0.863451, 0.841084, 0.834312, 0.837110, 0.845113, 0.854691, 0.864966,
+	0.874652, 0.882695, 0.889863, 0.895155, 0.900336, 0.908453, 0.920403, 0.933743,
+	0.891999, 0.849640, 0.824614, 0.811639, 0.806319, 0.806388, 0.810594, 0.816292,
+	0.822425, 0.827754, 0.834588, 0.840902, 0.848245, 0.858814, 0.873081, 0.889344,
+	0.872894, 0.830248, 0.802732, 0.784912, 0.774770, 0.768458, 0.765869, 0.766246,
+	0.767962, 0.770621, 0.774047, 0.780465, 0.788244, 0.799005, 0.813575, 0.830958,
+	0.851060, 0.804850, 0.775459, 0.754583, 0.739677, 0.729375, 0.723266, 0.718284,
+	0.714140, 0.712347, 0.712067, 0.716026, 0.722112, 0.731546, 0.743506, 0.759791,
+	0.826773, 0.775851, 0.743901, 0.720463, 0.702466, 0.689851, 0.679099, 0.669508,
+	0.662370, 0.655887, 0.652996, 0.652093, 0.654877, 0.660316, 0.668105, 0.679930,
+	0.799897, 0.745013, 0.709118, 0.683517, 0.663819, 0.648032, 0.635627, 0.624947,
+	0.612305, 0.602727, 0.596375, 0.590061, 0.588561, 0.589073, 0.591156, 0.596722,
+	0.772697, 0.711032, 0.673031, 0.645221, 0.624562, 0.607769, 0.594947, 0.580621,
+	0.567329, 0.552140, 0.541318, 0.532491, 0.525627, 0.520017, 0.515886, 0.514738,
+	0.744074, 0.677876, 0.637270, 0.607887, 0.586472, 0.569453, 0.555391, 0.541088,
+	0.524065, 0.507825, 0.492168, 0.478510, 0.466244, 0.455524, 0.445384, 0.437564,
+	0.716253, 0.644899, 0.601815, 0.571245, 0.550105, 0.533811, 0.519026, 0.504281,
+	0.484824, 0.465097, 0.446214, 0.428824, 0.412297, 0.396325, 0.381174, 0.367759,
+	0.999738, 0.999972, 0.999990, 0.999995, 0.999997, 0.999998, 0.999999, 0.999999,
+	0.999999, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 0.999999, 1.000000,
+	0.957006, 0.995107, 0.999028, 0.999597, 0.999769, 0.999854, 0.999901, 0.999931,
+	0.999950, 0.999964, 0.999974, 0.999982, 0.999988, 0.999985, 0.999948, 0.999994,
+	0.890620, 0.961112, 0.984592, 0.992072, 0.995290, 0.996708, 0.998290, 0.998698,
+	0.999080, 0.999507, 0.999723, 0.999600, 0.999798, 0.999460, 0.999579, 0.999950,
+	0.899034, 0.908934, 0.948791, 0.970539, 0.981613, 0.987524, 0.990791, 0.993638,
+	0.994896, 0.995381, 0.996232, 0.996836, 0.997944, 0.997220, 0.998280, 0.999722,
+	0.915892, 0.880416, 0.903583, 0.930734, 0.950196, 0.963328, 0.971803, 0.977348,
+	0.981253, 0.983933, 0.985771, 0.986441, 0.987270, 0.985596, 0.988007, 0.991401,
+	0.923670, 0.875540, 0.873947, 0.891121, 0.910526, 0.927547, 0.940950, 0.951129,
+	0.958201, 0.963250, 0.967207, 0.969643, 0.970122, 0.969658, 0.975216, 0.981554,
+	0.923067, 0.874912, 0.858138, 0.861132, 0.872720, 0.886938, 0.900545, 0.912598,
+	0.921939, 0.930079, 0.935823, 0.940397, 0.942813, 0.944724, 0.952591, 0.962499,
+	0.914541, 0.869102, 0.845748, 0.838104, 0.840033, 0.847135, 0.856774, 0.867002,
+	0.876358, 0.884735, 0.891850, 0.897421, 0.902068, 0.908327, 0.919061, 0.932606,
+	0.901883, 0.857359, 0.830890, 0.816344, 0.809476, 0.809396, 0.813411, 0.818513,
+	0.824357, 0.831080, 0.837166, 0.843514, 0.850239, 0.858309, 0.871468, 0.887464,
+	0.884710, 0.840109, 0.810314, 0.790940, 0.779553, 0.773578, 0.770643, 0.769861,
+	0.771016, 0.772866, 0.775979, 0.781828, 0.787723, 0.797516, 0.811117, 0.828035,
+	0.865655, 0.816777, 0.785407, 0.762874, 0.747321, 0.736051, 0.727674, 0.720727,
+	0.716965, 0.714632, 0.714121, 0.715965, 0.721178, 0.729208, 0.740103, 0.755652,
+	0.843348, 0.790105, 0.755727, 0.730267, 0.711625, 0.695709, 0.683878, 0.674912,
+	0.664241, 0.656191, 0.651478, 0.651193, 0.652918, 0.656405, 0.663435, 0.674719,
+	0.818976, 0.760302, 0.722728, 0.695313, 0.673405, 0.655562, 0.640277, 0.626499,
+	0.613145, 0.601461, 0.592502, 0.587505, 0.583844, 0.583303, 0.585044, 0.590123,
+	0.794039, 0.729590, 0.687668, 0.657800, 0.634279, 0.614975, 0.597819, 0.581018,
+	0.564266, 0.550475, 0.536489, 0.527073, 0.519025, 0.512642, 0.508514, 0.506968,
+	0.767232, 0.697093, 0.652900, 0.619142, 0.595923, 0.575695, 0.557302, 0.539320,
+	0.519830, 0.501245, 0.484515, 0.470929, 0.457873, 0.446618, 0.436906, 0.429248,
+	0.741891, 0.664970, 0.618043, 0.583925, 0.558803, 0.537912, 0.519688, 0.501234,
+	0.476848, 0.455101, 0.436933, 0.418526, 0.401806, 0.385969, 0.371668, 0.359044,
+	0.999738, 0.999972, 0.999990, 0.999995, 0.999997, 0.999998, 0.999999, 0.999999,
+	0.999999, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 0.999999, 1.000000,
+	0.957006, 0.995107, 0.999028, 0.999597, 0.999769, 0.999854, 0.999901, 0.999931,
+	0.999950, 0.999964, 0.999974, 0.999982, 0.999988, 0.999992, 0.999908, 0.999993,
+	0.891046, 0.961112, 0.984714, 0.992255, 0.995351, 0.996830, 0.998413, 0.998881,
+	0.998907, 0.999437, 0.999777, 0.999849, 0.999884, 0.999633, 0.999278, 0.999942,
+	0.899921, 0.909349, 0.949092, 0.970842, 0.981673, 0.987584, 0.990913, 0.993454,
+	0.995074, 0.995680, 0.996564, 0.996988, 0.998122, 0.997943, 0.997508, 0.999686,
+	0.917366, 0.881146, 0.904235, 0.930909, 0.950321, 0.963346, 0.972020, 0.977654,
+	0.981515, 0.984319, 0.985404, 0.986954, 0.987605, 0.986450, 0.987027, 0.991285,
+	0.926340, 0.877502, 0.875070, 0.891901, 0.911259, 0.928175, 0.941534, 0.951341,
+	0.958409, 0.963893, 0.967911, 0.970578, 0.971367, 0.971199, 0.974097, 0.981280,
+	0.926523, 0.877443, 0.860128, 0.862408, 0.873911, 0.887686, 0.901337, 0.913188,
+	0.922864, 0.930601, 0.936986, 0.941547, 0.943973, 0.945654, 0.951510, 0.961956,
+	0.920271, 0.873296, 0.848702, 0.840245, 0.841347, 0.848293, 0.858207, 0.868090,
+	0.877799, 0.885862, 0.893126, 0.899356, 0.904161, 0.909238, 0.918069, 0.931651,
+	0.909357, 0.863588, 0.835472, 0.819430, 0.812717, 0.812573, 0.815526, 0.820034,
+	0.825853, 0.833282, 0.839143, 0.845358, 0.851313, 0.858457, 0.870241, 0.886005,
+	0.895164, 0.847697, 0.816841, 0.796967, 0.784816, 0.777048, 0.773203, 0.772265,
+	0.772432, 0.774902, 0.778717, 0.782918, 0.788857, 0.797034, 0.809637, 0.825995,
+	0.876372, 0.826337, 0.793626, 0.769725, 0.752815, 0.740477, 0.731382, 0.724330,
+	0.719234, 0.716458, 0.715123, 0.716962, 0.720988, 0.727689, 0.738181, 0.752948,
+	0.856744, 0.801447, 0.764642, 0.738248, 0.717781, 0.702133, 0.688558, 0.675428,
+	0.665312, 0.658312, 0.652679, 0.650156, 0.650253, 0.654046, 0.660788, 0.671142,
+	0.834339, 0.773864, 0.733947, 0.704091, 0.680488, 0.660840, 0.645147, 0.628824,
+	0.612189, 0.601070, 0.592357, 0.585865, 0.581587, 0.579968, 0.581298, 0.585791,
+	0.811393, 0.743897, 0.700049, 0.667455, 0.640971, 0.620203, 0.601352, 0.581982,
+	0.563023, 0.547739, 0.534458, 0.523303, 0.514707, 0.508085, 0.503924, 0.501916,
+	0.787929, 0.713033, 0.665156, 0.630680, 0.603117, 0.580616, 0.559749, 0.537347,
+	0.515652, 0.495532, 0.479944, 0.465247, 0.451877, 0.440994, 0.431326, 0.423570,
+	0.762730, 0.681573, 0.630224, 0.594590, 0.564999, 0.541724, 0.520152, 0.496996,
+	0.471423, 0.449499, 0.430396, 0.411330, 0.394594, 0.379285, 0.365369, 0.353101,
+	0.999738, 0.999972, 0.999990, 0.999995, 0.999997, 0.999998, 0.999999, 0.999999,
+	0.999999, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 0.999997, 1.000000,
+	0.957006, 0.995107, 0.999028, 0.999597, 0.999769, 0.999854, 0.999901, 0.999931,
+	0.999950, 0.999964, 0.999974, 0.999982, 0.999988, 0.999930, 0.999733, 0.999992,
+	0.891107, 0.961173, 0.984714, 0.992255, 0.995595, 0.997135, 0.998657, 0.999186,
+	0.999090, 0.999294, 0.999601, 0.999846, 0.999817, 0.999660, 0.998652, 0.999933,
+	0.900655, 0.909606, 0.949400, 0.970843, 0.981673, 0.987706, 0.990912, 0.993328,
+	0.995021, 0.995858, 0.996335, 0.997210, 0.997898, 0.998152, 0.996835, 0.999646,
+	0.918657, 0.881906, 0.904513, 0.931290, 0.950698, 0.963619, 0.971936, 0.977882,
+	0.981527, 0.983866, 0.985586, 0.986977, 0.988117, 0.987859, 0.986356, 0.991157,
+	0.928909, 0.878548, 0.875949, 0.892429, 0.911819, 0.928399, 0.941549, 0.951278,
+	0.958557, 0.964458, 0.968121, 0.970616, 0.972474, 0.972565, 0.973603, 0.980989,
+	0.930352, 0.879771, 0.861917, 0.863633, 0.874495, 0.888191, 0.901984, 0.913597,
+	0.923547, 0.931427, 0.937659, 0.942396, 0.945171, 0.947040, 0.950894, 0.961445,
+	0.925315, 0.876897, 0.851130, 0.841907, 0.843043, 0.850093, 0.859391, 0.868795,
+	0.878580, 0.886758, 0.894400, 0.901008, 0.905792, 0.910310, 0.917673, 0.930870,
+	0.915790, 0.867919, 0.839200, 0.822579, 0.815541, 0.814767, 0.817230, 0.821451,
+	0.827529, 0.833830, 0.840534, 0.846987, 0.852522, 0.859469, 0.869852, 0.884880,
+	0.901755, 0.852911, 0.821989, 0.801142, 0.787213, 0.779600, 0.775701, 0.774093,
+	0.774882, 0.777309, 0.780384, 0.784513, 0.790752, 0.797575, 0.808699, 0.824444,
+	0.885888, 0.834065, 0.799327, 0.774537, 0.757365, 0.743891, 0.734349, 0.725784,
+	0.720393, 0.717721, 0.716561, 0.718426, 0.721505, 0.726884, 0.736717, 0.750893,
+	0.867958, 0.810474, 0.773096, 0.744392, 0.723325, 0.704933, 0.692096, 0.678179,
+	0.666907, 0.658633, 0.652899, 0.650989, 0.650644, 0.652658, 0.659171, 0.668588,
+	0.847446, 0.783970, 0.742694, 0.711090, 0.686359, 0.666068, 0.646938, 0.629200,
+	0.614228, 0.601198, 0.591798, 0.584193, 0.580637, 0.578235, 0.578973, 0.582676,
+	0.825155, 0.755837, 0.709274, 0.674705, 0.647821, 0.624936, 0.603344, 0.581343,
+	0.561911, 0.546008, 0.533128, 0.520844, 0.511961, 0.505489, 0.500801, 0.498391,
+	0.803048, 0.725998, 0.675473, 0.638362, 0.609489, 0.583991, 0.561179, 0.536882,
+	0.512824, 0.494407, 0.475939, 0.461873, 0.448414, 0.436919, 0.427374, 0.419648,
+	0.780368, 0.695666, 0.641256, 0.601737, 0.571364, 0.545376, 0.520481, 0.492328,
+	0.468307, 0.445264, 0.424498, 0.406474, 0.389422, 0.374707, 0.361329, 0.349101,
+	0.999738, 0.999972, 0.999990, 0.999995, 0.999997, 0.999998, 0.999999, 0.999999,
+	0.999999, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000,
+	0.957006, 0.995107, 0.999028, 0.999597, 0.999769, 0.999854, 0.999901, 0.999931,
+	0.999950, 0.999964, 0.999974, 0.999982, 0.999988, 0.999893, 0.999927, 0.999991,
+	0.891412, 0.961295, 0.984836, 0.992377, 0.995779, 0.997196, 0.998779, 0.999369,
+	0.999273, 0.999154, 0.999642, 0.999849, 0.999531, 0.999857, 0.998805, 0.999922,
+	0.901234, 0.909905, 0.949582, 0.970843, 0.981856, 0.987767, 0.991095, 0.993510,
+	0.994825, 0.995948, 0.996455, 0.997211, 0.998229, 0.998309, 0.996968, 0.999599,
+	0.919625, 0.882460, 0.905018, 0.931482, 0.950944, 0.963667, 0.972114, 0.977773,
+	0.981670, 0.984087, 0.985945, 0.987393, 0.988494, 0.988608, 0.986354, 0.991026,
+	0.930367, 0.879670, 0.876627, 0.892763, 0.911958, 0.928678, 0.941735, 0.951675,
+	0.959047, 0.964541, 0.968337, 0.971386, 0.972932, 0.973742, 0.973731, 0.980711,
+	0.932851, 0.881281, 0.862785, 0.864416, 0.875310, 0.889020, 0.902144, 0.914025,
+	0.924020, 0.932199, 0.938229, 0.943226, 0.946585, 0.948430, 0.951403, 0.960931,
+	0.928440, 0.879354, 0.853346, 0.843898, 0.844745, 0.851095, 0.860432, 0.869554,
+	0.878974, 0.888043, 0.895912, 0.902065, 0.907180, 0.911007, 0.918021, 0.930184,
+	0.920047, 0.871667, 0.841949, 0.824717, 0.817362, 0.815641, 0.818260, 0.822750,
+	0.828866, 0.835513, 0.842290, 0.848352, 0.853996, 0.860437, 0.869868, 0.884024,
+	0.908358, 0.858839, 0.825719, 0.804077, 0.790348, 0.782007, 0.777316, 0.775199,
+	0.775561, 0.777847, 0.781791, 0.786185, 0.791564, 0.799021, 0.808828, 0.823317,
+	0.894152, 0.839886, 0.804681, 0.779556, 0.760593, 0.746418, 0.736247, 0.727415,
+	0.722296, 0.719528, 0.718543, 0.719746, 0.722151, 0.728070, 0.736543, 0.749573,
+	0.876496, 0.818053, 0.778652, 0.749507, 0.727067, 0.709486, 0.693227, 0.679126,
+	0.667393, 0.659769, 0.654669, 0.651342, 0.650490, 0.652555, 0.658183, 0.666865,
+	0.857867, 0.792674, 0.749344, 0.716762, 0.690867, 0.669593, 0.649916, 0.630013,
+	0.614090, 0.601196, 0.591291, 0.584576, 0.579204, 0.576972, 0.577390, 0.580618,
+	0.837202, 0.764715, 0.717368, 0.681849, 0.652760, 0.628557, 0.605288, 0.581669,
+	0.562218, 0.545244, 0.531013, 0.520281, 0.510322, 0.503329, 0.498660, 0.495956,
+	0.816488, 0.736690, 0.684257, 0.645380, 0.613823, 0.587555, 0.561439, 0.534429,
+	0.512609, 0.491043, 0.474378, 0.458783, 0.445779, 0.434443, 0.424973, 0.417069,
+	0.794713, 0.707011, 0.650643, 0.607800, 0.576134, 0.547358, 0.519963, 0.490476,
+	0.463720, 0.441341, 0.421542, 0.402606, 0.385915, 0.371211, 0.358224, 0.346373,
+	0.999738, 0.999972, 0.999990, 0.999995, 0.999997, 0.999998, 0.999999, 0.999999,
+	0.999999, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000,
+	0.957006, 0.995107, 0.999028, 0.999597, 0.999769, 0.999854, 0.999901, 0.999931,
+	0.999950, 0.999964, 0.999974, 0.999982, 0.999988, 0.999992, 0.999919, 0.999989,
+	0.891412, 0.961295, 0.984897, 0.992560, 0.995779, 0.997196, 0.998779, 0.999369,
+	0.999578, 0.999276, 0.999593, 0.999812, 0.999897, 0.999818, 0.999369, 0.999910,
+	0.901455, 0.910032, 0.949582, 0.971025, 0.981916, 0.987827, 0.991156, 0.993571,
+	0.994681, 0.995995, 0.996519, 0.997332, 0.998368, 0.998663, 0.997554, 0.999546,
+	0.920827, 0.882909, 0.905166, 0.931703, 0.951045, 0.963885, 0.972176, 0.977771,
+	0.981534, 0.984248, 0.986110, 0.987568, 0.988720, 0.988668, 0.987044, 0.990868,
+	0.931946, 0.880617, 0.877226, 0.893319, 0.912352, 0.928857, 0.941982, 0.951982,
+	0.959173, 0.964918, 0.968541, 0.971824, 0.973609, 0.974462, 0.973769, 0.980383,
+	0.935174, 0.883040, 0.864149, 0.865120, 0.875970, 0.889525, 0.902928, 0.914392,
+	0.924274, 0.932548, 0.939185, 0.944021, 0.947478, 0.949164, 0.951513, 0.960508,
+	0.931971, 0.881727, 0.855000, 0.844879, 0.845427, 0.851614, 0.860501, 0.869964,
+	0.879755, 0.888813, 0.896563, 0.903047, 0.908498, 0.912729, 0.917840, 0.929651,
+	0.924752, 0.874691, 0.844107, 0.827043, 0.819230, 0.817135, 0.818912, 0.823429,
+	0.829529, 0.836312, 0.843169, 0.849953, 0.855896, 0.862070, 0.870237, 0.883322,
+	0.913026, 0.861936, 0.828962, 0.807268, 0.792087, 0.783409, 0.778489, 0.776482,
+	0.777069, 0.779354, 0.783352, 0.787415, 0.792996, 0.799446, 0.809190, 0.822527,
+	0.899450, 0.844954, 0.808842, 0.782080, 0.762665, 0.749747, 0.736645, 0.729218,
+	0.723602, 0.720169, 0.719341, 0.720661, 0.723554, 0.728141, 0.736547, 0.748565,
+	0.883467, 0.824234, 0.783707, 0.754275, 0.730384, 0.711491, 0.694464, 0.680509,
+	0.669430, 0.661209, 0.655944, 0.652297, 0.650815, 0.652472, 0.657580, 0.665620,
+	0.866433, 0.799542, 0.755327, 0.721611, 0.694884, 0.671462, 0.650571, 0.631501,
+	0.615273, 0.602605, 0.592190, 0.584200, 0.578351, 0.576471, 0.576739, 0.579177,
+	0.847074, 0.773645, 0.723787, 0.686762, 0.656569, 0.630672, 0.605377, 0.581578,
+	0.561867, 0.545481, 0.530386, 0.518849, 0.509342, 0.502813, 0.497556, 0.494258,
+	0.827088, 0.745457, 0.690920, 0.650752, 0.618033, 0.589594, 0.560844, 0.534477,
+	0.511167, 0.491375, 0.472746, 0.457146, 0.443999, 0.432726, 0.423502, 0.415108,
+	0.806600, 0.716616, 0.658197, 0.614761, 0.579151, 0.549512, 0.517843, 0.487951,
+	0.462697, 0.439288, 0.418431, 0.399842, 0.383593, 0.368952, 0.356121, 0.344517,
+	0.999738, 0.999972, 0.999990, 0.999995, 0.999997, 0.999998, 0.999999, 0.999999,
+	0.999999, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000,
+	0.957006, 0.995107, 0.999028, 0.999597, 0.999769, 0.999854, 0.999901, 0.999931,
+	0.999950, 0.999964, 0.999974, 0.999982, 0.999988, 0.999992, 0.999984, 0.999987,
+	0.891595, 0.961417, 0.985019, 0.992743, 0.995779, 0.997196, 0.998779, 0.999369,
+	0.999578, 0.999459, 0.999393, 0.999808, 0.999897, 0.999566, 0.999526, 0.999895,
+	0.901853, 0.910090, 0.949703, 0.971086, 0.982038, 0.987949, 0.991278, 0.993632,
+	0.994864, 0.995836, 0.996730, 0.997231, 0.998458, 0.998985, 0.998190, 0.999485,
+	0.921432, 0.883472, 0.905259, 0.931749, 0.951199, 0.964007, 0.972456, 0.978072,
+	0.981320, 0.984346, 0.986100, 0.987702, 0.989097, 0.989069, 0.987439, 0.990695,
+	0.933298, 0.881258, 0.877945, 0.893547, 0.912668, 0.929210, 0.942055, 0.951835,
+	0.959325, 0.964647, 0.968931, 0.971996, 0.974132, 0.974956, 0.974837, 0.980093,
+	0.937368, 0.884236, 0.864828, 0.866193, 0.876683, 0.889950, 0.902969, 0.914671,
+	0.924416, 0.932638, 0.939548, 0.944407, 0.948401, 0.950243, 0.952057, 0.960103,
+	0.934349, 0.883698, 0.856444, 0.846149, 0.846407, 0.852068, 0.860630, 0.870493,
+	0.880300, 0.889431, 0.897000, 0.903992, 0.909516, 0.913861, 0.918336, 0.929115,
+	0.927699, 0.877741, 0.846632, 0.828706, 0.819684, 0.817892, 0.819761, 0.824237,
+	0.830458, 0.837394, 0.844553, 0.850896, 0.857290, 0.863070, 0.870630, 0.882868,
+	0.917733, 0.866033, 0.831783, 0.808527, 0.793657, 0.785232, 0.779226, 0.777377,
+	0.778034, 0.780840, 0.784241, 0.789183, 0.794118, 0.800827, 0.809407, 0.822000,
+	0.905556, 0.849623, 0.812166, 0.785232, 0.765324, 0.750023, 0.738411, 0.729354,
+	0.725009, 0.721746, 0.721013, 0.722110, 0.724926, 0.729789, 0.736256, 0.747838,
+	0.890348, 0.828710, 0.788725, 0.757057, 0.733574, 0.712681, 0.696016, 0.681323,
+	0.670605, 0.662457, 0.656669, 0.652722, 0.651915, 0.653705, 0.657750, 0.664853,
+	0.873374, 0.806170, 0.759953, 0.725643, 0.697804, 0.674023, 0.650713, 0.632117,
+	0.617030, 0.603097, 0.592521, 0.584670, 0.579479, 0.576797, 0.576123, 0.578203,
+	0.856215, 0.780701, 0.729785, 0.691157, 0.660038, 0.632475, 0.605968, 0.582790,
+	0.562455, 0.545172, 0.530036, 0.519224, 0.509526, 0.502016, 0.496744, 0.492977,
+	0.836973, 0.753918, 0.697334, 0.655180, 0.621346, 0.591197, 0.561501, 0.532831,
+	0.509658, 0.489778, 0.471923, 0.456085, 0.443181, 0.432346, 0.422362, 0.413925,
+	0.816852, 0.725062, 0.664522, 0.619308, 0.582453, 0.549817, 0.517352, 0.487403,
+	0.460415, 0.437260, 0.416315, 0.398418, 0.382001, 0.367829, 0.354750, 0.343167,
+	0.999738, 0.999972, 0.999990, 0.999995, 0.999997, 0.999998, 0.999999, 0.999999,
+	0.999999, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000,
+	0.957006, 0.995107, 0.999028, 0.999597, 0.999769, 0.999854, 0.999901, 0.999931,
+	0.999950, 0.999964, 0.999974, 0.999982, 0.999988, 0.999992, 0.999993, 0.999985,
+	0.891656, 0.961417, 0.985019, 0.992743, 0.995779, 0.997196, 0.998779, 0.999369,
+	0.999578, 0.999703, 0.999362, 0.999729, 0.999776, 0.999805, 0.999699, 0.999877,
+	0.902175, 0.910215, 0.949825, 0.971086, 0.982038, 0.988071, 0.991400, 0.993693,
+	0.994864, 0.995880, 0.996843, 0.997212, 0.998489, 0.998982, 0.998407, 0.999406,
+	0.922063, 0.883708, 0.905693, 0.931923, 0.951243, 0.964065, 0.972417, 0.977914,
+	0.981380, 0.984427, 0.986279, 0.987660, 0.989176, 0.989428, 0.988023, 0.990508,
+	0.934683, 0.882075, 0.878174, 0.893961, 0.912784, 0.929459, 0.942089, 0.952060,
+	0.959477, 0.964872, 0.969055, 0.972289, 0.974501, 0.975879, 0.975331, 0.979782,
+	0.938883, 0.885013, 0.865496, 0.866264, 0.876633, 0.890097, 0.903344, 0.914853,
+	0.925007, 0.933135, 0.939667, 0.945115, 0.948961, 0.951665, 0.952680, 0.959733,
+	0.936729, 0.884966, 0.857439, 0.846719, 0.846520, 0.852385, 0.861249, 0.870870,
+	0.880918, 0.889805, 0.898261, 0.904878, 0.910622, 0.914768, 0.918686, 0.928842,
+	0.930338, 0.879283, 0.847686, 0.829386, 0.820979, 0.818795, 0.820413, 0.824780,
+	0.831206, 0.838216, 0.844722, 0.851912, 0.858145, 0.864145, 0.871145, 0.882490,
+	0.921032, 0.868649, 0.834206, 0.810727, 0.795490, 0.785586, 0.779856, 0.778074,
+	0.778916, 0.781403, 0.785903, 0.790291, 0.796072, 0.801964, 0.809293, 0.821597,
+	0.909215, 0.852918, 0.815030, 0.787302, 0.767156, 0.751549, 0.739367, 0.731137,
+	0.725190, 0.723011, 0.722403, 0.722912, 0.725846, 0.730143, 0.737163, 0.747414,
+	0.895481, 0.833653, 0.791224, 0.760199, 0.734796, 0.714919, 0.696353, 0.682360,
+	0.671068, 0.663195, 0.657861, 0.654197, 0.652743, 0.653981, 0.657263, 0.664136,
+	0.880657, 0.810465, 0.764171, 0.728410, 0.700376, 0.675522, 0.652746, 0.632479,
+	0.616722, 0.603837, 0.593112, 0.585520, 0.579923, 0.576857, 0.575849, 0.577305,
+	0.862483, 0.785883, 0.734568, 0.694950, 0.662727, 0.634134, 0.605637, 0.582528,
+	0.563442, 0.545242, 0.530860, 0.518242, 0.509616, 0.501763, 0.496285, 0.492374,
+	0.845020, 0.759020, 0.702599, 0.659139, 0.624267, 0.592391, 0.560429, 0.534719,
+	0.509814, 0.489240, 0.471457, 0.455755, 0.442548, 0.430938, 0.421655, 0.413161,
+	0.826013, 0.731604, 0.670040, 0.623175, 0.585276, 0.550403, 0.516897, 0.486029,
+	0.459943, 0.436360, 0.414849, 0.397139, 0.381261, 0.366735, 0.353952, 0.342189,
+	0.999738, 0.999972, 0.999990, 0.999995, 0.999997, 0.999998, 0.999999, 0.999999,
+	0.999999, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000, 1.000000,
+	0.957006, 0.995107, 0.999028, 0.999597, 0.999769, 0.999854, 0.999901, 0.999931,
+	0.999950, 0.999964, 0.999974, 0.999982, 0.999988, 0.999992, 0.999995, 0.999983,
+	0.891839, 0.961539, 0.985202, 0.992743, 0.995779, 0.997196, 0.998779, 0.999369,
+	0.999578, 0.999703, 0.999423, 0.999665, 0.999897, 0.999918, 0.999707, 0.999855,
+	0.902353, 0.910576, 0.949824, 0.971207, 0.982159, 0.988132, 0.991460, 0.993815,
+	0.994985, 0.995699, 0.996736, 0.997355, 0.998303, 0.998951, 0.998749, 0.999313,
+	0.922514, 0.883874, 0.905811, 0.932022, 0.951126, 0.963862, 0.972361, 0.977888,
+	0.981642, 0.984529, 0.986380, 0.987991, 0.989197, 0.989606, 0.988294, 0.990306,
+	0.935411, 0.882582, 0.878725, 0.894166, 0.912971, 0.929299, 0.942089, 0.952135,
+	0.959492, 0.965038, 0.969427, 0.972629, 0.974844, 0.976122, 0.975684, 0.979489,
+	0.939919, 0.886156, 0.866165, 0.866721, 0.876920, 0.890213, 0.903338, 0.915084,
+	0.924867, 0.933560, 0.939989, 0.945267, 0.949658, 0.952116, 0.953259, 0.959427,
+	0.938551, 0.886535, 0.858533, 0.847311, 0.847149, 0.852827, 0.861459, 0.871375,
+	0.881264, 0.890137, 0.898655, 0.905493, 0.911696, 0.915482, 0.920006, 0.928518,
+	0.933329, 0.881703, 0.849193, 0.830166, 0.821688, 0.819072, 0.820702, 0.825355,
+	0.831556, 0.838635, 0.845961, 0.852963, 0.859534, 0.865123, 0.871505, 0.882190,
+	0.924854, 0.870774, 0.835530, 0.811966, 0.796868, 0.786368, 0.780280, 0.779039,
+	0.779805, 0.782398, 0.786514, 0.791332, 0.796450, 0.802838, 0.810101, 0.821360,
+	0.913678, 0.855588, 0.817069, 0.789737, 0.768551, 0.752458, 0.740260, 0.731365,
+	0.726622, 0.723798, 0.722994, 0.724429, 0.726443, 0.730590, 0.737370, 0.747140,
+	0.899743, 0.836812, 0.794625, 0.762015, 0.737281, 0.715546, 0.697235, 0.683101,
+	0.671812, 0.664052, 0.658265, 0.655403, 0.653655, 0.654265, 0.658044, 0.663982,
+	0.884917, 0.815427, 0.766993, 0.731649, 0.702035, 0.676168, 0.652928, 0.633678,
+	0.617367, 0.604741, 0.593844, 0.586058, 0.580795, 0.576989, 0.575828, 0.577044,
+	0.868990, 0.790559, 0.737508, 0.697411, 0.664833, 0.635131, 0.607486, 0.583153,
+	0.562505, 0.546174, 0.530964, 0.519262, 0.509540, 0.501816, 0.496104, 0.491792,
+	0.851248, 0.764508, 0.706170, 0.662068, 0.625962, 0.592289, 0.560797, 0.532539,
+	0.509440, 0.489374, 0.471138, 0.455352, 0.442418, 0.431070, 0.420975, 0.412495,
+	0.832760, 0.737686, 0.673951, 0.626390, 0.586252, 0.550531, 0.515049, 0.485799,
+	0.458835, 0.435239, 0.414507, 0.396272, 0.380305, 0.366291, 0.353356, 0.341608,
+];
+
+/// Albedo of a white sheen lobe, indexed by [roughness][cos]
+#[rustfmt::skip]
+pub static SHEEN_ALBEDO: [f64; 1024] = [
+	5.404712, 1.446407, 0.374266, 0.085557, 0.016531, 0.002475, 0.000101, 0.000000,
+	0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000,
+	0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000,
+	0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000,
+	4.617702, 1.859305, 0.771245, 0.309429, 0.117287, 0.041380, 0.013382, 0.003869,
+	0.000919, 0.000084, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000,
+	0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000,
+	0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000,
+	3.426565, 1.927203, 1.135824, 0.672812, 0.394812, 0.227737, 0.128437, 0.070529,
+	0.037552, 0.019308, 0.009527, 0.004481, 0.001974, 0.000787, 0.000263, 0.000023,
+	0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000,
+	0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000,
+	2.710976, 1.763953, 1.200134, 0.826455, 0.569690, 0.390826, 0.265867, 0.178849,
+	0.118701, 0.077560, 0.049788, 0.031334, 0.019279, 0.011567, 0.006740, 0.003795,
+	0.002044, 0.001036, 0.000482, 0.000191, 0.000030, 0.000000, 0.000000, 0.000000,
+	0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000,
+	2.254700, 1.588435, 1.169970, 0.873585, 0.655106, 0.491087, 0.366926, 0.272680,
+	0.201208, 0.147209, 0.106631, 0.076365, 0.054000, 0.037639, 0.025815, 0.017389,
+	0.011477, 0.007396, 0.004637, 0.002810, 0.001631, 0.000895, 0.000451, 0.000196,
+	0.000048, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000,
+	1.933066, 1.435137, 1.112372, 0.874064, 0.690567, 0.546442, 0.432000, 0.340610,
+	0.267472, 0.208948, 0.162210, 0.125010, 0.095544, 0.072339, 0.054193, 0.040119,
+	0.029303, 0.021081, 0.014907, 0.010336, 0.007002, 0.004612, 0.002938, 0.001791,
+	0.001031, 0.000543, 0.000244, 0.000076, 0.000000, 0.000000, 0.000000, 0.000000,
+	1.695382, 1.308268, 1.050044, 0.854609, 0.699673, 0.574271, 0.471498, 0.386655,
+	0.316329, 0.257931, 0.209426, 0.169191, 0.135888, 0.108412, 0.085840, 0.067390,
+	0.052397, 0.040298, 0.030614, 0.022930, 0.016899, 0.012223, 0.008644, 0.005952,
+	0.003963, 0.002530, 0.001523, 0.000842, 0.000403, 0.000142, 0.000000, 0.000000,
+	1.513575, 1.201495, 0.990810, 0.827250, 0.695031, 0.585744, 0.494173, 0.416771,
+	0.351013, 0.294978, 0.247164, 0.206345, 0.171526, 0.141874, 0.116678, 0.095337,
+	0.077328, 0.062204, 0.049566, 0.039077, 0.030428, 0.023359, 0.017636, 0.013053,
+	0.009431, 0.006615, 0.004465, 0.002860, 0.001701, 0.000893, 0.000354, 0.000021,
+	1.370639, 1.111527, 0.936250, 0.797530, 0.683446, 0.587606, 0.505978, 0.435798,
+	0.375078, 0.322357, 0.276464, 0.236457, 0.201580, 0.171181, 0.144720, 0.121728,
+	0.101794, 0.084565, 0.069728, 0.057004, 0.046149, 0.036939, 0.029183, 0.022695,
+	0.017324, 0.012922, 0.009365, 0.006531, 0.004319, 0.002634, 0.001390, 0.000490,
+	1.255812, 1.035986, 0.887743, 0.767975, 0.668493, 0.583852, 0.510840, 0.447214,
+	0.391435, 0.342295, 0.298859, 0.260405, 0.226314, 0.196086, 0.169289, 0.145550,
+	0.124549, 0.106009, 0.089676, 0.075330, 0.062774, 0.051828, 0.042332, 0.034138,
+	0.027116, 0.021143, 0.016107, 0.011907, 0.008451, 0.005648, 0.003415, 0.001659,
+	1.161902, 0.973968, 0.845161, 0.740124, 0.652261, 0.576910, 0.511249, 0.453482,
+	0.402255, 0.356624, 0.315831, 0.279257, 0.246419, 0.216906, 0.190368, 0.166511,
+	0.145073, 0.125829, 0.108580, 0.093149, 0.079375, 0.067116, 0.056240, 0.046630,
+	0.038174, 0.030777, 0.024344, 0.018791, 0.014038, 0.010009, 0.006634, 0.003905,
+	1.083968, 0.921331, 0.807267, 0.714246, 0.635929, 0.568258, 0.508876, 0.456203,
+	0.409126, 0.366791, 0.328594, 0.294017, 0.262653, 0.234161, 0.208252, 0.184680,
+	0.163240, 0.143740, 0.126023, 0.109940, 0.095362, 0.082173, 0.070265, 0.059543,
+	0.049923, 0.041318, 0.033655, 0.026864, 0.020879, 0.015645, 0.011143, 0.007265,
+	1.018496, 0.876022, 0.773526, 0.690248, 0.619987, 0.558839, 0.504804, 0.456558,
+	0.413162, 0.373872, 0.338132, 0.305537, 0.275718, 0.248403, 0.223331, 0.200310,
+	0.179154, 0.159716, 0.141853, 0.125450, 0.110394, 0.096598, 0.083969, 0.072427,
+	0.061900, 0.052326, 0.043639, 0.035785, 0.028719, 0.022414, 0.016772, 0.011794,
+	0.962901, 0.836659, 0.743434, 0.668230, 0.604692, 0.549119, 0.499725, 0.455358,
+	0.415201, 0.378652, 0.345209, 0.314498, 0.286225, 0.260132, 0.236014, 0.213693,
+	0.193011, 0.173843, 0.156071, 0.139595, 0.124325, 0.110175, 0.097078, 0.084967,
+	0.073778, 0.063461, 0.053966, 0.045265, 0.037296, 0.030006, 0.023384, 0.017381,
+	0.915241, 0.802242, 0.716609, 0.648573, 0.590554, 0.539519, 0.494062, 0.453101,
+	0.415834, 0.381742, 0.350400, 0.321463, 0.294675, 0.269803, 0.246676, 0.225132,
+	0.205041, 0.186283, 0.168764, 0.152395, 0.137096, 0.122798, 0.109441, 0.096968,
+	0.085329, 0.074484, 0.064400, 0.055016, 0.046295, 0.038223, 0.030755, 0.023871,
+	0.874061, 0.772010, 0.692612, 0.630798, 0.577380, 0.530301, 0.488218, 0.450158,
+	0.415468, 0.383580, 0.354144, 0.326853, 0.301461, 0.277784, 0.255646, 0.234918,
+	0.215475, 0.197220, 0.180059, 0.163921, 0.148737, 0.134444, 0.120989, 0.108327,
+	0.096427, 0.085233, 0.074701, 0.064808, 0.055527, 0.046825, 0.038677, 0.031060,
+	0.838259, 0.745285, 0.671141, 0.614663, 0.565116, 0.521450, 0.482347, 0.446873,
+	0.414399, 0.384496, 0.356795, 0.331009, 0.306930, 0.284377, 0.263213, 0.243298,
+	0.224534, 0.206825, 0.190098, 0.174277, 0.159306, 0.145133, 0.131714, 0.119012,
+	0.106968, 0.095551, 0.084739, 0.074496, 0.064800, 0.055624, 0.046947, 0.038748,
+	0.806922, 0.721563, 0.651877, 0.599971, 0.553718, 0.512998, 0.476524, 0.443357,
+	0.412900, 0.384744, 0.358599, 0.334195, 0.311332, 0.289845, 0.269606, 0.250489,
+	0.232409, 0.215272, 0.199018, 0.183571, 0.168892, 0.154931, 0.141641, 0.128974,
+	0.116897, 0.105392, 0.094417, 0.083954, 0.073976, 0.064461, 0.055394, 0.046754,
+	0.779332, 0.700453, 0.634633, 0.586585, 0.543182, 0.505131, 0.470930, 0.439734,
+	0.411080, 0.384513, 0.359776, 0.336622, 0.314878, 0.294390, 0.275026, 0.256681,
+	0.239271, 0.222714, 0.206952, 0.191921, 0.177587, 0.163884, 0.150778, 0.138229,
+	0.126219, 0.114707, 0.103676, 0.093095, 0.082949, 0.073214, 0.063874, 0.054915,
+	0.754919, 0.681566, 0.619443, 0.574395, 0.533453, 0.497842, 0.465621, 0.436180,
+	0.409058, 0.383932, 0.360460, 0.338462, 0.317745, 0.298178, 0.279636, 0.262030,
+	0.245270, 0.229288, 0.214027, 0.199437, 0.185461, 0.172051, 0.159179, 0.146819,
+	0.134928, 0.123490, 0.112476, 0.101866, 0.091642, 0.081783, 0.072274, 0.063101,
+	0.733216, 0.664638, 0.605846, 0.563261, 0.524627, 0.491058, 0.460555, 0.432684,
+	0.406979, 0.383088, 0.360786, 0.339823, 0.320057, 0.301345, 0.283577, 0.266666,
+	0.250530, 0.235114, 0.220357, 0.206197, 0.192593, 0.179504, 0.166910, 0.154765,
+	0.143051, 0.131736, 0.120804, 0.110232, 0.100003, 0.090098, 0.080505, 0.071207,
+	0.713841, 0.649386, 0.593617, 0.553096, 0.516566, 0.484720, 0.455757, 0.429273,
+	0.404836, 0.382112, 0.360827, 0.340829, 0.321917, 0.304000, 0.286952, 0.270700,
+	0.255166, 0.240293, 0.226015, 0.212284, 0.199062, 0.186317, 0.174010, 0.162117,
+	0.150607, 0.139463, 0.128658, 0.118177, 0.108000, 0.098113, 0.088501, 0.079150,
+	0.696477, 0.635624, 0.582546, 0.543790, 0.509182, 0.478819, 0.451199, 0.425972,
+	0.402691, 0.381008, 0.360677, 0.341529, 0.323432, 0.306237, 0.289863, 0.274227,
+	0.259268, 0.244904, 0.231090, 0.217780, 0.204945, 0.192541, 0.180539, 0.168912,
+	0.157637, 0.146687, 0.136046, 0.125692, 0.115615, 0.105796, 0.096217, 0.086872,
+	0.680858, 0.623176, 0.572483, 0.535265, 0.502364, 0.473338, 0.446918, 0.422823,
+	0.400565, 0.379819, 0.360377, 0.342019, 0.324641, 0.308124, 0.292382, 0.277329,
+	0.262895, 0.249016, 0.235651, 0.222759, 0.210301, 0.198236, 0.186544, 0.175197,
+	0.164165, 0.153433, 0.142980, 0.132786, 0.122840, 0.113125, 0.103625, 0.094331,
+	0.666767, 0.611868, 0.563326, 0.527455, 0.496085, 0.468221, 0.442896, 0.419869,
+	0.398534, 0.378622, 0.359939, 0.342327, 0.325619, 0.309735, 0.294569, 0.280054,
+	0.266114, 0.252702, 0.239767, 0.227274, 0.215179, 0.203457, 0.192071, 0.181003,
+	0.170228, 0.159727, 0.149478, 0.139466, 0.129676, 0.120094, 0.110706, 0.101498,
+	0.654016, 0.601569, 0.554972, 0.520283, 0.490295, 0.463458, 0.439177, 0.417079,
+	0.396573, 0.377431, 0.359454, 0.342492, 0.326409, 0.311093, 0.296479, 0.282464,
+	0.268990, 0.256014, 0.243490, 0.231376, 0.219637, 0.208241, 0.197164, 0.186378,
+	0.175864, 0.165599, 0.155565, 0.145748, 0.136132, 0.126703, 0.117449, 0.108357,
+	0.642439, 0.592176, 0.547320, 0.513677, 0.484943, 0.459038, 0.435715, 0.414429,
+	0.394677, 0.376238, 0.358914, 0.342552, 0.327044, 0.312272, 0.298146, 0.284592,
+	0.271560, 0.259001, 0.246867, 0.235112, 0.223715, 0.212640, 0.201859, 0.191352,
+	0.181097, 0.171073, 0.161261, 0.151648, 0.142219, 0.132960, 0.123857, 0.114900,
+	0.631899, 0.583600, 0.540314, 0.507587, 0.479991, 0.454914, 0.432477, 0.411933,
+	0.392861, 0.375060, 0.358339, 0.342537, 0.327544, 0.313272, 0.299603, 0.286491,
+	0.273872, 0.261702, 0.249929, 0.238525, 0.227452, 0.216686, 0.206196, 0.195962,
+	0.185963, 0.176177, 0.166591, 0.157188, 0.147952, 0.138870, 0.129933, 0.121125,
+	0.622277, 0.575737, 0.533870, 0.501971, 0.475406, 0.451074, 0.429458, 0.409578,
+	0.391128, 0.373911, 0.357742, 0.342465, 0.327957, 0.314129, 0.300892, 0.288184,
+	0.275955, 0.264145, 0.252722, 0.241648, 0.230886, 0.220415, 0.210204, 0.200236,
+	0.190488, 0.180939, 0.171577, 0.162384, 0.153346, 0.144449, 0.135685, 0.127037,
+	0.613473, 0.568513, 0.527929, 0.496786, 0.471147, 0.447503, 0.426632, 0.407353,
+	0.389465, 0.372785, 0.357125, 0.342335, 0.328280, 0.314864, 0.302032, 0.289700,
+	0.277832, 0.266365, 0.255274, 0.244505, 0.234046, 0.223856, 0.213917, 0.204204,
+	0.194699, 0.185385, 0.176242, 0.167258, 0.158419, 0.149711, 0.141123, 0.132642,
+	0.605398, 0.561863, 0.522465, 0.491987, 0.467195, 0.444189, 0.423981, 0.405250,
+	0.387879, 0.371700, 0.356510, 0.342162, 0.328525, 0.315501, 0.303037, 0.291068,
+	0.279536, 0.268395, 0.257605, 0.247138, 0.236954, 0.227036, 0.217355, 0.207889,
+	0.198622, 0.189534, 0.180608, 0.171832, 0.163190, 0.154671, 0.146260, 0.137951,
+	0.597975, 0.555737, 0.517409, 0.487542, 0.463522, 0.441126, 0.421493, 0.403265,
+	0.386379, 0.370680, 0.355919, 0.341964, 0.328712, 0.316058, 0.303934, 0.292302,
+	0.281080, 0.270242, 0.259745, 0.249553, 0.239643, 0.229980, 0.220546, 0.211319,
+	0.202279, 0.193410, 0.184696, 0.176122, 0.167676, 0.159343, 0.151112, 0.142974,
+];
+
+/// Cosine weighted average of each row of SHEEN_ALBEDO
+#[rustfmt::skip]
+pub static SHEEN_AVERAGE_ALBEDO: [f64; 32] = [
+	0.012101, 0.017556, 0.029073, 0.040869, 0.052799, 0.064729, 0.076564, 0.088198,
+	0.099550, 0.110542, 0.121127, 0.131244, 0.140859, 0.149950, 0.158518, 0.166562,
+	0.174088, 0.181113, 0.187661, 0.193758, 0.199429, 0.204698, 0.209592, 0.214136,
+	0.218358, 0.222281, 0.225928, 0.229318, 0.232474, 0.235411, 0.238148, 0.240702,
+];
+
+/// Albedo of the white principled Burley diffuse, indexed by [roughness][cos]
+#[rustfmt::skip]
+pub static PRINCIPLED_DIFFUSE_ALBEDO: [f64; 1024] = [
+	0.092642, 0.220574, 0.332739, 0.430646, 0.515701, 0.589215, 0.652407, 0.706406,
+	0.752256, 0.790917, 0.823270, 0.850123, 0.872210, 0.890196, 0.904682, 0.916206,
+	0.925247, 0.932232, 0.937534, 0.941477, 0.944342, 0.946366, 0.947752, 0.948664,
+	0.949236, 0.949574, 0.949760, 0.949853, 0.949895, 0.949913, 0.949921, 0.949927,
+	0.133001, 0.254235, 0.360438, 0.453061, 0.533458, 0.602888, 0.662519, 0.713433,
+	0.756627, 0.793018, 0.823448, 0.848684, 0.869425, 0.886302, 0.899886, 0.910684,
+	0.919153, 0.925691, 0.930653, 0.934342, 0.937024, 0.938921, 0.940222, 0.941081,
+	0.941624, 0.941950, 0.942135, 0.942233, 0.942284, 0.942312, 0.942332, 0.942349,
+	0.172695, 0.287314, 0.387629, 0.475036, 0.550834, 0.616231, 0.672347, 0.720214,
+	0.760787, 0.794938, 0.823469, 0.847110, 0.866523, 0.882307, 0.894999, 0.905083,
+	0.912985, 0.919083, 0.923709, 0.927149, 0.929650, 0.931421, 0.932638, 0.933446,
+	0.933961, 0.934274, 0.934457, 0.934560, 0.934619, 0.934658, 0.934689, 0.934718,
+	0.211716, 0.319805, 0.414307, 0.496564, 0.567823, 0.629241, 0.681887, 0.726749,
+	0.764734, 0.796676, 0.823334, 0.845401, 0.863503, 0.878208, 0.890022, 0.899400,
+	0.906744, 0.912408, 0.916703, 0.919896, 0.922219, 0.923866, 0.925001, 0.925758,
+	0.926244, 0.926545, 0.926726, 0.926834, 0.926902, 0.926951, 0.926993, 0.927032,
+	0.250057, 0.351701, 0.440467, 0.517643, 0.584423, 0.641914, 0.691137, 0.733034,
+	0.768468, 0.798230, 0.823040, 0.843555, 0.860366, 0.874006, 0.884955, 0.893637,
+	0.900430, 0.905666, 0.909635, 0.912585, 0.914732, 0.916257, 0.917310, 0.918017,
+	0.918475, 0.918764, 0.918943, 0.919056, 0.919132, 0.919191, 0.919243, 0.919293,
+	0.287711, 0.382996, 0.466104, 0.538267, 0.600629, 0.654247, 0.700094, 0.739067,
+	0.771985, 0.799598, 0.822587, 0.841572, 0.857109, 0.869700, 0.879795, 0.887792,
+	0.894043, 0.898857, 0.902504, 0.905215, 0.907189, 0.908593, 0.909566, 0.910222,
+	0.910653, 0.910930, 0.911108, 0.911225, 0.911309, 0.911377, 0.911440, 0.911501,
+	0.324670, 0.413685, 0.491211, 0.558431, 0.616437, 0.666237, 0.708757, 0.744847,
+	0.775285, 0.800780, 0.821974, 0.839450, 0.853732, 0.865290, 0.874544, 0.881865,
+	0.887582, 0.891980, 0.895310, 0.897785, 0.899589, 0.900874, 0.901768, 0.902375,
+	0.902779, 0.903044, 0.903219, 0.903341, 0.903433, 0.903511, 0.903583, 0.903655,
+	0.360929, 0.443760, 0.515785, 0.578132, 0.631845, 0.677881, 0.717121, 0.750371,
+	0.778366, 0.801773, 0.821199, 0.837189, 0.850235, 0.860775, 0.869201, 0.875857,
+	0.881047, 0.885036, 0.888054, 0.890297, 0.891932, 0.893100, 0.893916, 0.894475,
+	0.894852, 0.895105, 0.895279, 0.895404, 0.895504, 0.895591, 0.895673, 0.895755,
+	0.396478, 0.473216, 0.539819, 0.597365, 0.646847, 0.689176, 0.725185, 0.755637,
+	0.781225, 0.802577, 0.820261, 0.834788, 0.846617, 0.856155, 0.863765, 0.869766,
+	0.874438, 0.878024, 0.880735, 0.882749, 0.884219, 0.885271, 0.886011, 0.886522,
+	0.886873, 0.887114, 0.887285, 0.887415, 0.887522, 0.887618, 0.887710, 0.887801,
+	0.431312, 0.502047, 0.563309, 0.616126, 0.661441, 0.700118, 0.732946, 0.760643,
+	0.783861, 0.803189, 0.819159, 0.832246, 0.842876, 0.851428, 0.858235, 0.863592,
+	0.867754, 0.870944, 0.873352, 0.875142, 0.876449, 0.877388, 0.878052, 0.878516,
+	0.878841, 0.879070, 0.879240, 0.879373, 0.879487, 0.879592, 0.879693, 0.879794,
+	0.465423, 0.530245, 0.586248, 0.634409, 0.675622, 0.710705, 0.740401, 0.765387,
+	0.786272, 0.803609, 0.817892, 0.829562, 0.839014, 0.846595, 0.852613, 0.857335,
+	0.860996, 0.863796, 0.865907, 0.867475, 0.868622, 0.869449, 0.870039, 0.870457,
+	0.870756, 0.870974, 0.871141, 0.871278, 0.871399, 0.871513, 0.871623, 0.871733,
+	0.498804, 0.557806, 0.608633, 0.652211, 0.689388, 0.720933, 0.747548, 0.769866,
+	0.788457, 0.803835, 0.816458, 0.826735, 0.835028, 0.841655, 0.846896, 0.850995,
+	0.854163, 0.856579, 0.858398, 0.859749, 0.860739, 0.861456, 0.861973, 0.862345,
+	0.862618, 0.862825, 0.862990, 0.863131, 0.863258, 0.863380, 0.863500, 0.863619,
+	0.531447, 0.584723, 0.630457, 0.669527, 0.702733, 0.730800, 0.754384, 0.774079,
+	0.790414, 0.803866, 0.814858, 0.823765, 0.830918, 0.836607, 0.841085, 0.844572,
+	0.847255, 0.849295, 0.850826, 0.851963, 0.852798, 0.853408, 0.853852, 0.854180,
+	0.854428, 0.854624, 0.854787, 0.854931, 0.855065, 0.855195, 0.855323, 0.855451,
+	0.563346, 0.610990, 0.651716, 0.686353, 0.715654, 0.740301, 0.760907, 0.778022,
+	0.792141, 0.803700, 0.813088, 0.820649, 0.826683, 0.831451, 0.835180, 0.838064,
+	0.840272, 0.841941, 0.843191, 0.844117, 0.844801, 0.845304, 0.845678, 0.845962,
+	0.846186, 0.846370, 0.846530, 0.846678, 0.846818, 0.846956, 0.847093, 0.847229,
+	0.594492, 0.636600, 0.672403, 0.702683, 0.728148, 0.749434, 0.767113, 0.781695,
+	0.793635, 0.803335, 0.811149, 0.817388, 0.822323, 0.826186, 0.829179, 0.831473,
+	0.833213, 0.834519, 0.835492, 0.836212, 0.836746, 0.837146, 0.837450, 0.837691,
+	0.837890, 0.838063, 0.838222, 0.838372, 0.838519, 0.838664, 0.838809, 0.838954,
+	0.624880, 0.661548, 0.692514, 0.718514, 0.740210, 0.758195, 0.773000, 0.785095,
+	0.794896, 0.802771, 0.809040, 0.813981, 0.817836, 0.820811, 0.823082, 0.824797,
+	0.826078, 0.827028, 0.827729, 0.828247, 0.828635, 0.828932, 0.829169, 0.829367,
+	0.829542, 0.829704, 0.829861, 0.830014, 0.830167, 0.830319, 0.830472, 0.830625,
+	0.654502, 0.685827, 0.712044, 0.733842, 0.751837, 0.766583, 0.778566, 0.788219,
+	0.795922, 0.802006, 0.806758, 0.810426, 0.813223, 0.815327, 0.816890, 0.818036,
+	0.818867, 0.819468, 0.819902, 0.820222, 0.820466, 0.820663, 0.820833, 0.820990,
+	0.821141, 0.821293, 0.821447, 0.821603, 0.821761, 0.821921, 0.822081, 0.822242,
+	0.683350, 0.709432, 0.730987, 0.748660, 0.763026, 0.774592, 0.783808, 0.791066,
+	0.796711, 0.801038, 0.804303, 0.806723, 0.808482, 0.809733, 0.810601, 0.811189,
+	0.811580, 0.811838, 0.812012, 0.812137, 0.812241, 0.812339, 0.812444, 0.812559,
+	0.812688, 0.812829, 0.812980, 0.813139, 0.813303, 0.813470, 0.813637, 0.813805,
+	0.711417, 0.732355, 0.749338, 0.762967, 0.773772, 0.782221, 0.788723, 0.793634,
+	0.797261, 0.799866, 0.801674, 0.802871, 0.803613, 0.804027, 0.804215, 0.804258,
+	0.804217, 0.804139, 0.804057, 0.803992, 0.803958, 0.803960, 0.804000, 0.804076,
+	0.804182, 0.804312, 0.804461, 0.804623, 0.804792, 0.804965, 0.805140, 0.805315,
+	0.738697, 0.754591, 0.767091, 0.776755, 0.784071, 0.789465, 0.793309, 0.795919,
+	0.797570, 0.798489, 0.798870, 0.798869, 0.798615, 0.798210, 0.797732, 0.797240,
+	0.796777, 0.796371, 0.796038, 0.795787, 0.795618, 0.795526, 0.795503, 0.795539,
+	0.795623, 0.795743, 0.795890, 0.796054, 0.796228, 0.796408, 0.796589, 0.796772,
+	0.765182, 0.776133, 0.784242, 0.790023, 0.793920, 0.796323, 0.797563, 0.797921,
+	0.797636, 0.796905, 0.795888, 0.794715, 0.793487, 0.792281, 0.791151, 0.790137,
+	0.789260, 0.788533, 0.787955, 0.787522, 0.787221, 0.787036, 0.786952, 0.786950,
+	0.787012, 0.787122, 0.787266, 0.787432, 0.787611, 0.787797, 0.787985, 0.788174,
+	0.790864, 0.796976, 0.800785, 0.802763, 0.803316, 0.802790, 0.801482, 0.799637,
+	0.797459, 0.795113, 0.792730, 0.790410, 0.788229, 0.786239, 0.784472, 0.782947,
+	0.781666, 0.780624, 0.779808, 0.779196, 0.778766, 0.778492, 0.778347, 0.778307,
+	0.778347, 0.778447, 0.778589, 0.778757, 0.778941, 0.779133, 0.779328, 0.779523,
+	0.815737, 0.817113, 0.816715, 0.814973, 0.812253, 0.808864, 0.805064, 0.801064,
+	0.797035, 0.793111, 0.789392, 0.785952, 0.782840, 0.780084, 0.777695, 0.775670,
+	0.773995, 0.772646, 0.771596, 0.770810, 0.770254, 0.769891, 0.769688, 0.769611,
+	0.769630, 0.769721, 0.769859, 0.770030, 0.770218, 0.770416, 0.770617, 0.770819,
+	0.839794, 0.836538, 0.832027, 0.826648, 0.820729, 0.814541, 0.808306, 0.802201,
+	0.796364, 0.790898, 0.785874, 0.781340, 0.777319, 0.773815, 0.770818, 0.768306,
+	0.766246, 0.764598, 0.763319, 0.762364, 0.761684, 0.761236, 0.760975, 0.760862,
+	0.760861, 0.760941, 0.761078, 0.761250, 0.761442, 0.761645, 0.761852, 0.762061,
+	0.863027, 0.855245, 0.846715, 0.837784, 0.828740, 0.819819, 0.811206, 0.803046,
+	0.795443, 0.788472, 0.782175, 0.776574, 0.771665, 0.767432, 0.763843, 0.760855,
+	0.758419, 0.756479, 0.754978, 0.753856, 0.753057, 0.752525, 0.752208, 0.752060,
+	0.752038, 0.752109, 0.752243, 0.752417, 0.752614, 0.752822, 0.753035, 0.753249,
+	0.885429, 0.873227, 0.860773, 0.848375, 0.836282, 0.824693, 0.813761, 0.803595,
+	0.794271, 0.785832, 0.778294, 0.771651, 0.765878, 0.760934, 0.756767, 0.753316,
+	0.750514, 0.748289, 0.746571, 0.745289, 0.744373, 0.743759, 0.743387, 0.743204,
+	0.743163, 0.743225, 0.743356, 0.743531, 0.743732, 0.743945, 0.744164, 0.744383,
+	0.906992, 0.890479, 0.874198, 0.858417, 0.843351, 0.829162, 0.815968, 0.803848,
+	0.792846, 0.782977, 0.774230, 0.766573, 0.759957, 0.754321, 0.749592, 0.745689,
+	0.742530, 0.740029, 0.738100, 0.736660, 0.735631, 0.734937, 0.734512, 0.734295,
+	0.734236, 0.734288, 0.734416, 0.734593, 0.734798, 0.735016, 0.735239, 0.735464,
+	0.927710, 0.906995, 0.886983, 0.867907, 0.849943, 0.833221, 0.817825, 0.803802,
+	0.791167, 0.779905, 0.769981, 0.761337, 0.753902, 0.747592, 0.742315, 0.737974,
+	0.734469, 0.731698, 0.729564, 0.727971, 0.726831, 0.726059, 0.725583, 0.725334,
+	0.725255, 0.725298, 0.725424, 0.725602, 0.725810, 0.726033, 0.726261, 0.726491,
+	0.947576, 0.922767, 0.899123, 0.876839, 0.856056, 0.836868, 0.819329, 0.803455,
+	0.789230, 0.776615, 0.765546, 0.755943, 0.747711, 0.740746, 0.734938, 0.730171,
+	0.726328, 0.723296, 0.720962, 0.719221, 0.717973, 0.717127, 0.716599, 0.716319,
+	0.716222, 0.716256, 0.716379, 0.716559, 0.716770, 0.716997, 0.717230, 0.717464,
+	0.966582, 0.937791, 0.910613, 0.885208, 0.861684, 0.840099, 0.820477, 0.802804,
+	0.787036, 0.773105, 0.760924, 0.750389, 0.741384, 0.733784, 0.727459, 0.722278,
+	0.718109, 0.714822, 0.712295, 0.710410, 0.709058, 0.708138, 0.707562, 0.707250,
+	0.707136, 0.707161, 0.707282, 0.707462, 0.707676, 0.707907, 0.708145, 0.708384,
+	0.984721, 0.952059, 0.921448, 0.893012, 0.866824, 0.842912, 0.821268, 0.801847,
+	0.784581, 0.769374, 0.756114, 0.744675, 0.734920, 0.726703, 0.719878, 0.714296,
+	0.709810, 0.706278, 0.703563, 0.701538, 0.700085, 0.699094, 0.698470, 0.698129,
+	0.697997, 0.698014, 0.698132, 0.698313, 0.698530, 0.698765, 0.699007, 0.699250,
+	1.001986, 0.965566, 0.931622, 0.900244, 0.871472, 0.845303, 0.821697, 0.800583,
+	0.781864, 0.765420, 0.751115, 0.738801, 0.728319, 0.719505, 0.712195, 0.706225,
+	0.701432, 0.697661, 0.694765, 0.692605, 0.691054, 0.689995, 0.689325, 0.688954,
+	0.688805, 0.688814, 0.688929, 0.689111, 0.689331, 0.689569, 0.689815, 0.690063,
+];
+
+/// Albedo of the white principled subsurface approximation, indexed by [roughness][cos]
+#[rustfmt::skip]
+pub static PRINCIPLED_SUBSURFACE_ALBEDO: [f64; 1024] = [
+	0.742109, 0.906775, 1.023844, 1.105349, 1.159844, 1.193666, 1.211636, 1.217477,
+	1.214102, 1.203799, 1.188373, 1.169251, 1.147556, 1.124171, 1.099786, 1.074935,
+	1.050023, 1.025358, 1.001163, 0.977599, 0.954773, 0.932752, 0.911571, 0.891241,
+	0.871755, 0.853092, 0.835222, 0.818111, 0.801719, 0.786006, 0.770934, 0.756464,
+	0.763059, 0.923689, 1.037548, 1.116477, 1.168894, 1.201036, 1.217646, 1.222389,
+	1.218127, 1.207109, 1.191109, 1.171526, 1.149463, 1.125785, 1.101167, 1.076129,
+	1.051070, 1.026286, 1.001997, 0.978356, 0.955467, 0.933394, 0.912168, 0.891800,
+	0.872279, 0.853585, 0.835687, 0.818549, 0.802132, 0.786396, 0.771301, 0.756809,
+	0.784166, 0.940716, 1.051334, 1.127665, 1.177989, 1.208440, 1.223682, 1.227320,
+	1.222166, 1.210430, 1.193853, 1.173808, 1.151375, 1.127403, 1.102550, 1.077326,
+	1.052118, 1.027216, 1.002832, 0.979114, 0.956162, 0.934036, 0.912765, 0.892358,
+	0.872803, 0.854078, 0.836151, 0.818987, 0.802545, 0.786785, 0.771667, 0.757154,
+	0.805428, 0.957854, 1.065203, 1.138915, 1.187129, 1.215878, 1.229744, 1.232271,
+	1.226220, 1.213761, 1.196604, 1.176095, 1.153291, 1.129023, 1.103935, 1.078524,
+	1.053167, 1.028146, 1.003667, 0.979872, 0.956856, 0.934678, 0.913363, 0.892917,
+	0.873327, 0.854571, 0.836616, 0.819425, 0.802958, 0.787174, 0.772034, 0.757498,
+	0.826846, 0.975105, 1.079154, 1.150226, 1.196316, 1.223350, 1.235832, 1.237240,
+	1.230287, 1.217103, 1.199364, 1.178387, 1.155211, 1.130646, 1.105322, 1.079723,
+	1.054217, 1.029077, 1.004502, 0.980630, 0.957551, 0.935320, 0.913960, 0.893475,
+	0.873852, 0.855064, 0.837081, 0.819863, 0.803371, 0.787563, 0.772400, 0.757843,
+	0.848420, 0.992468, 1.093187, 1.161598, 1.205548, 1.230856, 1.241945, 1.242228,
+	1.234369, 1.220456, 1.202131, 1.180686, 1.157135, 1.132272, 1.106711, 1.080924,
+	1.055268, 1.030009, 1.005338, 0.981388, 0.958246, 0.935962, 0.914558, 0.894034,
+	0.874376, 0.855557, 0.837545, 0.820301, 0.803784, 0.787952, 0.772767, 0.758188,
+	0.870150, 1.009943, 1.107303, 1.173030, 1.214825, 1.238396, 1.248083, 1.247236,
+	1.238466, 1.223819, 1.204906, 1.182990, 1.159064, 1.133901, 1.108102, 1.082126,
+	1.056320, 1.030941, 1.006174, 0.982147, 0.958942, 0.936604, 0.915155, 0.894592,
+	0.874900, 0.856051, 0.838010, 0.820739, 0.804197, 0.788342, 0.773133, 0.758533,
+	0.892035, 1.027531, 1.121500, 1.184524, 1.224148, 1.245970, 1.254247, 1.252263,
+	1.242576, 1.227193, 1.207688, 1.185300, 1.160996, 1.135533, 1.109495, 1.083329,
+	1.057373, 1.031874, 1.007011, 0.982906, 0.959637, 0.937247, 0.915753, 0.895151,
+	0.875424, 0.856544, 0.838474, 0.821177, 0.804609, 0.788731, 0.773500, 0.758878,
+	0.914076, 1.045230, 1.135780, 1.196079, 1.233516, 1.253579, 1.260437, 1.257308,
+	1.246701, 1.230577, 1.210479, 1.187615, 1.162933, 1.137168, 1.110890, 1.084534,
+	1.058427, 1.032807, 1.007848, 0.983665, 0.960332, 0.937889, 0.916350, 0.895710,
+	0.875948, 0.857037, 0.838939, 0.821615, 0.805022, 0.789120, 0.773866, 0.759223,
+	0.936273, 1.063042, 1.150142, 1.207695, 1.242930, 1.261221, 1.266652, 1.262373,
+	1.250840, 1.233972, 1.213277, 1.189936, 1.164873, 1.138806, 1.112288, 1.085741,
+	1.059482, 1.033742, 1.008686, 0.984425, 0.961028, 0.938532, 0.916948, 0.896268,
+	0.876473, 0.857530, 0.839404, 0.822053, 0.805435, 0.789509, 0.774233, 0.759568,
+	0.958626, 1.080965, 1.164587, 1.219372, 1.252390, 1.268898, 1.272893, 1.267457,
+	1.254993, 1.237378, 1.216083, 1.192263, 1.166818, 1.140447, 1.113687, 1.086949,
+	1.060537, 1.034677, 1.009524, 0.985185, 0.961724, 0.939175, 0.917545, 0.896827,
+	0.876997, 0.858023, 0.839868, 0.822491, 0.805848, 0.789898, 0.774599, 0.759912,
+	0.981135, 1.099001, 1.179114, 1.231109, 1.261895, 1.276609, 1.279159, 1.272561,
+	1.259161, 1.240794, 1.218897, 1.194596, 1.168767, 1.142090, 1.115089, 1.088158,
+	1.061594, 1.035612, 1.010362, 0.985945, 0.962420, 0.939817, 0.918143, 0.897386,
+	0.877521, 0.858517, 0.840333, 0.822929, 0.806261, 0.790287, 0.774966, 0.760257,
+	1.003800, 1.117149, 1.193723, 1.242908, 1.271446, 1.284354, 1.285451, 1.277683,
+	1.263342, 1.244220, 1.221719, 1.196934, 1.170720, 1.143737, 1.116492, 1.089369,
+	1.062652, 1.036548, 1.011201, 0.986705, 0.963116, 0.940460, 0.918741, 0.897945,
+	0.878046, 0.859010, 0.840798, 0.823367, 0.806674, 0.790677, 0.775332, 0.760602,
+	1.026620, 1.135409, 1.208414, 1.254768, 1.281042, 1.292133, 1.291768, 1.282824,
+	1.267538, 1.247657, 1.224548, 1.199278, 1.172677, 1.145387, 1.117898, 1.090581,
+	1.063711, 1.037485, 1.012041, 0.987466, 0.963813, 0.941103, 0.919339, 0.898504,
+	0.878570, 0.859503, 0.841262, 0.823805, 0.807087, 0.791066, 0.775699, 0.760947,
+	1.049596, 1.153782, 1.223188, 1.266689, 1.290684, 1.299946, 1.298111, 1.287985,
+	1.271749, 1.251105, 1.227386, 1.201628, 1.174638, 1.147039, 1.119306, 1.091795,
+	1.064771, 1.038423, 1.012880, 0.988227, 0.964509, 0.941747, 0.919937, 0.899062,
+	0.879094, 0.859996, 0.841727, 0.824243, 0.807500, 0.791455, 0.776065, 0.761292,
+	1.072728, 1.172266, 1.238043, 1.278671, 1.300372, 1.307794, 1.304480, 1.293165,
+	1.275973, 1.254563, 1.230231, 1.203983, 1.176604, 1.148695, 1.120715, 1.093010,
+	1.065832, 1.039361, 1.013720, 0.988988, 0.965206, 0.942390, 0.920535, 0.899621,
+	0.879619, 0.860489, 0.842192, 0.824681, 0.807913, 0.791844, 0.776432, 0.761637,
+	1.096016, 1.190862, 1.252981, 1.290714, 1.310105, 1.315675, 1.310874, 1.298363,
+	1.280212, 1.258032, 1.233083, 1.206344, 1.178573, 1.150353, 1.122127, 1.094227,
+	1.066894, 1.040300, 1.014561, 0.989749, 0.965903, 0.943033, 0.921133, 0.900180,
+	0.880143, 0.860983, 0.842656, 0.825119, 0.808326, 0.792233, 0.776798, 0.761981,
+	1.119460, 1.209571, 1.268002, 1.302818, 1.319884, 1.323591, 1.317294, 1.303581,
+	1.284465, 1.261512, 1.235944, 1.208711, 1.180547, 1.152015, 1.123541, 1.095445,
+	1.067957, 1.041239, 1.015402, 0.990511, 0.966600, 0.943677, 0.921731, 0.900739,
+	0.880668, 0.861476, 0.843121, 0.825557, 0.808739, 0.792622, 0.777165, 0.762326,
+	1.143059, 1.228392, 1.283104, 1.314983, 1.329708, 1.331541, 1.323739, 1.308818,
+	1.288732, 1.265002, 1.238812, 1.211083, 1.182524, 1.153679, 1.124957, 1.096664,
+	1.069020, 1.042180, 1.016244, 0.991273, 0.967297, 0.944320, 0.922329, 0.901298,
+	0.881192, 0.861969, 0.843585, 0.825995, 0.809152, 0.793012, 0.777531, 0.762671,
+	1.166814, 1.247325, 1.298289, 1.327210, 1.339578, 1.339525, 1.330210, 1.314075,
+	1.293014, 1.268502, 1.241688, 1.213461, 1.184506, 1.155346, 1.126376, 1.097885,
+	1.070085, 1.043121, 1.017086, 0.992035, 0.967994, 0.944964, 0.922927, 0.901857,
+	0.881716, 0.862462, 0.844050, 0.826433, 0.809565, 0.793401, 0.777898, 0.763016,
+	1.190725, 1.266370, 1.313556, 1.339497, 1.349493, 1.347543, 1.336706, 1.319350,
+	1.297309, 1.272014, 1.244572, 1.215845, 1.186492, 1.157017, 1.127796, 1.099108,
+	1.071151, 1.044062, 1.017928, 0.992798, 0.968692, 0.945607, 0.923526, 0.902416,
+	0.882241, 0.862956, 0.844515, 0.826871, 0.809978, 0.793790, 0.778265, 0.763361,
+	1.214792, 1.285527, 1.328906, 1.351845, 1.359454, 1.355595, 1.343228, 1.324644,
+	1.301619, 1.275535, 1.247464, 1.218235, 1.188482, 1.158690, 1.129218, 1.100332,
+	1.072218, 1.045004, 1.018771, 0.993561, 0.969390, 0.946251, 0.924124, 0.902975,
+	0.882765, 0.863449, 0.844979, 0.827309, 0.810391, 0.794179, 0.778631, 0.763706,
+	1.239015, 1.304796, 1.344337, 1.364254, 1.369460, 1.363681, 1.349776, 1.329958,
+	1.305944, 1.279068, 1.250364, 1.220630, 1.190476, 1.160366, 1.130643, 1.101557,
+	1.073286, 1.045947, 1.019614, 0.994324, 0.970088, 0.946895, 0.924722, 0.903535,
+	0.883290, 0.863942, 0.845444, 0.827747, 0.810804, 0.794568, 0.778998, 0.764051,
+	1.263393, 1.324178, 1.359851, 1.376724, 1.379512, 1.371801, 1.356349, 1.335291,
+	1.310282, 1.282610, 1.253271, 1.223031, 1.192475, 1.162045, 1.132069, 1.102784,
+	1.074354, 1.046891, 1.020458, 0.995087, 0.970786, 0.947539, 0.925321, 0.904094,
+	0.883814, 0.864436, 0.845909, 0.828185, 0.811217, 0.794958, 0.779364, 0.764395,
+	1.287927, 1.343672, 1.375447, 1.389255, 1.389610, 1.379956, 1.362948, 1.340642,
+	1.314635, 1.286164, 1.256186, 1.225437, 1.194477, 1.163727, 1.133498, 1.104012,
+	1.075424, 1.047835, 1.021302, 0.995851, 0.971484, 0.948183, 0.925919, 0.904653,
+	0.884339, 0.864929, 0.846373, 0.828623, 0.811630, 0.795347, 0.779731, 0.764740,
+	1.312617, 1.363277, 1.391125, 1.401848, 1.399753, 1.388144, 1.369572, 1.346013,
+	1.319002, 1.289728, 1.259109, 1.227849, 1.196484, 1.165412, 1.134928, 1.105242,
+	1.076495, 1.048780, 1.022146, 0.996615, 0.972182, 0.948828, 0.926518, 0.905212,
+	0.884863, 0.865422, 0.846838, 0.829061, 0.812043, 0.795736, 0.780097, 0.765085,
+	1.337463, 1.382995, 1.406886, 1.414501, 1.409942, 1.396367, 1.376222, 1.351403,
+	1.323383, 1.293302, 1.262040, 1.230267, 1.198494, 1.167100, 1.136361, 1.106473,
+	1.077567, 1.049725, 1.022991, 0.997379, 0.972881, 0.949472, 0.927117, 0.905771,
+	0.885388, 0.865915, 0.847303, 0.829499, 0.812456, 0.796125, 0.780464, 0.765430,
+	1.362465, 1.402825, 1.422729, 1.427215, 1.420176, 1.404624, 1.382897, 1.356812,
+	1.327779, 1.296888, 1.264978, 1.232691, 1.200509, 1.168791, 1.137796, 1.107705,
+	1.078639, 1.050671, 1.023837, 0.998144, 0.973580, 0.950117, 0.927715, 0.906331,
+	0.885912, 0.866409, 0.847767, 0.829937, 0.812869, 0.796514, 0.780830, 0.765775,
+	1.387622, 1.422767, 1.438654, 1.439991, 1.430456, 1.412915, 1.389598, 1.362241,
+	1.332189, 1.300483, 1.267924, 1.235120, 1.202528, 1.170485, 1.139233, 1.108939,
+	1.079713, 1.051618, 1.024683, 0.998909, 0.974279, 0.950761, 0.928314, 0.906890,
+	0.886437, 0.866902, 0.848232, 0.830375, 0.813281, 0.796903, 0.781197, 0.766120,
+	1.412936, 1.442822, 1.454662, 1.452827, 1.440781, 1.421240, 1.396325, 1.367688,
+	1.336613, 1.304090, 1.270878, 1.237555, 1.204551, 1.172182, 1.140672, 1.110175,
+	1.080788, 1.052566, 1.025529, 0.999674, 0.974978, 0.951406, 0.928913, 0.907449,
+	0.886962, 0.867395, 0.848697, 0.830813, 0.813694, 0.797293, 0.781563, 0.766465,
+	1.438405, 1.462988, 1.470751, 1.465725, 1.451152, 1.429600, 1.403077, 1.373155,
+	1.341051, 1.307706, 1.273840, 1.239996, 1.206578, 1.173881, 1.142113, 1.111412,
+	1.081864, 1.053514, 1.026376, 1.000439, 0.975677, 0.952050, 0.929512, 0.908009,
+	0.887486, 0.867889, 0.849162, 0.831251, 0.814107, 0.797682, 0.781930, 0.766809,
+	1.464030, 1.483267, 1.486923, 1.478683, 1.461569, 1.437993, 1.409855, 1.378640,
+	1.345504, 1.311334, 1.276810, 1.242442, 1.208609, 1.175584, 1.143556, 1.112650,
+	1.082940, 1.054463, 1.027223, 1.001205, 0.976377, 0.952695, 0.930111, 0.908568,
+	0.888011, 0.868382, 0.849626, 0.831689, 0.814520, 0.798071, 0.782296, 0.767154,
+];
+
+/// Albedo of the principled sheen for a sheen color of one, indexed by [cos]
+#[rustfmt::skip]
+pub static PRINCIPLED_SHEEN_ALBEDO: [f64; 32] = [
+	0.084491, 0.077168, 0.070330, 0.063960, 0.058037, 0.052541, 0.047451, 0.042745,
+	0.038403, 0.034405, 0.030729, 0.027357, 0.024270, 0.021450, 0.018880, 0.016543,
+	0.014425, 0.012510, 0.010784, 0.009234, 0.007847, 0.006611, 0.005515, 0.004548,
+	0.003700, 0.002962, 0.002323, 0.001777, 0.001314, 0.000927, 0.000609, 0.000354,
+];
@@ -0,0 +1,304 @@
+use super::{
+	fresnel::Fresnel,
+	microfacet::Ggx,
+	multiple_scattering::{self, MultipleScattering},
+};
+use crate::{math::prelude::*, sampling};
+
+///
+/// A scattering function at a single shading point
+///
+/// All directions are in the local shading frame (see `shading::Frame`), normalized and
+/// pointing away from the surface. `wo` is the direction towards the viewer, `wi` the
+/// direction light arrives from. Neither is restricted to the upper hemisphere.
+///
+pub trait Bsdf {
+	/// The value of the BSDF, without the cosine term
+	fn eval(&self, wo: Vector3, wi: Vector3) -> Vector3;
+
+	/// Solid angle density with which `sample` generates `wi`
+	fn pdf(&self, wo: Vector3, wi: Vector3) -> f64;
+
+	/// Samples an incident direction
+	/// `u` drives the direction itself, `uc` the choice between lobes
+	fn sample(&self, wo: Vector3, u: Vector2, uc: f64) -> Option<BsdfSample>;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BsdfSample {
+	pub wi: Vector3,
+	/// The value of the BSDF for the sampled direction, without the cosine term
+	pub f: Vector3,
+	pub pdf: f64,
+}
+
+impl BsdfSample {
+	/// Builds a sample by evaluating the full BSDF in the sampled direction
+	/// Used by BSDFs with several lobes, so the pdf accounts for all the ways `wi` could have been generated
+	pub fn evaluate(bsdf: &(impl Bsdf + ?Sized), wo: Vector3, wi: Vector3) -> Option<BsdfSample> {
+		let pdf = bsdf.pdf(wo, wi);
+		if pdf.is_nan() || pdf <= 0.0 {
+			return None;
+		}
+
+		Some(BsdfSample {
+			wi,
+			f: bsdf.eval(wo, wi),
+			pdf,
+		})
+	}
+}
+
+pub fn cos_theta(w: Vector3) -> f64 {
+	w.y
+}
+
+pub fn abs_cos_theta(w: Vector3) -> f64 {
+	w.y.abs()
+}
+
+pub fn same_hemisphere(a: Vector3, b: Vector3) -> bool {
+	a.y * b.y > 0.0
+}
+
+/// Mirrors a direction into the upper hemisphere
+pub fn upper_hemisphere(w: Vector3) -> Vector3 {
+	Vector3::new(w.x, w.y.abs(), w.z)
+}
+
+/// Reflects w about n
+pub fn reflect(w: Vector3, n: Vector3) -> Vector3 {
+	-w + n * (2.0 * w.dot(n))
+}
+
+/// Refracts w through a surface with normal n, where n lies on the same side as w
+/// eta is the ratio of the index of refraction on the far side over the one on w's side
+pub fn refract(w: Vector3, n: Vector3, eta: f64) -> Option<Vector3> {
+	let cos_i = w.dot(n);
+	let sin2_t = (1.0 - cos_i * cos_i).max(0.0) / (eta * eta);
+	if sin2_t >= 1.0 {
+		return None;
+	}
+
+	let cos_t = (1.0 - sin2_t).sqrt();
+	Some(-w / eta + n * (cos_i / eta - cos_t))
+}
+
+/// Ideal diffuse reflection, on both sides of the surface
+#[derive(Debug, Clone, Copy)]
+pub struct Lambertian(pub Vector3);
+
+impl Bsdf for Lambertian {
+	fn eval(&self, wo: Vector3, wi: Vector3) -> Vector3 {
+		if !same_hemisphere(wo, wi) {
+			return Vector3::new(0.0, 0.0, 0.0);
+		}
+
+		self.0 / PI
+	}
+
+	fn pdf(&self, wo: Vector3, wi: Vector3) -> f64 {
+		if !same_hemisphere(wo, wi) {
+			return 0.0;
+		}
+
+		sampling::cosine_hemisphere_pdf(abs_cos_theta(wi))
+	}
+
+	fn sample(&self, wo: Vector3, u: Vector2, _uc: f64) -> Option<BsdfSample> {
+		let mut wi = sampling::cosine_sample_hemisphere(u);
+		if wo.y < 0.0 {
+			wi.y = -wi.y;
+		}
+
+		BsdfSample::evaluate(self, wo, wi)
+	}
+}
+
+/// Specular reflection off a rough surface, on both sides of the surface
+#[derive(Debug, Clone, Copy)]
+pub struct MicrofacetReflection {
+	pub distribution: Ggx,
+	pub fresnel: Fresnel,
+	multiple_scattering: Option<MultipleScattering>,
+}
+
+impl MicrofacetReflection {
+	/// Only light reflected by a single microfacet, rough surfaces lose energy
+	pub fn new(distribution: Ggx, fresnel: Fresnel) -> MicrofacetReflection {
+		MicrofacetReflection {
+			distribution,
+			fresnel,
+			multiple_scattering: None,
+		}
+	}
+
+	/// Adds back the energy of light scattered between microfacets, see `multiple_scattering`
+	pub fn compensated(distribution: Ggx, fresnel: Fresnel) -> MicrofacetReflection {
+		MicrofacetReflection {
+			distribution,
+			fresnel,
+			multiple_scattering: MultipleScattering::new(&distribution, &fresnel),
+		}
+	}
+
+	fn multiple_scattering_probability(&self, wo: Vector3) -> f64 {
+		self.multiple_scattering.map_or(0.0, |ms| ms.sample_probability(wo))
+	}
+
+	/// GGX alpha of the isotropic distribution the albedo tables are looked up with
+	fn table_alpha(&self) -> f64 {
+		(self.distribution.alpha_x * self.distribution.alpha_y).sqrt()
+	}
+
+	/// Reflectance at normal incidence, other Fresnel terms are approximated by Schlick's
+	fn f0(&self) -> Vector3 {
+		match self.fresnel {
+			Fresnel::Schlick(f0) => f0,
+			_ => self.fresnel.eval(1.0),
+		}
+	}
+
+	/// Directional albedo towards wo, so lobes below can receive the rest of the energy
+	pub fn albedo(&self, wo: Vector3) -> Vector3 {
+		let wo = upper_hemisphere(wo);
+		let single = multiple_scattering::schlick_albedo(wo.y, self.table_alpha(), self.f0());
+		single + self.multiple_scattering.map_or(Vector3::new(0.0, 0.0, 0.0), |ms| ms.albedo(wo))
+	}
+
+	/// Cosine weighted average of `albedo` over the hemisphere
+	pub fn average_albedo(&self) -> Vector3 {
+		let single = multiple_scattering::schlick_average_albedo(self.table_alpha(), self.f0());
+		single + self.multiple_scattering.map_or(Vector3::new(0.0, 0.0, 0.0), |ms| ms.average_albedo())
+	}
+
+	///
+	/// Weight of a diffuse base below this lobe, for directions in the upper hemisphere
+	///
+	/// The base receives the light neither direction reflects, (1 - E(wo)) (1 - E(wi)) / (1 - E_avg)
+	/// per channel, which is reciprocal and leaves a white Lambertian base exactly the energy
+	/// the lobe doesn't reflect.
+	///
+	pub fn base_weight(&self, wo: Vector3, wi: Vector3) -> Vector3 {
+		let (eo, ei, average) = (self.albedo(wo), self.albedo(wi), self.average_albedo());
+		let weight = |o: f64, i: f64, average: f64| {
+			if average < 1.0 {
+				((1.0 - o) * (1.0 - i) / (1.0 - average)).max(0.0)
+			} else {
+				0.0
+			}
+		};
+
+		Vector3::new(weight(eo.x, ei.x, average.x), weight(eo.y, ei.y, average.y), weight(eo.z, ei.z, average.z))
+	}
+}
+
+impl Bsdf for MicrofacetReflection {
+	fn eval(&self, wo: Vector3, wi: Vector3) -> Vector3 {
+		if !same_hemisphere(wo, wi) {
+			return Vector3::new(0.0, 0.0, 0.0);
+		}
+		let (wo, wi) = (upper_hemisphere(wo), upper_hemisphere(wi));
+		let multiple_scattering = match &self.multiple_scattering {
+			Some(ms) => ms.eval(wo, wi),
+			None => Vector3::new(0.0, 0.0, 0.0),
+		};
+		let wh = wo + wi;
+		if wh.magnitude2() == 0.0 {
+			return multiple_scattering;
+		}
+		let wh = wh.normalize();
+
+		let d = self.distribution.d(wh);
+		let g = self.distribution.g(wo, wi);
+		let f = self.fresnel.eval(wi.dot(wh));
+
+		f * (d * g / (4.0 * wo.y * wi.y)) + multiple_scattering
+	}
+
+	fn pdf(&self, wo: Vector3, wi: Vector3) -> f64 {
+		if !same_hemisphere(wo, wi) {
+			return 0.0;
+		}
+		let (wo, wi) = (upper_hemisphere(wo), upper_hemisphere(wi));
+		let p = self.multiple_scattering_probability(wo);
+		let multiple_scattering = p * sampling::cosine_hemisphere_pdf(wi.y);
+		let wh = wo + wi;
+		if wh.magnitude2() == 0.0 {
+			return multiple_scattering;
+		}
+		let wh = wh.normalize();
+
+		(1.0 - p) * self.distribution.pdf(wo, wh) / (4.0 * wo.dot(wh)) + multiple_scattering
+	}
+
+	fn sample(&self, wo: Vector3, u: Vector2, uc: f64) -> Option<BsdfSample> {
+		let mut wi = if uc < self.multiple_scattering_probability(upper_hemisphere(wo)) {
+			sampling::cosine_sample_hemisphere(u)
+		} else {
+			let wh = self.distribution.sample_wh(upper_hemisphere(wo), u);
+			reflect(upper_hemisphere(wo), wh)
+		};
+		if wi.y <= 0.0 {
+			return None;
+		}
+		if wo.y < 0.0 {
+			wi.y = -wi.y;
+		}
+
+		BsdfSample::evaluate(self, wo, wi)
+	}
+}
+
+/// Blends two BSDFs, a factor of 0 gives only `a`, 1 only `b`
+pub struct MixBsdf {
+	pub a: Box<dyn Bsdf>,
+	pub b: Box<dyn Bsdf>,
+	pub factor: f64,
+}
+
+impl Bsdf for MixBsdf {
+	fn eval(&self, wo: Vector3, wi: Vector3) -> Vector3 {
+		self.a.eval(wo, wi) * (1.0 - self.factor) + self.b.eval(wo, wi) * self.factor
+	}
+
+	fn pdf(&self, wo: Vector3, wi: Vector3) -> f64 {
+		self.a.pdf(wo, wi) * (1.0 - self.factor) + self.b.pdf(wo, wi) * self.factor
+	}
+
+	fn sample(&self, wo: Vector3, u: Vector2, uc: f64) -> Option<BsdfSample> {
+		let wi = if uc >= self.factor {
+			self.a.sample(wo, u, ((uc - self.factor) / (1.0 - self.factor)).min(1.0))?.wi
+		} else {
+			self.b.sample(wo, u, uc / self.factor)?.wi
+		};
+
+		BsdfSample::evaluate(self, wo, wi)
+	}
+}
+
+/// The sum of two BSDFs, each sampled half of the time
+pub struct AddBsdf {
+	pub a: Box<dyn Bsdf>,
+	pub b: Box<dyn Bsdf>,
+}
+
+impl Bsdf for AddBsdf {
+	fn eval(&self, wo: Vector3, wi: Vector3) -> Vector3 {
+		self.a.eval(wo, wi) + self.b.eval(wo, wi)
+	}
+
+	fn pdf(&self, wo: Vector3, wi: Vector3) -> f64 {
+		(self.a.pdf(wo, wi) + self.b.pdf(wo, wi)) / 2.0
+	}
+
+	fn sample(&self, wo: Vector3, u: Vector2, uc: f64) -> Option<BsdfSample> {
+		let wi = if uc < 0.5 {
+			self.a.sample(wo, u, uc * 2.0)?.wi
+		} else {
+			self.b.sample(wo, u, ((uc - 0.5) * 2.0).min(1.0))?.wi
+		};
+
+		BsdfSample::evaluate(self, wo, wi)
+	}
+}
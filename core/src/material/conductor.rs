@@ -0,0 +1,145 @@
+use serde::{Deserialize, Serialize};
+
+use super::{bsdf::MicrofacetReflection, fresnel::Fresnel, microfacet::Ggx, thin_film::ThinFilm};
+use crate::{geometry::SurfaceProperties, math::prelude::*};
+
+/// Wavelengths in nanometers at which spectral data is sampled for the red, green and blue channels
+const RGB_WAVELENGTHS: [f64; 3] = [650.0, 550.0, 450.0];
+
+///
+/// A metal described by its complex index of refraction, reflecting with the exact conductor
+/// Fresnel equations instead of Schlick's approximation
+///
+/// Roughness is perceptually linear, like the principled model's.
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Conductor {
+	pub ior: ComplexIor,
+	#[serde(default)]
+	pub roughness: f64,
+	#[serde(default)]
+	pub anisotropic: f64,
+	#[serde(default)]
+	pub anisotropic_rotation: f64,
+	#[serde(default)]
+	pub thin_film: Option<ThinFilm>,
+}
+
+impl Conductor {
+	pub fn bsdf(&self, surface: &SurfaceProperties) -> MicrofacetReflection {
+		let (eta, k) = self.ior.rgb();
+		let fresnel = match &self.thin_film {
+			Some(film) => Fresnel::ThinFilm {
+				thickness: film.thickness_at(surface),
+				ior: film.ior,
+				eta,
+				k,
+			},
+			None => Fresnel::Conductor { eta, k },
+		};
+
+		MicrofacetReflection::compensated(
+			Ggx::from_roughness_anisotropic(self.roughness, self.anisotropic, self.anisotropic_rotation * 2.0 * PI),
+			fresnel,
+		)
+	}
+}
+
+///
+/// The complex index of refraction n + ik of a conductor
+///
+/// In a project file this is either the name of a built in metal, e.g. `"Gold"`, an object
+/// with `n` and `k` per color channel, or measured spectral data with strictly increasing
+/// `wavelengths` in nanometers and matching lists `n` and `k`.
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ComplexIor {
+	Metal(Metal),
+	Rgb { n: Vector3, k: Vector3 },
+	Spectral {
+		#[serde(deserialize_with = "increasing")]
+		wavelengths: Vec<f64>,
+		n: Vec<f64>,
+		k: Vec<f64>,
+	},
+}
+
+/// Reads sample positions, rejecting repeated or unordered ones that would break the interpolation
+fn increasing<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Vec<f64>, D::Error> {
+	let values = Vec::<f64>::deserialize(deserializer)?;
+	match values.windows(2).find(|pair| pair[0] >= pair[1]) {
+		Some(pair) => Err(serde::de::Error::custom(format!(
+			"wavelengths have to be strictly increasing, {} is followed by {}",
+			pair[0], pair[1]
+		))),
+		None => Ok(values),
+	}
+}
+
+impl ComplexIor {
+	/// n and k per color channel
+	pub fn rgb(&self) -> (Vector3, Vector3) {
+		match self {
+			ComplexIor::Metal(metal) => metal.ior(),
+			ComplexIor::Rgb { n, k } => (*n, *k),
+			ComplexIor::Spectral { wavelengths, n, k } => {
+				let sample = |values: &[f64]| {
+					let [r, g, b] = RGB_WAVELENGTHS;
+					Vector3::new(
+						interpolate(wavelengths, values, r),
+						interpolate(wavelengths, values, g),
+						interpolate(wavelengths, values, b),
+					)
+				};
+				(sample(n), sample(k))
+			}
+		}
+	}
+}
+
+/// Linearly interpolates tabulated values, clamping outside the sampled range
+fn interpolate(xs: &[f64], ys: &[f64], x: f64) -> f64 {
+	let len = xs.len().min(ys.len());
+	if len == 0 {
+		return 0.0;
+	}
+	if x <= xs[0] {
+		return ys[0];
+	}
+
+	for i in 1..len {
+		if x <= xs[i] {
+			let t = (x - xs[i - 1]) / (xs[i] - xs[i - 1]);
+			return ys[i - 1] * (1.0 - t) + ys[i] * t;
+		}
+	}
+	ys[len - 1]
+}
+
+/// Common metals with measured indices of refraction
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum Metal {
+	Gold,
+	Silver,
+	Copper,
+	Aluminium,
+	Chrome,
+	Iron,
+}
+
+impl Metal {
+	/// n and k per color channel, sampled from measured spectral data
+	pub fn ior(&self) -> (Vector3, Vector3) {
+		let (n, k) = match self {
+			Metal::Gold => ([0.18299, 0.42108, 1.37340], [3.42420, 2.34590, 1.77040]),
+			Metal::Silver => ([0.15943, 0.14512, 0.13547], [3.92910, 3.19000, 2.38080]),
+			Metal::Copper => ([0.27105, 0.67693, 1.31640], [3.60920, 2.62480, 2.29210]),
+			Metal::Aluminium => ([1.34560, 0.96521, 0.61722], [7.47460, 6.39950, 5.30310]),
+			Metal::Chrome => ([3.10710, 3.18120, 2.32300], [3.33140, 3.32910, 3.13500]),
+			Metal::Iron => ([2.91140, 2.94970, 2.58450], [3.08930, 2.93180, 2.76700]),
+		};
+
+		(Vector3::new(n[0], n[1], n[2]), Vector3::new(k[0], k[1], k[2]))
+	}
+}
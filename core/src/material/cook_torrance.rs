@@ -0,0 +1,76 @@
+use super::{
+	bsdf::{same_hemisphere, upper_hemisphere, Bsdf, BsdfSample, MicrofacetReflection},
+	fresnel::Fresnel,
+	microfacet::Ggx,
+};
+use crate::{math::prelude::*, sampling};
+
+///
+/// The original shading model behind `Material::Diffuse` and `Material::Metal`
+///
+/// A Lambertian base, weighted by the light the specular lobe doesn't reflect, under a GGX
+/// specular lobe whose reflectance at normal incidence moves from 4% towards the base color
+/// as metalness increases. Roughness is used as the GGX alpha directly.
+///
+#[derive(Debug, Clone, Copy)]
+pub struct CookTorrance {
+	pub color: Vector3,
+	pub metalness: f64,
+	specular: MicrofacetReflection,
+}
+
+impl CookTorrance {
+	pub fn new(color: Vector3, roughness: f64, metalness: f64) -> CookTorrance {
+		let f0 = Vector3::new(0.04, 0.04, 0.04) * (1.0 - metalness) + color * metalness;
+
+		CookTorrance {
+			color,
+			metalness,
+			specular: MicrofacetReflection::compensated(Ggx::new(roughness), Fresnel::Schlick(f0)),
+		}
+	}
+
+	/// Probability of sampling the diffuse lobe
+	fn diffuse_probability(&self) -> f64 {
+		0.5 * (1.0 - self.metalness)
+	}
+}
+
+impl Bsdf for CookTorrance {
+	fn eval(&self, wo: Vector3, wi: Vector3) -> Vector3 {
+		if !same_hemisphere(wo, wi) {
+			return Vector3::new(0.0, 0.0, 0.0);
+		}
+
+		let specular = self.specular.eval(wo, wi);
+		let base_weight = self.specular.base_weight(upper_hemisphere(wo), upper_hemisphere(wi));
+		let diffuse = base_weight.mul_element_wise(self.color) * ((1.0 - self.metalness) / PI);
+
+		diffuse + specular
+	}
+
+	fn pdf(&self, wo: Vector3, wi: Vector3) -> f64 {
+		if !same_hemisphere(wo, wi) {
+			return 0.0;
+		}
+
+		let p = self.diffuse_probability();
+		p * sampling::cosine_hemisphere_pdf(wi.y.abs()) + (1.0 - p) * self.specular.pdf(wo, wi)
+	}
+
+	fn sample(&self, wo: Vector3, u: Vector2, uc: f64) -> Option<BsdfSample> {
+		let wi = if uc < self.diffuse_probability() {
+			let mut wi = sampling::cosine_sample_hemisphere(u);
+			if wo.y < 0.0 {
+				wi.y = -wi.y;
+			}
+			wi
+		} else {
+			let p = self.diffuse_probability();
+			self.specular.sample(wo, u, ((uc - p) / (1.0 - p)).min(1.0))?.wi
+		};
+
+		BsdfSample::evaluate(self, wo, wi)
+	}
+}
+
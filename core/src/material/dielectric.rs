@@ -0,0 +1,162 @@
+use super::{
+	bsdf::{refract, reflect, same_hemisphere, Bsdf, BsdfSample},
+	fresnel,
+	microfacet::Ggx,
+	multiple_scattering,
+};
+use crate::math::prelude::*;
+
+///
+/// Reflection and refraction through a rough dielectric interface
+/// Walter et al. 2007, "Microfacet Models for Refraction through Rough Surfaces"
+///
+/// eta is the index of refraction of the inside (below the normal) relative to the outside.
+/// Radiance is scaled by 1/eta^2 when crossing the interface, so the BSDF is only
+/// reciprocal up to that factor.
+///
+/// The compensated variant scales the BSDF by the inverse of its directional albedo towards
+/// wo, so light lost to scattering between microfacets is redistributed like single
+/// scattering (Turquin 2019, "Practical multiple scattering compensation for microfacet
+/// models"). This gives up reciprocity for reflection as well.
+///
+#[derive(Debug, Clone, Copy)]
+pub struct MicrofacetDielectric {
+	pub distribution: Ggx,
+	pub eta: f64,
+	/// Color filter applied to refracted light
+	pub tint: Vector3,
+	compensated: bool,
+}
+
+impl MicrofacetDielectric {
+	/// Only light scattered by a single microfacet, rough interfaces lose energy
+	pub fn new(distribution: Ggx, eta: f64, tint: Vector3) -> MicrofacetDielectric {
+		MicrofacetDielectric {
+			distribution,
+			eta,
+			tint,
+			compensated: false,
+		}
+	}
+
+	/// Adds back the energy of light scattered between microfacets
+	pub fn compensated(distribution: Ggx, eta: f64, tint: Vector3) -> MicrofacetDielectric {
+		MicrofacetDielectric {
+			distribution,
+			eta,
+			tint,
+			compensated: true,
+		}
+	}
+
+	/// Factor restoring the energy lost by single scattering towards wo
+	fn compensation(&self, wo: Vector3) -> f64 {
+		if !self.compensated {
+			return 1.0;
+		}
+
+		let alpha = (self.distribution.alpha_x * self.distribution.alpha_y).sqrt();
+		let eta = if wo.y > 0.0 { self.eta } else { 1.0 / self.eta };
+		let albedo = multiple_scattering::dielectric_albedo(wo.y, alpha, eta);
+		if albedo > 0.0 {
+			1.0 / albedo.min(1.0)
+		} else {
+			1.0
+		}
+	}
+
+	/// The relative index of refraction along a path from wo to wi, and the matching microfacet normal
+	/// The normal is always oriented towards +y
+	fn half_vector(&self, wo: Vector3, wi: Vector3) -> Option<(f64, Vector3)> {
+		if wo.y == 0.0 || wi.y == 0.0 {
+			return None;
+		}
+
+		let eta_path = if same_hemisphere(wo, wi) {
+			1.0
+		} else if wo.y > 0.0 {
+			self.eta
+		} else {
+			1.0 / self.eta
+		};
+
+		let wh = wi * eta_path + wo;
+		if wh.magnitude2() == 0.0 {
+			return None;
+		}
+		let wh = wh.normalize();
+		let wh = if wh.y < 0.0 { -wh } else { wh };
+
+		// Microfacets seen from behind don't contribute
+		if wh.dot(wi) * wi.y < 0.0 || wh.dot(wo) * wo.y < 0.0 {
+			return None;
+		}
+
+		Some((eta_path, wh))
+	}
+}
+
+impl Bsdf for MicrofacetDielectric {
+	fn eval(&self, wo: Vector3, wi: Vector3) -> Vector3 {
+		let (eta_path, wh) = match self.half_vector(wo, wi) {
+			Some(h) => h,
+			None => return Vector3::new(0.0, 0.0, 0.0),
+		};
+
+		let d = self.distribution.d(wh);
+		let g = self.distribution.g(wo, wi);
+		let f = fresnel::dielectric(wo.dot(wh), self.eta);
+
+		if same_hemisphere(wo, wi) {
+			let value = d * g * f / (4.0 * wo.y * wi.y).abs() * self.compensation(wo);
+			return Vector3::new(value, value, value);
+		}
+
+		let denominator = wi.dot(wh) + wo.dot(wh) / eta_path;
+		let denominator = denominator * denominator * wi.y * wo.y;
+		let value = d * (1.0 - f) * g * (wi.dot(wh) * wo.dot(wh) / denominator).abs() / (eta_path * eta_path);
+
+		self.tint * (value * self.compensation(wo))
+	}
+
+	fn pdf(&self, wo: Vector3, wi: Vector3) -> f64 {
+		let (eta_path, wh) = match self.half_vector(wo, wi) {
+			Some(h) => h,
+			None => return 0.0,
+		};
+
+		let f = fresnel::dielectric(wo.dot(wh), self.eta);
+		let pdf_wh = self.distribution.pdf(wo, wh);
+
+		if same_hemisphere(wo, wi) {
+			return pdf_wh / (4.0 * wo.dot(wh).abs()) * f;
+		}
+
+		let denominator = wi.dot(wh) + wo.dot(wh) / eta_path;
+		pdf_wh * wi.dot(wh).abs() / (denominator * denominator) * (1.0 - f)
+	}
+
+	fn sample(&self, wo: Vector3, u: Vector2, uc: f64) -> Option<BsdfSample> {
+		// The sampled normal lies on wo's side
+		let wh = self.distribution.sample_wh(wo, u);
+		let outward = if wh.y < 0.0 { -wh } else { wh };
+		let f = fresnel::dielectric(wo.dot(outward), self.eta);
+
+		let wi = if uc < f {
+			let wi = reflect(wo, wh);
+			if !same_hemisphere(wo, wi) {
+				return None;
+			}
+			wi
+		} else {
+			let eta = if wo.y > 0.0 { self.eta } else { 1.0 / self.eta };
+			let wi = refract(wo, wh, eta)?;
+			if same_hemisphere(wo, wi) || wi.y == 0.0 {
+				return None;
+			}
+			wi
+		};
+
+		BsdfSample::evaluate(self, wo, wi)
+	}
+}
@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{color, geometry::SurfaceProperties, math::prelude::*, texture::Texture};
+
+///
+/// A light emitting surface
+///
+/// In a project file:
+///
+/// ```json
+/// "Emission": {
+///     "color": { "Blackbody": 3200 },
+///     "strength": { "Power": 150 },
+///     "two_sided": false
+/// }
+/// ```
+///
+/// Emitters emit evenly in all directions (Lambertian) and don't scatter light.
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Emission {
+	#[serde(default)]
+	pub color: EmissionColor,
+	/// Multiplies the color, e.g. for screens or signs
+	/// Decoded from sRGB like other color textures, unless it's an HDR image or marked linear
+	#[serde(default)]
+	pub texture: Option<Texture>,
+	pub strength: EmissionStrength,
+	/// One-sided emitters only emit on the side their surface normal points to
+	#[serde(default)]
+	pub two_sided: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum EmissionColor {
+	/// A linear RGB color, scaled by the strength as is
+	Rgb(Vector3),
+	/// The color of a black body at the given temperature in kelvin, normalized to a luminance of one
+	Blackbody(Blackbody),
+}
+
+///
+/// A black body temperature along with its color, which is integrated over the spectrum once
+///
+/// In the project file it's just the temperature in kelvin.
+///
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(from = "f64", into = "f64")]
+pub struct Blackbody {
+	temperature: f64,
+	rgb: Vector3,
+}
+
+impl Blackbody {
+	pub fn new(temperature: f64) -> Blackbody {
+		Blackbody {
+			temperature,
+			rgb: color::blackbody(temperature),
+		}
+	}
+
+	pub fn temperature(&self) -> f64 {
+		self.temperature
+	}
+}
+
+impl From<f64> for Blackbody {
+	fn from(temperature: f64) -> Blackbody {
+		Blackbody::new(temperature)
+	}
+}
+
+impl From<Blackbody> for f64 {
+	fn from(blackbody: Blackbody) -> f64 {
+		blackbody.temperature
+	}
+}
+
+impl Default for EmissionColor {
+	fn default() -> Self {
+		EmissionColor::Rgb(Vector3::new(1.0, 1.0, 1.0))
+	}
+}
+
+impl EmissionColor {
+	pub fn rgb(&self) -> Vector3 {
+		match self {
+			EmissionColor::Rgb(color) => *color,
+			EmissionColor::Blackbody(blackbody) => blackbody.rgb,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum EmissionStrength {
+	/// Radiance leaving every point of the surface, independent of the emitter's size
+	Radiance(f64),
+	/// Total power leaving the emitter, spread evenly over its surface
+	/// Resizing the emitter keeps the amount of light in the scene the same. Planes are infinite,
+	/// so projects with emissive planes have to use radiance.
+	Power(f64),
+}
+
+impl Emission {
+	///
+	/// Radiance leaving the surface towards a viewer
+	///
+	/// `cos_theta` is the cosine between the geometric normal and the direction towards the
+	/// viewer, `area` the surface area of the whole emitter.
+	///
+	pub fn radiance(&self, surface: &SurfaceProperties, cos_theta: f64, area: f64) -> Vector3 {
+		if !self.two_sided && cos_theta <= 0.0 {
+			return Vector3::new(0.0, 0.0, 0.0);
+		}
+
+		let color = match &self.texture {
+			Some(texture) => self.color.rgb().mul_element_wise(texture.sample(surface.uv)),
+			None => self.color.rgb(),
+		};
+
+		color * self.scale(area)
+	}
+
+	/// Radiance leaving the front of the surface with the texture taken at its average
+	pub fn average_radiance(&self, area: f64) -> Vector3 {
+		let color = match &self.texture {
+			Some(texture) => self.color.rgb().mul_element_wise(texture.average()),
+			None => self.color.rgb(),
+		};
+
+		color * self.scale(area)
+	}
+
+	fn scale(&self, area: f64) -> f64 {
+		match self.strength {
+			EmissionStrength::Radiance(radiance) => radiance,
+			EmissionStrength::Power(power) => {
+				let sides = if self.two_sided { 2.0 } else { 1.0 };
+				power / (PI * area * sides)
+			}
+		}
+	}
+}
@@ -0,0 +1,102 @@
+use super::thin_film;
+use crate::math::prelude::*;
+
+/// (1 - cos)^5, the interpolation weight of Schlick's approximation
+pub fn schlick_weight(cos_theta: f64) -> f64 {
+	let m = (1.0 - cos_theta).clamp(0.0, 1.0);
+	let m2 = m * m;
+	m2 * m2 * m
+}
+
+pub fn schlick(cos_theta: f64, f0: Vector3) -> Vector3 {
+	f0 + (Vector3::new(1.0, 1.0, 1.0) - f0) * schlick_weight(cos_theta)
+}
+
+///
+/// Unpolarized Fresnel reflectance of a dielectric interface
+///
+/// eta is the relative index of refraction of the side opposite of the normal,
+/// a negative cosine means the light arrives from that side.
+///
+pub fn dielectric(cos_theta_i: f64, eta: f64) -> f64 {
+	let mut cos_i = cos_theta_i.clamp(-1.0, 1.0);
+	let mut eta = eta;
+	if cos_i < 0.0 {
+		eta = 1.0 / eta;
+		cos_i = -cos_i;
+	}
+
+	let sin2_t = (1.0 - cos_i * cos_i) / (eta * eta);
+	if sin2_t >= 1.0 {
+		return 1.0;
+	}
+	let cos_t = (1.0 - sin2_t).max(0.0).sqrt();
+
+	let r_parallel = (eta * cos_i - cos_t) / (eta * cos_i + cos_t);
+	let r_perpendicular = (cos_i - eta * cos_t) / (cos_i + eta * cos_t);
+	(r_parallel * r_parallel + r_perpendicular * r_perpendicular) / 2.0
+}
+
+///
+/// Unpolarized Fresnel reflectance of a conductor with the complex index of refraction eta + ik,
+/// relative to the outside medium. Evaluated per channel.
+///
+pub fn conductor(cos_theta_i: f64, eta: Vector3, k: Vector3) -> Vector3 {
+	let cos_i = cos_theta_i.abs().min(1.0);
+	Vector3::new(
+		conductor_channel(cos_i, eta.x, k.x),
+		conductor_channel(cos_i, eta.y, k.y),
+		conductor_channel(cos_i, eta.z, k.z),
+	)
+}
+
+fn conductor_channel(cos_i: f64, eta: f64, k: f64) -> f64 {
+	let cos2 = cos_i * cos_i;
+	let sin2 = 1.0 - cos2;
+	let eta2 = eta * eta;
+	let k2 = k * k;
+
+	let t0 = eta2 - k2 - sin2;
+	let a2_plus_b2 = (t0 * t0 + 4.0 * eta2 * k2).sqrt();
+	let t1 = a2_plus_b2 + cos2;
+	let a = (0.5 * (a2_plus_b2 + t0)).max(0.0).sqrt();
+	let t2 = 2.0 * cos_i * a;
+	let r_perpendicular = (t1 - t2) / (t1 + t2);
+
+	let t3 = cos2 * a2_plus_b2 + sin2 * sin2;
+	let t4 = t2 * sin2;
+	let r_parallel = r_perpendicular * (t3 - t4) / (t3 + t4);
+
+	(r_parallel + r_perpendicular) / 2.0
+}
+
+/// The real index of refraction of a dielectric reflecting f0 at normal incidence
+pub fn f0_to_ior(f0: f64) -> f64 {
+	let r = f0.clamp(0.0, 0.999).sqrt();
+	(1.0 + r) / (1.0 - r)
+}
+
+/// The Fresnel term of a specular lobe
+#[derive(Debug, Clone, Copy)]
+pub enum Fresnel {
+	Schlick(Vector3),
+	Dielectric(f64),
+	Conductor { eta: Vector3, k: Vector3 },
+	/// A film of the given thickness in nanometers and index of refraction over a base with
+	/// the complex index of refraction eta + ik
+	ThinFilm { thickness: f64, ior: f64, eta: Vector3, k: Vector3 },
+}
+
+impl Fresnel {
+	pub fn eval(&self, cos_theta: f64) -> Vector3 {
+		match self {
+			Fresnel::Schlick(f0) => schlick(cos_theta.abs(), *f0),
+			Fresnel::Dielectric(eta) => {
+				let f = dielectric(cos_theta, *eta);
+				Vector3::new(f, f, f)
+			}
+			Fresnel::Conductor { eta, k } => conductor(cos_theta, *eta, *k),
+			Fresnel::ThinFilm { thickness, ior, eta, k } => thin_film::reflectance(cos_theta, *thickness, *ior, *eta, *k),
+		}
+	}
+}
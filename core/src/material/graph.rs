@@ -0,0 +1,490 @@
+use std::{collections::HashMap, error, fmt, sync::Arc};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{
+	bsdf::{AddBsdf, Bsdf, Lambertian, MixBsdf},
+	conductor::{ComplexIor, Conductor},
+	dielectric::MicrofacetDielectric,
+	microfacet::Ggx,
+	principled::Principled,
+};
+use crate::{
+	geometry::SurfaceProperties,
+	math::{luminance, prelude::*},
+	texture::Texture,
+};
+
+///
+/// A material described as a graph of nodes in a project file
+///
+/// Value nodes compute colors and numbers from textures and math, shader nodes turn them into
+/// BSDFs, and the shader nodes can be combined with `MixShader` and `AddShader`. Nodes refer
+/// to each other by name, `output` names the shader node the material uses.
+///
+/// ```json
+/// "material": {
+///     "nodes": {
+///         "wood": { "Texture": { "texture": "assets/textures/wood.png", "scale": 4.0 } },
+///         "wood_roughness": {
+///             "Texture": {
+///                 "texture": { "path": "assets/textures/wood_roughness.png", "color_space": "Linear" },
+///                 "scale": 4.0
+///             }
+///         },
+///         "grain": { "Math": { "op": "Multiply", "a": "wood_roughness", "b": 0.8 } },
+///         "base": { "Principled": { "base_color": "wood", "roughness": "grain" } },
+///         "varnish": { "Glass": { "ior": 1.5, "roughness": 0.05 } },
+///         "out": { "MixShader": { "a": "base", "b": "varnish", "factor": 0.2 } }
+///     },
+///     "output": "out"
+/// }
+/// ```
+///
+/// Inputs of value nodes are either the name of another value node, a number or an
+/// `[r, g, b]` color.
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphDescription {
+	pub nodes: HashMap<String, Node>,
+	pub output: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Input {
+	Scalar(f64),
+	Color([f64; 3]),
+	Node(String),
+}
+
+impl Default for Input {
+	fn default() -> Self {
+		Input::Scalar(0.0)
+	}
+}
+
+fn default_scale() -> f64 {
+	1.0
+}
+
+fn default_ior() -> f64 {
+	1.5
+}
+
+fn default_tint() -> Input {
+	Input::Scalar(1.0)
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum MathOp {
+	Add,
+	Subtract,
+	Multiply,
+	Divide,
+	Power,
+	Minimum,
+	Maximum,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Node {
+	/// Samples a texture, with the uv's multiplied by scale
+	/// The texture is read as a color, maps holding data like roughness have to be marked linear
+	Texture {
+		texture: Texture,
+		#[serde(default = "default_scale")]
+		scale: f64,
+	},
+	/// The surface's uv coordinates in the red and green channels
+	Uv,
+	/// Applies an operation per channel
+	Math { op: MathOp, a: Input, b: Input },
+	/// Blends two values, a factor of 0 gives a and 1 gives b
+	Mix { a: Input, b: Input, factor: Input },
+
+	Diffuse { color: Input },
+	Principled(Box<PrincipledNode>),
+	Conductor {
+		ior: ComplexIor,
+		#[serde(default)]
+		roughness: Input,
+	},
+	Glass {
+		#[serde(default = "default_ior")]
+		ior: f64,
+		#[serde(default)]
+		roughness: Input,
+		#[serde(default = "default_tint")]
+		tint: Input,
+	},
+	/// Blends two shaders, a factor of 0 gives a and 1 gives b
+	MixShader { a: String, b: String, factor: Input },
+	/// Sums two shaders, unlike mixing this can reflect more light than arrives
+	AddShader { a: String, b: String },
+}
+
+///
+/// The inputs of the principled shader node, all optional
+/// Missing inputs take the defaults of `Principled`
+///
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PrincipledNode<I = Input> {
+	pub base_color: Option<I>,
+	pub metallic: Option<I>,
+	pub roughness: Option<I>,
+	pub anisotropic: Option<I>,
+	pub anisotropic_rotation: Option<I>,
+	pub specular: Option<I>,
+	pub specular_tint: Option<I>,
+	pub sheen: Option<I>,
+	pub sheen_tint: Option<I>,
+	pub clearcoat: Option<I>,
+	pub clearcoat_roughness: Option<I>,
+	pub ior: Option<I>,
+	pub transmission: Option<I>,
+	pub subsurface: Option<I>,
+}
+
+impl<I> PrincipledNode<I> {
+	fn try_map<'s, J, E>(&'s self, mut f: impl FnMut(&'s I) -> Result<J, E>) -> Result<PrincipledNode<J>, E> {
+		let mut map = |input: &'s Option<I>| input.as_ref().map(&mut f).transpose();
+
+		Ok(PrincipledNode {
+			base_color: map(&self.base_color)?,
+			metallic: map(&self.metallic)?,
+			roughness: map(&self.roughness)?,
+			anisotropic: map(&self.anisotropic)?,
+			anisotropic_rotation: map(&self.anisotropic_rotation)?,
+			specular: map(&self.specular)?,
+			specular_tint: map(&self.specular_tint)?,
+			sheen: map(&self.sheen)?,
+			sheen_tint: map(&self.sheen_tint)?,
+			clearcoat: map(&self.clearcoat)?,
+			clearcoat_roughness: map(&self.clearcoat_roughness)?,
+			ior: map(&self.ior)?,
+			transmission: map(&self.transmission)?,
+			subsurface: map(&self.subsurface)?,
+		})
+	}
+}
+
+#[derive(Debug)]
+pub enum GraphError {
+	UnknownNode(String),
+	/// The node depends on itself
+	Cycle(String),
+	/// A shader node was used as a value or the other way around
+	TypeMismatch { node: String, expected: &'static str },
+}
+
+impl fmt::Display for GraphError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			GraphError::UnknownNode(node) => write!(f, "material graph references unknown node '{}'", node),
+			GraphError::Cycle(node) => write!(f, "material graph node '{}' depends on itself", node),
+			GraphError::TypeMismatch { node, expected } => {
+				write!(f, "material graph node '{}' is used where a {} is expected", node, expected)
+			}
+		}
+	}
+}
+
+impl error::Error for GraphError {}
+
+/// A value input after compilation, either a constant or the index of the node computing it
+#[derive(Debug, Clone, Copy)]
+enum Slot {
+	Constant(Vector3),
+	Node(usize),
+}
+
+#[derive(Debug, Clone)]
+enum CompiledNode {
+	Texture { texture: Texture, scale: f64 },
+	Uv,
+	Math { op: MathOp, a: Slot, b: Slot },
+	Mix { a: Slot, b: Slot, factor: Slot },
+	Diffuse { color: Slot },
+	Principled(Box<PrincipledNode<Slot>>),
+	Conductor { ior: ComplexIor, roughness: Slot },
+	Glass { ior: f64, roughness: Slot, tint: Slot },
+	MixShader { a: usize, b: usize, factor: Slot },
+	AddShader { a: usize, b: usize },
+}
+
+impl CompiledNode {
+	fn is_shader(&self) -> bool {
+		!matches!(
+			self,
+			CompiledNode::Texture { .. } | CompiledNode::Uv | CompiledNode::Math { .. } | CompiledNode::Mix { .. }
+		)
+	}
+}
+
+///
+/// A material graph compiled into a list of nodes, ordered so that every node comes after
+/// its inputs. Nodes that don't contribute to the output are dropped.
+///
+#[derive(Debug, Clone)]
+pub struct MaterialGraph {
+	nodes: Vec<CompiledNode>,
+	output: usize,
+	/// Kept to write the graph back to a project file
+	description: GraphDescription,
+}
+
+///
+/// Serde support for compiled graphs, which are written as their description and compiled
+/// when they're read
+///
+pub mod shared {
+	use super::*;
+
+	pub fn serialize<S: Serializer>(graph: &Arc<MaterialGraph>, serializer: S) -> Result<S::Ok, S::Error> {
+		graph.description.serialize(serializer)
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Arc<MaterialGraph>, D::Error> {
+		let description = GraphDescription::deserialize(deserializer)?;
+		MaterialGraph::compile(&description).map(Arc::new).map_err(serde::de::Error::custom)
+	}
+}
+
+impl MaterialGraph {
+	pub fn compile(description: &GraphDescription) -> Result<MaterialGraph, GraphError> {
+		let mut compiler = Compiler {
+			description,
+			indices: HashMap::new(),
+			nodes: Vec::new(),
+		};
+		let output = compiler.shader(&description.output)?;
+
+		Ok(MaterialGraph {
+			nodes: compiler.nodes,
+			output,
+			description: description.clone(),
+		})
+	}
+
+	/// Whether the graph produces the same BSDF everywhere, it has no texture or UV inputs
+	pub fn is_uniform(&self) -> bool {
+		!self
+			.nodes
+			.iter()
+			.any(|node| matches!(node, CompiledNode::Texture { .. } | CompiledNode::Uv))
+	}
+
+	pub fn bsdf(&self, surface: &SurfaceProperties) -> Option<Box<dyn Bsdf>> {
+		let mut values = vec![Vector3::new(0.0, 0.0, 0.0); self.nodes.len()];
+		for (i, node) in self.nodes.iter().enumerate() {
+			let value = |slot: &Slot| match slot {
+				Slot::Constant(value) => *value,
+				Slot::Node(index) => values[*index],
+			};
+
+			let result = match node {
+				CompiledNode::Texture { texture, scale } => texture.sample(surface.uv * *scale),
+				CompiledNode::Uv => Vector3::new(surface.uv.x, surface.uv.y, 0.0),
+				CompiledNode::Math { op, a, b } => math(*op, value(a), value(b)),
+				CompiledNode::Mix { a, b, factor } => {
+					let factor = value(factor);
+					value(a).mul_element_wise(Vector3::new(1.0, 1.0, 1.0) - factor) + value(b).mul_element_wise(factor)
+				}
+				_ => continue,
+			};
+			values[i] = result;
+		}
+
+		Some(self.shader(self.output, &values, surface))
+	}
+
+	fn shader(&self, index: usize, values: &[Vector3], surface: &SurfaceProperties) -> Box<dyn Bsdf> {
+		let value = |slot: &Slot| match slot {
+			Slot::Constant(value) => *value,
+			Slot::Node(index) => values[*index],
+		};
+		let scalar = |slot: &Slot| luminance(value(slot));
+
+		match &self.nodes[index] {
+			CompiledNode::Diffuse { color } => Box::new(Lambertian(value(color))),
+			CompiledNode::Principled(inputs) => {
+				let mut principled = Principled::default();
+				let set = |field: &mut f64, input: &Option<Slot>| {
+					if let Some(input) = input {
+						*field = scalar(input);
+					}
+				};
+				set(&mut principled.metallic, &inputs.metallic);
+				set(&mut principled.roughness, &inputs.roughness);
+				set(&mut principled.anisotropic, &inputs.anisotropic);
+				set(&mut principled.anisotropic_rotation, &inputs.anisotropic_rotation);
+				set(&mut principled.specular, &inputs.specular);
+				set(&mut principled.specular_tint, &inputs.specular_tint);
+				set(&mut principled.sheen, &inputs.sheen);
+				set(&mut principled.sheen_tint, &inputs.sheen_tint);
+				set(&mut principled.clearcoat, &inputs.clearcoat);
+				set(&mut principled.clearcoat_roughness, &inputs.clearcoat_roughness);
+				set(&mut principled.ior, &inputs.ior);
+				set(&mut principled.transmission, &inputs.transmission);
+				set(&mut principled.subsurface, &inputs.subsurface);
+				if let Some(base_color) = &inputs.base_color {
+					principled.base_color = value(base_color);
+				}
+
+				Box::new(principled.bsdf(surface))
+			}
+			CompiledNode::Conductor { ior, roughness } => Box::new(
+				Conductor {
+					ior: ior.clone(),
+					roughness: scalar(roughness),
+					anisotropic: 0.0,
+					anisotropic_rotation: 0.0,
+					thin_film: None,
+				}
+				.bsdf(surface),
+			),
+			CompiledNode::Glass { ior, roughness, tint } => Box::new(MicrofacetDielectric::compensated(
+				Ggx::from_roughness(scalar(roughness)),
+				*ior,
+				value(tint),
+			)),
+			CompiledNode::MixShader { a, b, factor } => {
+				let factor = scalar(factor).clamp(0.0, 1.0);
+				if factor == 0.0 {
+					return self.shader(*a, values, surface);
+				}
+				if factor == 1.0 {
+					return self.shader(*b, values, surface);
+				}
+
+				Box::new(MixBsdf {
+					a: self.shader(*a, values, surface),
+					b: self.shader(*b, values, surface),
+					factor,
+				})
+			}
+			CompiledNode::AddShader { a, b } => Box::new(AddBsdf {
+				a: self.shader(*a, values, surface),
+				b: self.shader(*b, values, surface),
+			}),
+			_ => unreachable!("value nodes are rejected as shaders during compilation"),
+		}
+	}
+}
+
+fn math(op: MathOp, a: Vector3, b: Vector3) -> Vector3 {
+	let apply = |f: fn(f64, f64) -> f64| Vector3::new(f(a.x, b.x), f(a.y, b.y), f(a.z, b.z));
+
+	match op {
+		MathOp::Add => a + b,
+		MathOp::Subtract => a - b,
+		MathOp::Multiply => a.mul_element_wise(b),
+		MathOp::Divide => apply(|a, b| if b != 0.0 { a / b } else { 0.0 }),
+		MathOp::Power => apply(f64::powf),
+		MathOp::Minimum => apply(f64::min),
+		MathOp::Maximum => apply(f64::max),
+	}
+}
+
+/// Index of a node in the compiled graph, or a marker that it is still being compiled
+#[derive(Clone, Copy)]
+enum State {
+	Visiting,
+	Done(usize),
+}
+
+struct Compiler<'a> {
+	description: &'a GraphDescription,
+	indices: HashMap<&'a str, State>,
+	nodes: Vec<CompiledNode>,
+}
+
+impl<'a> Compiler<'a> {
+	/// Compiles a node after all of its inputs and returns its index
+	fn node(&mut self, name: &'a str) -> Result<usize, GraphError> {
+		match self.indices.get(name) {
+			Some(State::Done(index)) => return Ok(*index),
+			Some(State::Visiting) => return Err(GraphError::Cycle(name.to_string())),
+			None => (),
+		}
+		let (name, node) = self
+			.description
+			.nodes
+			.get_key_value(name)
+			.ok_or_else(|| GraphError::UnknownNode(name.to_string()))?;
+		self.indices.insert(name, State::Visiting);
+
+		let compiled = match node {
+			Node::Texture { texture, scale } => CompiledNode::Texture {
+				texture: texture.clone(),
+				scale: *scale,
+			},
+			Node::Uv => CompiledNode::Uv,
+			Node::Math { op, a, b } => CompiledNode::Math {
+				op: *op,
+				a: self.value(a)?,
+				b: self.value(b)?,
+			},
+			Node::Mix { a, b, factor } => CompiledNode::Mix {
+				a: self.value(a)?,
+				b: self.value(b)?,
+				factor: self.value(factor)?,
+			},
+			Node::Diffuse { color } => CompiledNode::Diffuse { color: self.value(color)? },
+			Node::Principled(inputs) => CompiledNode::Principled(Box::new(inputs.try_map(|input| self.value(input))?)),
+			Node::Conductor { ior, roughness } => CompiledNode::Conductor {
+				ior: ior.clone(),
+				roughness: self.value(roughness)?,
+			},
+			Node::Glass { ior, roughness, tint } => CompiledNode::Glass {
+				ior: *ior,
+				roughness: self.value(roughness)?,
+				tint: self.value(tint)?,
+			},
+			Node::MixShader { a, b, factor } => CompiledNode::MixShader {
+				a: self.shader(a)?,
+				b: self.shader(b)?,
+				factor: self.value(factor)?,
+			},
+			Node::AddShader { a, b } => CompiledNode::AddShader {
+				a: self.shader(a)?,
+				b: self.shader(b)?,
+			},
+		};
+
+		let index = self.nodes.len();
+		self.nodes.push(compiled);
+		self.indices.insert(name, State::Done(index));
+		Ok(index)
+	}
+
+	fn value(&mut self, input: &'a Input) -> Result<Slot, GraphError> {
+		match input {
+			Input::Scalar(x) => Ok(Slot::Constant(Vector3::new(*x, *x, *x))),
+			Input::Color([r, g, b]) => Ok(Slot::Constant(Vector3::new(*r, *g, *b))),
+			Input::Node(name) => {
+				let index = self.node(name)?;
+				if self.nodes[index].is_shader() {
+					return Err(GraphError::TypeMismatch {
+						node: name.clone(),
+						expected: "value",
+					});
+				}
+				Ok(Slot::Node(index))
+			}
+		}
+	}
+
+	fn shader(&mut self, name: &'a str) -> Result<usize, GraphError> {
+		let index = self.node(name)?;
+		if !self.nodes[index].is_shader() {
+			return Err(GraphError::TypeMismatch {
+				node: name.to_string(),
+				expected: "shader",
+			});
+		}
+		Ok(index)
+	}
+}
@@ -0,0 +1,256 @@
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+	bsdf::{refract, same_hemisphere, upper_hemisphere, Bsdf, BsdfSample, MicrofacetReflection},
+	fresnel::{self, Fresnel},
+	microfacet::Ggx,
+};
+use crate::{
+	math::{luminance, prelude::*},
+	sampling,
+};
+
+/// Number of samples used to estimate the albedo of the base
+const BASE_ALBEDO_SAMPLES: usize = 64;
+
+fn default_ior() -> f64 {
+	1.5
+}
+
+fn default_tint() -> Vector3 {
+	Vector3::new(1.0, 1.0, 1.0)
+}
+
+///
+/// A dielectric coating, like clear lacquer or the top coat of car paint
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Coating {
+	#[serde(default = "default_ior")]
+	pub ior: f64,
+	#[serde(default)]
+	pub roughness: f64,
+	/// Color of the base seen through the coating at normal incidence
+	/// Grazing paths travel further through the coating and get tinted more
+	#[serde(default = "default_tint")]
+	pub tint: Vector3,
+	/// The base's albedo estimate, computed on first use when the base is the same everywhere
+	#[serde(skip)]
+	base_albedo: OnceLock<(Vector3, Vector3)>,
+}
+
+///
+/// A coating on top of an arbitrary base BSDF
+///
+/// Light either reflects off the coating or refracts into it, gets tinted, scatters off the
+/// base and refracts back out. Light the base sends back towards the coating at angles where
+/// it can't escape gets reflected down again. Those inter-reflections are added as a separate
+/// diffuse lobe, sized from the base's albedo, so a white Lambertian base under a clear coat
+/// loses no energy.
+///
+/// The base is treated as opaque, anything it transmits is lost.
+///
+pub struct LayeredBsdf {
+	coating: MicrofacetReflection,
+	eta: f64,
+	tint: Vector3,
+	base: Option<Box<dyn Bsdf>>,
+	/// Albedo of the base for light that escapes after a single bounce, used for lobe selection
+	single_scattering_albedo: f64,
+	/// The constant part of the inter-reflection lobe, per channel
+	multiple_scattering: Vector3,
+}
+
+impl LayeredBsdf {
+	/// Bases that are `uniform` over the surface share one albedo estimate, cached in the coating
+	pub fn new(coating: &Coating, base: Option<Box<dyn Bsdf>>, uniform: bool) -> LayeredBsdf {
+		let eta = coating.ior;
+		let external_reflectance = diffuse_fresnel_reflectance(eta);
+		// Fraction of diffuse light reflected back into the coating from below
+		let internal_reflectance = 1.0 - (1.0 - external_reflectance) / (eta * eta);
+
+		let (single_scattering_albedo, multiple_scattering) = match &base {
+			Some(base) => {
+				let (albedo, escaped) = if uniform {
+					*coating.base_albedo.get_or_init(|| estimate_albedo(base.as_ref(), eta))
+				} else {
+					estimate_albedo(base.as_ref(), eta)
+				};
+				let albedo = albedo.mul_element_wise(coating.tint);
+				let escaped = escaped.mul_element_wise(coating.tint);
+				let trapped = if luminance(albedo) > 0.0 {
+					(1.0 - luminance(escaped) / luminance(albedo)).clamp(0.0, 1.0)
+				} else {
+					0.0
+				};
+
+				// Light trapped after the first bounce keeps bouncing between the base and the coating,
+				// assumed diffuse from then on, with a fraction escaping on every bounce
+				let lobe = |albedo: f64| {
+					let albedo = albedo.clamp(0.0, 1.0);
+					albedo * albedo * trapped / (eta * eta * PI * (1.0 - albedo * internal_reflectance))
+				};
+				(luminance(escaped), Vector3::new(lobe(albedo.x), lobe(albedo.y), lobe(albedo.z)))
+			}
+			None => (0.0, Vector3::new(0.0, 0.0, 0.0)),
+		};
+
+		LayeredBsdf {
+			coating: MicrofacetReflection::new(Ggx::from_roughness(coating.roughness), Fresnel::Dielectric(eta)),
+			eta,
+			tint: coating.tint,
+			base,
+			single_scattering_albedo,
+			multiple_scattering,
+		}
+	}
+
+	/// Maps an outside direction in the upper hemisphere to the refracted one inside the coating
+	/// The result points up, away from the base
+	fn refract_into_layer(&self, w: Vector3) -> Vector3 {
+		let up = Vector3::new(0.0, 1.0, 0.0);
+		match refract(w, up, self.eta) {
+			Some(t) => -t,
+			None => up,
+		}
+	}
+
+	/// The inverse of `refract_into_layer`, None if the direction is totally internally reflected
+	fn refract_out_of_layer(&self, w: Vector3) -> Option<Vector3> {
+		refract(-w, Vector3::new(0.0, -1.0, 0.0), 1.0 / self.eta)
+	}
+
+	fn transmittance(&self, w: Vector3) -> f64 {
+		1.0 - fresnel::dielectric(w.y, self.eta)
+	}
+
+	/// Tint of a path going down the coating along wo and back up along wi (both inside)
+	fn absorption(&self, wo: Vector3, wi: Vector3) -> Vector3 {
+		let exponent = 0.5 * (1.0 / wo.y.max(1e-4) + 1.0 / wi.y.max(1e-4));
+		Vector3::new(self.tint.x.powf(exponent), self.tint.y.powf(exponent), self.tint.z.powf(exponent))
+	}
+
+	/// Probabilities of sampling the coating, the base and the inter-reflection lobe
+	fn lobe_probabilities(&self, wo: Vector3) -> [f64; 3] {
+		if self.base.is_none() {
+			return [1.0, 0.0, 0.0];
+		}
+
+		let transmittance = self.transmittance(wo);
+		let weights = [
+			fresnel::dielectric(wo.y, self.eta),
+			transmittance * self.single_scattering_albedo.max(0.05),
+			transmittance * luminance(self.multiple_scattering) * PI,
+		];
+		let total: f64 = weights.iter().sum();
+
+		[weights[0] / total, weights[1] / total, weights[2] / total]
+	}
+}
+
+impl Bsdf for LayeredBsdf {
+	fn eval(&self, wo: Vector3, wi: Vector3) -> Vector3 {
+		if !same_hemisphere(wo, wi) {
+			return Vector3::new(0.0, 0.0, 0.0);
+		}
+		let (wo, wi) = (upper_hemisphere(wo), upper_hemisphere(wi));
+		let coating = self.coating.eval(wo, wi);
+
+		let base = match &self.base {
+			Some(base) => base,
+			None => return coating,
+		};
+		let (wo_inside, wi_inside) = (self.refract_into_layer(wo), self.refract_into_layer(wi));
+		let transmittance = self.transmittance(wo) * self.transmittance(wi);
+		let single = base
+			.eval(wo_inside, wi_inside)
+			.mul_element_wise(self.absorption(wo_inside, wi_inside))
+			/ (self.eta * self.eta);
+
+		coating + (single + self.multiple_scattering) * transmittance
+	}
+
+	fn pdf(&self, wo: Vector3, wi: Vector3) -> f64 {
+		if !same_hemisphere(wo, wi) {
+			return 0.0;
+		}
+		let (wo, wi) = (upper_hemisphere(wo), upper_hemisphere(wi));
+		let p = self.lobe_probabilities(wo);
+		let coating = self.coating.pdf(wo, wi);
+
+		let base = match &self.base {
+			Some(base) => base,
+			None => return coating,
+		};
+		let (wo_inside, wi_inside) = (self.refract_into_layer(wo), self.refract_into_layer(wi));
+		// Change of variables from the refracted direction to the outside one
+		let jacobian = wi.y / (self.eta * self.eta * wi_inside.y.max(1e-8));
+
+		p[0] * coating + p[1] * base.pdf(wo_inside, wi_inside) * jacobian + p[2] * sampling::cosine_hemisphere_pdf(wi.y)
+	}
+
+	fn sample(&self, wo: Vector3, u: Vector2, uc: f64) -> Option<BsdfSample> {
+		let o = upper_hemisphere(wo);
+		let p = self.lobe_probabilities(o);
+
+		let mut wi = if uc < p[0] {
+			self.coating.sample(o, u, uc / p[0])?.wi
+		} else if uc < p[0] + p[1] {
+			let base = self.base.as_ref()?;
+			let uc = ((uc - p[0]) / p[1]).min(1.0);
+			let wi_inside = base.sample(self.refract_into_layer(o), u, uc)?.wi;
+			if wi_inside.y <= 0.0 {
+				return None;
+			}
+			self.refract_out_of_layer(wi_inside)?
+		} else {
+			sampling::cosine_sample_hemisphere(u)
+		};
+
+		if wo.y < 0.0 {
+			wi.y = -wi.y;
+		}
+		BsdfSample::evaluate(self, wo, wi)
+	}
+}
+
+/// Cosine weighted average of the Fresnel reflectance over the hemisphere
+fn diffuse_fresnel_reflectance(eta: f64) -> f64 {
+	const STEPS: usize = 64;
+
+	// Integrate 2 * F(cos) * cos over cos in [0, 1] with the midpoint rule
+	(0..STEPS)
+		.map(|i| {
+			let cos = (i as f64 + 0.5) / STEPS as f64;
+			2.0 * fresnel::dielectric(cos, eta) * cos / STEPS as f64
+		})
+		.sum()
+}
+
+/// Estimates the directional albedo of a BSDF at normal incidence below a coating, with stratified samples
+/// Returns the albedo and the part of it that gets through the coating on the way out
+fn estimate_albedo(bsdf: &dyn Bsdf, eta: f64) -> (Vector3, Vector3) {
+	let wo = Vector3::new(0.0, 1.0, 0.0);
+	let n = (BASE_ALBEDO_SAMPLES as f64).sqrt() as usize;
+	let mut albedo = Vector3::new(0.0, 0.0, 0.0);
+	let mut escaped = Vector3::new(0.0, 0.0, 0.0);
+
+	for i in 0..n {
+		for j in 0..n {
+			let u = Vector2::new((i as f64 + 0.5) / n as f64, (j as f64 + 0.5) / n as f64);
+			let uc = (i * n + j) as f64 / (n * n) as f64;
+			if let Some(sample) = bsdf.sample(wo, u, uc) {
+				if sample.wi.y > 0.0 {
+					let weight = sample.f * (sample.wi.y / sample.pdf);
+					albedo += weight;
+					// Arriving at the coating from below
+					escaped += weight * (1.0 - fresnel::dielectric(-sample.wi.y, eta));
+				}
+			}
+		}
+	}
+
+	(albedo / (n * n) as f64, escaped / (n * n) as f64)
+}
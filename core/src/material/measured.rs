@@ -0,0 +1,239 @@
+use std::{
+	convert::TryFrom,
+	fmt, fs, io,
+	path::PathBuf,
+	sync::Arc,
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::bsdf::{same_hemisphere, upper_hemisphere, Bsdf, BsdfSample};
+use crate::{
+	math::{luminance, prelude::*},
+	project,
+	sampling::Distribution2D,
+};
+
+const THETA_H_RESOLUTION: usize = 90;
+const THETA_D_RESOLUTION: usize = 90;
+const PHI_D_RESOLUTION: usize = 180;
+const SAMPLE_COUNT: usize = THETA_H_RESOLUTION * THETA_D_RESOLUTION * PHI_D_RESOLUTION;
+
+/// The MERL data is stored scaled per channel
+const CHANNEL_SCALE: [f64; 3] = [1.0 / 1500.0, 1.15 / 1500.0, 1.66 / 1500.0];
+
+/// Resolution of the sampling tables in cos(theta_o), and in cos(theta_i) and relative phi
+const SAMPLING_COS_O_RESOLUTION: usize = 32;
+const SAMPLING_COS_I_RESOLUTION: usize = 64;
+const SAMPLING_PHI_RESOLUTION: usize = 64;
+/// Fraction of the average added to every cell of a sampling table, so no direction has zero density
+const SAMPLING_FLOOR: f64 = 0.05;
+
+///
+/// An isotropic BRDF measured by Matusik et al. 2003, in the MERL `.binary` format
+///
+/// The data is tabulated in Rusinkiewicz's half/difference angles and looked up without
+/// interpolation. Directions are importance sampled from a table of the BRDF's luminance,
+/// precomputed for a range of outgoing angles on load.
+///
+/// In the project file a measured BRDF is just the path to the file, relative to the project
+/// file or material library.
+///
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(try_from = "PathBuf", into = "PathBuf")]
+pub struct MeasuredBrdf {
+	pub path: PathBuf,
+	data: Arc<Vec<f64>>,
+	/// One distribution over (cos(theta_i), phi_i - phi_o) per bin of cos(theta_o)
+	sampling: Arc<Vec<Distribution2D>>,
+}
+
+impl MeasuredBrdf {
+	pub fn load(path: PathBuf) -> io::Result<MeasuredBrdf> {
+		let bytes = fs::read(&path)?;
+		let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), message));
+
+		if bytes.len() < 12 {
+			return Err(invalid("file is too short for a MERL header"));
+		}
+		let dimension = |i: usize| i32::from_le_bytes([bytes[i * 4], bytes[i * 4 + 1], bytes[i * 4 + 2], bytes[i * 4 + 3]]);
+		if (dimension(0), dimension(1), dimension(2)) != (THETA_H_RESOLUTION as i32, THETA_D_RESOLUTION as i32, PHI_D_RESOLUTION as i32) {
+			return Err(invalid("unexpected table dimensions"));
+		}
+		if bytes.len() != 12 + SAMPLE_COUNT * 3 * 8 {
+			return Err(invalid("file size doesn't match the table dimensions"));
+		}
+
+		let data = bytes[12..]
+			.chunks_exact(8)
+			.map(|chunk| {
+				let mut value = [0u8; 8];
+				value.copy_from_slice(chunk);
+				f64::from_le_bytes(value)
+			})
+			.collect();
+
+		let mut brdf = MeasuredBrdf {
+			path,
+			data: Arc::new(data),
+			sampling: Arc::new(Vec::new()),
+		};
+		brdf.sampling = Arc::new(brdf.build_sampling_tables());
+		Ok(brdf)
+	}
+
+	/// Looks up the BRDF for two directions in the upper hemisphere
+	fn lookup(&self, wo: Vector3, wi: Vector3) -> Vector3 {
+		let (theta_h, theta_d, phi_d) = half_difference(wo, wi);
+
+		// theta_h is stored with a non-linear mapping, to resolve the specular peak more finely
+		let theta_h_index = ((theta_h / (PI / 2.0)).max(0.0).sqrt() * THETA_H_RESOLUTION as f64) as usize;
+		let theta_d_index = (theta_d / (PI / 2.0) * THETA_D_RESOLUTION as f64) as usize;
+		// The BRDF is reciprocal, so only half of the phi_d range is stored
+		let phi_d = if phi_d < 0.0 { phi_d + PI } else { phi_d };
+		let phi_d_index = (phi_d / PI * PHI_D_RESOLUTION as f64) as usize;
+
+		let index = phi_d_index.min(PHI_D_RESOLUTION - 1)
+			+ theta_d_index.min(THETA_D_RESOLUTION - 1) * PHI_D_RESOLUTION
+			+ theta_h_index.min(THETA_H_RESOLUTION - 1) * PHI_D_RESOLUTION * THETA_D_RESOLUTION;
+
+		// Missing measurements are stored as negative values
+		let channel = |c: usize| (self.data[index + c * SAMPLE_COUNT] * CHANNEL_SCALE[c]).max(0.0);
+		Vector3::new(channel(0), channel(1), channel(2))
+	}
+
+	fn build_sampling_tables(&self) -> Vec<Distribution2D> {
+		(0..SAMPLING_COS_O_RESOLUTION)
+			.map(|o| {
+				let cos_o = (o as f64 + 0.5) / SAMPLING_COS_O_RESOLUTION as f64;
+				let wo = Vector3::new((1.0 - cos_o * cos_o).sqrt(), cos_o, 0.0);
+
+				let mut func = Vec::with_capacity(SAMPLING_COS_I_RESOLUTION * SAMPLING_PHI_RESOLUTION);
+				for i in 0..SAMPLING_COS_I_RESOLUTION {
+					for p in 0..SAMPLING_PHI_RESOLUTION {
+						let point = Vector2::new(
+							(p as f64 + 0.5) / SAMPLING_PHI_RESOLUTION as f64,
+							(i as f64 + 0.5) / SAMPLING_COS_I_RESOLUTION as f64,
+						);
+						let wi = direction_from_table(wo, point);
+						func.push(luminance(self.lookup(wo, wi)) * wi.y);
+					}
+				}
+
+				let floor = SAMPLING_FLOOR * func.iter().sum::<f64>() / func.len() as f64;
+				let floor = if floor > 0.0 { floor } else { 1.0 };
+				for value in func.iter_mut() {
+					*value += floor;
+				}
+
+				Distribution2D::new(&func, SAMPLING_PHI_RESOLUTION, SAMPLING_COS_I_RESOLUTION)
+			})
+			.collect()
+	}
+
+	fn sampling_table(&self, wo: Vector3) -> &Distribution2D {
+		let bin = (wo.y * SAMPLING_COS_O_RESOLUTION as f64) as usize;
+		&self.sampling[bin.min(SAMPLING_COS_O_RESOLUTION - 1)]
+	}
+}
+
+/// The direction for a point in a sampling table, phi runs along x and cos(theta) along y
+/// phi is relative to the azimuth of wo
+fn direction_from_table(wo: Vector3, point: Vector2) -> Vector3 {
+	let phi = wo.z.atan2(wo.x) + 2.0 * PI * point.x;
+	let cos_theta = point.y;
+	let sin_theta = (1.0 - cos_theta * cos_theta).max(0.0).sqrt();
+
+	Vector3::new(sin_theta * phi.cos(), cos_theta, sin_theta * phi.sin())
+}
+
+/// The inverse of `direction_from_table`
+fn table_from_direction(wo: Vector3, wi: Vector3) -> Vector2 {
+	let phi = wi.z.atan2(wi.x) - wo.z.atan2(wo.x);
+	let x = (phi / (2.0 * PI)).rem_euclid(1.0);
+
+	Vector2::new(x, wi.y)
+}
+
+///
+/// Converts a pair of directions to Rusinkiewicz's half/difference angles (theta_h, theta_d, phi_d)
+///
+/// Follows the MERL reference code, which works in a z-up frame
+///
+fn half_difference(wo: Vector3, wi: Vector3) -> (f64, f64, f64) {
+	let to_merl = |w: Vector3| Vector3::new(w.x, w.z, w.y);
+	let (wo, wi) = (to_merl(wo), to_merl(wi));
+
+	let half = (wo + wi).normalize();
+	let theta_h = half.z.clamp(-1.0, 1.0).acos();
+	let phi_h = half.y.atan2(half.x);
+
+	// Rotate wi so the half vector becomes the normal
+	let diff = rotate(rotate(wi, Vector3::new(0.0, 0.0, 1.0), -phi_h), Vector3::new(0.0, 1.0, 0.0), -theta_h);
+	let theta_d = diff.z.clamp(-1.0, 1.0).acos();
+	let phi_d = diff.y.atan2(diff.x);
+
+	(theta_h, theta_d, phi_d)
+}
+
+/// Rotates v about a unit axis
+fn rotate(v: Vector3, axis: Vector3, angle: f64) -> Vector3 {
+	let (sin, cos) = angle.sin_cos();
+	v * cos + axis * (v.dot(axis) * (1.0 - cos)) + axis.cross(v) * sin
+}
+
+impl Bsdf for MeasuredBrdf {
+	fn eval(&self, wo: Vector3, wi: Vector3) -> Vector3 {
+		if !same_hemisphere(wo, wi) {
+			return Vector3::new(0.0, 0.0, 0.0);
+		}
+
+		self.lookup(upper_hemisphere(wo), upper_hemisphere(wi))
+	}
+
+	fn pdf(&self, wo: Vector3, wi: Vector3) -> f64 {
+		if !same_hemisphere(wo, wi) {
+			return 0.0;
+		}
+		let (wo, wi) = (upper_hemisphere(wo), upper_hemisphere(wi));
+
+		// The table is uniform in cos(theta) and phi, which covers the hemisphere's 2π steradians evenly
+		self.sampling_table(wo).pdf(table_from_direction(wo, wi)) / (2.0 * PI)
+	}
+
+	fn sample(&self, wo: Vector3, u: Vector2, _uc: f64) -> Option<BsdfSample> {
+		let o = upper_hemisphere(wo);
+		let (point, _) = self.sampling_table(o).sample(u);
+		let mut wi = direction_from_table(o, point);
+		if wi.y <= 0.0 {
+			return None;
+		}
+		if wo.y < 0.0 {
+			wi.y = -wi.y;
+		}
+
+		BsdfSample::evaluate(self, wo, wi)
+	}
+}
+
+impl TryFrom<PathBuf> for MeasuredBrdf {
+	type Error = io::Error;
+
+	fn try_from(path: PathBuf) -> Result<Self, Self::Error> {
+		// Keep the path as written, so the BRDF is written back the same way
+		let brdf = MeasuredBrdf::load(project::resolve_asset(&path))?;
+		Ok(MeasuredBrdf { path, ..brdf })
+	}
+}
+
+impl From<MeasuredBrdf> for PathBuf {
+	fn from(brdf: MeasuredBrdf) -> PathBuf {
+		brdf.path
+	}
+}
+
+impl fmt::Debug for MeasuredBrdf {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "MeasuredBrdf {{ path: {:?} }}", self.path)
+	}
+}
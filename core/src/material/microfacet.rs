@@ -0,0 +1,190 @@
+use crate::math::prelude::*;
+
+/// Roughness values below this produce distributions too narrow to be represented numerically
+const MIN_ALPHA: f64 = 1e-3;
+
+///
+/// The anisotropic GGX (Trowbridge-Reitz) microfacet distribution, with Smith masking
+///
+/// alpha_x is the roughness along the tangent, alpha_y along the bitangent. The rotation
+/// turns the distribution about the normal, from the tangent towards the bitangent.
+///
+/// Functions taking a direction accept both hemispheres, the distribution is
+/// symmetric about the surface.
+///
+#[derive(Debug, Clone, Copy)]
+pub struct Ggx {
+	pub alpha_x: f64,
+	pub alpha_y: f64,
+	/// Cosine and sine of the rotation
+	rotation: (f64, f64),
+}
+
+impl Ggx {
+	pub fn new(alpha: f64) -> Ggx {
+		Ggx::anisotropic(alpha, alpha, 0.0)
+	}
+
+	/// rotation is in radians
+	pub fn anisotropic(alpha_x: f64, alpha_y: f64, rotation: f64) -> Ggx {
+		Ggx {
+			alpha_x: alpha_x.max(MIN_ALPHA),
+			alpha_y: alpha_y.max(MIN_ALPHA),
+			rotation: (rotation.cos(), rotation.sin()),
+		}
+	}
+
+	/// Perceptually linear roughness, as used by the principled model and most DCC tools
+	pub fn from_roughness(roughness: f64) -> Ggx {
+		Ggx::new(roughness * roughness)
+	}
+
+	///
+	/// Disney's anisotropic parametrization, anisotropy in [0, 1] stretches the highlight
+	/// while keeping its overall size. rotation is in radians.
+	///
+	pub fn from_roughness_anisotropic(roughness: f64, anisotropy: f64, rotation: f64) -> Ggx {
+		let aspect = (1.0 - 0.9 * anisotropy.clamp(0.0, 1.0)).sqrt();
+		let alpha = roughness * roughness;
+
+		Ggx::anisotropic(alpha / aspect, alpha * aspect, rotation)
+	}
+
+	/// Rotates a direction from the shading frame into the frame of the distribution
+	fn shading_to_distribution(&self, w: Vector3) -> Vector3 {
+		let (cos, sin) = self.rotation;
+		Vector3::new(cos * w.x + sin * w.z, w.y, -sin * w.x + cos * w.z)
+	}
+
+	fn distribution_to_shading(&self, w: Vector3) -> Vector3 {
+		let (cos, sin) = self.rotation;
+		Vector3::new(cos * w.x - sin * w.z, w.y, sin * w.x + cos * w.z)
+	}
+
+	/// Normal distribution function
+	pub fn d(&self, wh: Vector3) -> f64 {
+		let wh = self.shading_to_distribution(wh);
+		let x = wh.x / self.alpha_x;
+		let z = wh.z / self.alpha_y;
+		let denominator = x * x + z * z + wh.y * wh.y;
+
+		1.0 / (PI * self.alpha_x * self.alpha_y * denominator * denominator)
+	}
+
+	/// Smith's auxiliary function, the ratio of hidden to visible microfacet area
+	pub fn lambda(&self, w: Vector3) -> f64 {
+		let w = self.shading_to_distribution(w);
+		let cos2 = w.y * w.y;
+		if cos2 == 0.0 {
+			return F_MAX;
+		}
+		// alpha^2 tan^2, with alpha projected onto the direction's azimuth
+		let x = self.alpha_x * w.x;
+		let z = self.alpha_y * w.z;
+		let alpha2_tan2 = (x * x + z * z) / cos2;
+
+		((1.0 + alpha2_tan2).sqrt() - 1.0) / 2.0
+	}
+
+	/// Masking function for a single direction
+	pub fn g1(&self, w: Vector3) -> f64 {
+		1.0 / (1.0 + self.lambda(w))
+	}
+
+	/// Height correlated masking-shadowing
+	pub fn g(&self, wo: Vector3, wi: Vector3) -> f64 {
+		1.0 / (1.0 + self.lambda(wo) + self.lambda(wi))
+	}
+
+	///
+	/// Samples a microfacet normal from the distribution of normals visible from wo
+	/// Heitz 2018, "Sampling the GGX Distribution of Visible Normals"
+	///
+	/// The returned normal lies in the same hemisphere as wo
+	///
+	pub fn sample_wh(&self, wo: Vector3, u: Vector2) -> Vector3 {
+		let flip = wo.y < 0.0;
+		let wo = self.shading_to_distribution(if flip { -wo } else { wo });
+
+		// Stretch the view direction so the distribution becomes a hemisphere
+		let vh = Vector3::new(self.alpha_x * wo.x, wo.y, self.alpha_y * wo.z).normalize();
+
+		let len2 = vh.x * vh.x + vh.z * vh.z;
+		let t1 = if len2 > 0.0 {
+			Vector3::new(-vh.z, 0.0, vh.x) / len2.sqrt()
+		} else {
+			Vector3::new(1.0, 0.0, 0.0)
+		};
+		let t2 = t1.cross(vh);
+
+		// Sample the projected area of the hemisphere
+		let r = u.x.sqrt();
+		let phi = 2.0 * PI * u.y;
+		let p1 = r * phi.cos();
+		let p2 = r * phi.sin();
+		let s = 0.5 * (1.0 + vh.y);
+		let p2 = (1.0 - s) * (1.0 - p1 * p1).max(0.0).sqrt() + s * p2;
+		let nh = t1 * p1 + t2 * p2 + vh * (1.0 - p1 * p1 - p2 * p2).max(0.0).sqrt();
+
+		// Unstretch
+		let wh = Vector3::new(self.alpha_x * nh.x, nh.y.max(1e-6), self.alpha_y * nh.z).normalize();
+		let wh = self.distribution_to_shading(wh);
+		if flip {
+			-wh
+		} else {
+			wh
+		}
+	}
+
+	/// Density of `sample_wh` generating wh
+	pub fn pdf(&self, wo: Vector3, wh: Vector3) -> f64 {
+		if wo.y == 0.0 {
+			return 0.0;
+		}
+
+		self.g1(wo) * wo.dot(wh).abs() * self.d(wh) / wo.y.abs()
+	}
+}
+
+///
+/// The generalized Trowbridge-Reitz distribution with gamma = 1
+/// Its long tails make it a good fit for clear coats
+///
+#[derive(Debug, Clone, Copy)]
+pub struct Gtr1 {
+	pub alpha: f64,
+}
+
+impl Gtr1 {
+	pub fn new(alpha: f64) -> Gtr1 {
+		Gtr1 {
+			alpha: alpha.clamp(MIN_ALPHA, 0.999),
+		}
+	}
+
+	pub fn d(&self, wh: Vector3) -> f64 {
+		let a2 = self.alpha * self.alpha;
+		let cos2 = wh.y * wh.y;
+
+		(a2 - 1.0) / (PI * a2.ln() * (1.0 + (a2 - 1.0) * cos2))
+	}
+
+	/// Samples a microfacet normal proportional to D(wh) cos(theta_h), in wo's hemisphere
+	pub fn sample_wh(&self, wo: Vector3, u: Vector2) -> Vector3 {
+		let a2 = self.alpha * self.alpha;
+		let cos_theta = ((1.0 - a2.powf(1.0 - u.x)) / (1.0 - a2)).max(0.0).sqrt();
+		let sin_theta = (1.0 - cos_theta * cos_theta).max(0.0).sqrt();
+		let phi = 2.0 * PI * u.y;
+		let wh = Vector3::new(sin_theta * phi.cos(), cos_theta, sin_theta * phi.sin());
+
+		if wo.y < 0.0 {
+			-wh
+		} else {
+			wh
+		}
+	}
+
+	pub fn pdf(&self, wh: Vector3) -> f64 {
+		self.d(wh) * wh.y.abs()
+	}
+}
@@ -0,0 +1,139 @@
+mod albedo_tables;
+pub mod bsdf;
+pub mod conductor;
+pub mod cook_torrance;
+pub mod dielectric;
+pub mod emission;
+pub mod fresnel;
+pub mod graph;
+pub mod layered;
+pub mod measured;
+pub mod microfacet;
+pub mod multiple_scattering;
+pub mod principled;
+pub mod sheen;
+pub mod subsurface;
+pub mod thin_film;
+pub mod validation;
+
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{geometry::SurfaceProperties, math::prelude::*, shading};
+
+pub use self::{
+	bsdf::{Bsdf, BsdfSample},
+	conductor::{ComplexIor, Conductor, Metal},
+	emission::{Blackbody, Emission, EmissionColor, EmissionStrength},
+	graph::{GraphDescription, MaterialGraph},
+	layered::Coating,
+	measured::MeasuredBrdf,
+	principled::Principled,
+	sheen::Cloth,
+	subsurface::Subsurface,
+	thin_film::ThinFilm,
+};
+
+use self::{cook_torrance::CookTorrance, layered::LayeredBsdf};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Material {
+	Diffuse(Vector3, f64),
+	Metal(Vector3, f64),
+	Emission(Emission),
+	Principled(Principled),
+	/// A metal with a complex index of refraction
+	Conductor(Conductor),
+	/// Scatters light inside a closed object
+	Subsurface(Subsurface),
+	/// A measured isotropic BRDF
+	Measured(MeasuredBrdf),
+	/// Fabric, a sheen lobe alone or on top of a diffuse base
+	Cloth(Cloth),
+	/// A dielectric coating on top of another material
+	/// Normal maps of the base apply to the coating as well
+	Layered(Box<Material>, Coating),
+	/// Wraps another material, perturbing its shading normal
+	NormalMapped(Box<Material>, shading::NormalMap),
+	/// Wraps another material, letting rays pass through transparent parts of the surface
+	/// Handled by `Scene::intersect`, the shading never sees the transparent parts
+	Cutout(Box<Material>, shading::Opacity),
+	/// A node graph, compiled from its `GraphDescription` when it's read
+	/// Can be wrapped like any other material, the material table also takes bare descriptions
+	Graph(#[serde(with = "graph::shared")] Arc<MaterialGraph>),
+}
+
+impl Material {
+	/// The material that does the actual shading, with all wrappers stripped
+	pub fn base(&self) -> &Material {
+		match self {
+			Material::NormalMapped(inner, _) | Material::Cutout(inner, _) => inner.base(),
+			_ => self,
+		}
+	}
+
+	/// Whether the material has no transparent parts anywhere, so intersections can skip `opacity`
+	pub fn is_opaque(&self) -> bool {
+		match self {
+			Material::Cutout(..) => false,
+			Material::NormalMapped(inner, _) | Material::Layered(inner, _) => inner.is_opaque(),
+			_ => true,
+		}
+	}
+
+	/// Probability of a ray hitting the surface instead of passing through it
+	pub fn opacity(&self, surface: &SurfaceProperties) -> f64 {
+		match self {
+			Material::Cutout(inner, opacity) => opacity.at(surface) * inner.opacity(surface),
+			Material::NormalMapped(inner, _) | Material::Layered(inner, _) => inner.opacity(surface),
+			_ => 1.0,
+		}
+	}
+
+	/// The shading normal at a surface point, after all normal and bump maps are applied
+	pub fn shading_normal(&self, surface: &SurfaceProperties) -> Vector3 {
+		match self {
+			Material::NormalMapped(inner, map) => {
+				let surface = SurfaceProperties {
+					normal: map.perturb(surface),
+					..surface.clone()
+				};
+				inner.shading_normal(&surface)
+			}
+			Material::Layered(base, _) | Material::Cutout(base, _) => base.shading_normal(surface),
+			_ => surface.normal,
+		}
+	}
+
+	/// Whether the scattering function is the same at every surface point, ignoring the shading normal
+	pub fn is_uniform(&self) -> bool {
+		let uniform_film = |film: &Option<ThinFilm>| film.as_ref().is_none_or(|film| film.thickness_texture.is_none());
+		match self {
+			Material::Principled(principled) => uniform_film(&principled.thin_film),
+			Material::Conductor(conductor) => uniform_film(&conductor.thin_film),
+			Material::Layered(inner, _) | Material::NormalMapped(inner, _) | Material::Cutout(inner, _) => inner.is_uniform(),
+			Material::Graph(graph) => graph.is_uniform(),
+			_ => true,
+		}
+	}
+
+	/// The scattering function at a surface point, None if the material doesn't scatter light
+	pub fn bsdf(&self, surface: &SurfaceProperties) -> Option<Box<dyn Bsdf>> {
+		match self {
+			Material::Diffuse(color, roughness) => Some(Box::new(CookTorrance::new(*color, *roughness, 0.0))),
+			Material::Metal(color, roughness) => Some(Box::new(CookTorrance::new(*color, *roughness, 1.0))),
+			Material::Emission(..) => None,
+			Material::Principled(principled) => Some(Box::new(principled.bsdf(surface))),
+			Material::Conductor(conductor) => Some(Box::new(conductor.bsdf(surface))),
+			Material::Subsurface(subsurface) => Some(Box::new(subsurface.boundary())),
+			Material::Measured(brdf) => Some(Box::new(brdf.clone())),
+			Material::Cloth(cloth) => Some(Box::new(cloth.bsdf())),
+			Material::Layered(base, coating) => {
+				Some(Box::new(LayeredBsdf::new(coating, base.bsdf(surface), base.is_uniform())))
+			}
+			Material::NormalMapped(inner, _) | Material::Cutout(inner, _) => inner.bsdf(surface),
+			Material::Graph(graph) => graph.bsdf(surface),
+		}
+	}
+}
@@ -0,0 +1,215 @@
+//!
+//! Energy compensation for light scattering more than once between microfacets
+//!
+//! Single scattering microfacet models lose the energy of light that bounces between facets
+//! before leaving the surface, so rough surfaces come out too dark. The missing energy is the
+//! complement of the directional albedo `E(cos, alpha)` of the single scattering model, which is
+//! precomputed into the tables in `albedo_tables.rs`. They also hold the albedo of the sheen
+//! lobe, which decides how much light reaches the base of cloth, and of the diffuse lobes of
+//! the principled BSDF.
+//!
+//! Regenerate the tables after changing the distribution, the single scattering BSDFs, the
+//! sheen or the principled diffuse with `cargo run --release -p core --bin albedo_tables`.
+//!
+
+use super::{albedo_tables::*, fresnel::Fresnel, microfacet::Ggx};
+use crate::math::{luminance, prelude::*};
+
+/// Largest relative index of refraction covered by the dielectric tables
+pub const MAX_ETA: f64 = 3.0;
+
+/// Cosine at a cell of a table with the given resolution
+pub fn table_cos(index: usize, resolution: usize) -> f64 {
+	(index as f64 + 0.5) / resolution as f64
+}
+
+/// GGX alpha at a cell, the tables are uniform in perceptual roughness (sqrt(alpha))
+pub fn table_alpha(index: usize, resolution: usize) -> f64 {
+	let roughness = (index as f64 + 0.5) / resolution as f64;
+	roughness * roughness
+}
+
+/// Relative index of refraction at a cell, from 1 to `MAX_ETA`
+pub fn table_eta(index: usize, resolution: usize) -> f64 {
+	1.0 + (MAX_ETA - 1.0) * (index as f64 + 0.5) / resolution as f64
+}
+
+/// Position of x in [0, 1] between the cells of a table, as the lower cell, the next one and the weight of the next
+fn cell(x: f64, resolution: usize) -> (usize, usize, f64) {
+	let x = (x * resolution as f64 - 0.5).max(0.0).min((resolution - 1) as f64);
+	let lower = x.floor() as usize;
+
+	(lower, (lower + 1).min(resolution - 1), x - lower as f64)
+}
+
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+	a * (1.0 - t) + b * t
+}
+
+/// Bilinear lookup in a table of cos along its rows and alpha along its columns
+pub(super) fn lookup(table: &[f64], resolution: usize, cos: f64, alpha: f64) -> f64 {
+	let (c0, c1, tc) = cell(cos.abs(), resolution);
+	let (r0, r1, tr) = cell(alpha.sqrt(), resolution);
+	let at = |r: usize, c: usize| table[r * resolution + c];
+
+	lerp(lerp(at(r0, c0), at(r0, c1), tc), lerp(at(r1, c0), at(r1, c1), tc), tr)
+}
+
+/// Linear lookup in a table of averages over cos, with alpha along it
+fn lookup_average(table: &[f64], alpha: f64) -> f64 {
+	let (r0, r1, t) = cell(alpha.sqrt(), table.len());
+	lerp(table[r0], table[r1], t)
+}
+
+/// Directional albedo of microfacet reflection with a Fresnel term of one
+pub fn conductor_albedo(cos: f64, alpha: f64) -> f64 {
+	lookup(&CONDUCTOR_ALBEDO, CONDUCTOR_RESOLUTION, cos, alpha)
+}
+
+/// Cosine weighted average of `conductor_albedo` over the hemisphere
+pub fn conductor_average_albedo(alpha: f64) -> f64 {
+	lookup_average(&CONDUCTOR_AVERAGE_ALBEDO, alpha)
+}
+
+///
+/// Directional albedo of single scattering microfacet reflection with Schlick's Fresnel term
+///
+/// Schlick's approximation is linear in f0, so the albedo is interpolated between the tables
+/// for a Fresnel term of one and for f0 = 0.
+///
+pub fn schlick_albedo(cos: f64, alpha: f64, f0: Vector3) -> Vector3 {
+	let (one, zero) = (conductor_albedo(cos, alpha), lookup(&SCHLICK_WEIGHT_ALBEDO, CONDUCTOR_RESOLUTION, cos, alpha));
+	f0 * (one - zero) + Vector3::new(zero, zero, zero)
+}
+
+/// Cosine weighted average of `schlick_albedo` over the hemisphere
+pub fn schlick_average_albedo(alpha: f64, f0: Vector3) -> Vector3 {
+	let (one, zero) = (conductor_average_albedo(alpha), lookup_average(&SCHLICK_WEIGHT_AVERAGE_ALBEDO, alpha));
+	f0 * (one - zero) + Vector3::new(zero, zero, zero)
+}
+
+/// Directional albedo of a white `sheen::SheenBsdf` without base, alpha is the sheen's
+pub fn sheen_albedo(cos: f64, alpha: f64) -> f64 {
+	lookup(&SHEEN_ALBEDO, SHEEN_RESOLUTION, cos, alpha)
+}
+
+/// Cosine weighted average of `sheen_albedo` over the hemisphere
+pub fn sheen_average_albedo(alpha: f64) -> f64 {
+	lookup_average(&SHEEN_AVERAGE_ALBEDO, alpha)
+}
+
+///
+/// Directional albedo of a rough dielectric interface, reflection and transmission combined
+///
+/// eta is the relative index of refraction of the far side, seen from the viewer. Transmitted
+/// energy is counted without the 1/eta^2 radiance scaling.
+///
+pub fn dielectric_albedo(cos: f64, alpha: f64, eta: f64) -> f64 {
+	let (table, eta) = if eta >= 1.0 {
+		(&DIELECTRIC_ALBEDO_ENTERING, eta)
+	} else {
+		(&DIELECTRIC_ALBEDO_EXITING, 1.0 / eta)
+	};
+
+	let n = DIELECTRIC_RESOLUTION;
+	let (e0, e1, t) = cell((eta - 1.0) / (MAX_ETA - 1.0), n);
+	let slice = |e: usize| lookup(&table[e * n * n..(e + 1) * n * n], n, cos, alpha);
+
+	lerp(slice(e0), slice(e1), t)
+}
+
+/// Cosine weighted average of a Fresnel term over the hemisphere
+pub fn average_fresnel(fresnel: &Fresnel) -> Vector3 {
+	const STEPS: usize = 16;
+
+	match fresnel {
+		// 2 * integral of Schlick's approximation times cos, in closed form
+		Fresnel::Schlick(f0) => *f0 + (Vector3::new(1.0, 1.0, 1.0) - *f0) / 21.0,
+		_ => {
+			(0..STEPS)
+				.map(|i| {
+					let cos = (i as f64 + 0.5) / STEPS as f64;
+					fresnel.eval(cos) * cos
+				})
+				.fold(Vector3::new(0.0, 0.0, 0.0), |sum, f| sum + f)
+				* (2.0 / STEPS as f64)
+		}
+	}
+}
+
+///
+/// The multiple scattering lobe of a rough reflector
+/// Kulla and Conty 2017, "Revisiting Physically Based Shading at Imageworks"
+///
+/// The lobe is diffuse-like, shaped so reflection off a white surface conserves energy. Its
+/// color accounts for the energy absorbed by the Fresnel term on every additional bounce.
+///
+#[derive(Debug, Clone, Copy)]
+pub struct MultipleScattering {
+	alpha: f64,
+	/// Average albedo of single scattering with a Fresnel term of one
+	average_albedo: f64,
+	/// Energy of the multiple scattering, F_avg^2 E_avg / (1 - F_avg (1 - E_avg))
+	fresnel: Vector3,
+	/// Brightness of single scattering relative to the lobe, to choose between them when sampling
+	single_scattering_weight: f64,
+}
+
+impl MultipleScattering {
+	/// None if the surface is smooth enough that multiple scattering doesn't matter
+	pub fn new(distribution: &Ggx, fresnel: &Fresnel) -> Option<MultipleScattering> {
+		// Anisotropic distributions use the tables of an isotropic one of similar roughness
+		let alpha = (distribution.alpha_x * distribution.alpha_y).sqrt();
+		let average_albedo = conductor_average_albedo(alpha);
+		if average_albedo > 0.999 {
+			return None;
+		}
+
+		let f_avg = average_fresnel(fresnel);
+		let f_ms = |f: f64| f * f * average_albedo / (1.0 - f * (1.0 - average_albedo));
+		let f_ms = Vector3::new(f_ms(f_avg.x), f_ms(f_avg.y), f_ms(f_avg.z));
+		let f_ms_luminance = luminance(f_ms);
+		if f_ms_luminance.is_nan() || f_ms_luminance <= 0.0 {
+			return None;
+		}
+
+		Some(MultipleScattering {
+			alpha,
+			average_albedo,
+			fresnel: f_ms,
+			single_scattering_weight: luminance(f_avg) / f_ms_luminance,
+		})
+	}
+
+	/// Fraction of the energy single scattering loses towards a direction in the upper hemisphere
+	fn missing(&self, w: Vector3) -> f64 {
+		(1.0 - conductor_albedo(w.y, self.alpha)).max(0.0)
+	}
+
+	/// The lobe for two directions in the upper hemisphere
+	pub fn eval(&self, wo: Vector3, wi: Vector3) -> Vector3 {
+		self.fresnel * (self.missing(wo) * self.missing(wi) / (PI * (1.0 - self.average_albedo)))
+	}
+
+	/// Directional albedo of the lobe, for wo in the upper hemisphere
+	pub fn albedo(&self, wo: Vector3) -> Vector3 {
+		self.fresnel * self.missing(wo)
+	}
+
+	/// Cosine weighted average of `albedo` over the hemisphere
+	pub fn average_albedo(&self) -> Vector3 {
+		self.fresnel * (1.0 - self.average_albedo)
+	}
+
+	/// Probability of sampling this lobe instead of single scattering, for wo in the upper hemisphere
+	pub fn sample_probability(&self, wo: Vector3) -> f64 {
+		let albedo = conductor_albedo(wo.y, self.alpha).clamp(0.0, 1.0);
+		let missing = 1.0 - albedo;
+		let single = albedo * self.single_scattering_weight;
+		if missing + single > 0.0 {
+			missing / (missing + single)
+		} else {
+			0.0
+		}
+	}
+}
@@ -0,0 +1,334 @@
+use serde::{Deserialize, Serialize};
+
+use super::{
+	albedo_tables::{DIFFUSE_RESOLUTION, PRINCIPLED_DIFFUSE_ALBEDO, PRINCIPLED_SHEEN_ALBEDO, PRINCIPLED_SUBSURFACE_ALBEDO},
+	bsdf::{reflect, same_hemisphere, upper_hemisphere, Bsdf, BsdfSample, MicrofacetReflection},
+	dielectric::MicrofacetDielectric,
+	fresnel::{self, Fresnel},
+	microfacet::{Ggx, Gtr1},
+	multiple_scattering,
+	thin_film::ThinFilm,
+};
+use crate::{
+	geometry::SurfaceProperties,
+	math::{luminance, prelude::*},
+	sampling,
+};
+
+///
+/// Disney's principled BSDF (Burley 2012 and 2015)
+///
+/// The parameters and their ranges follow the Principled BSDF node of Blender and the
+/// exporters of Substance and most glTF tools, so values can be copied over verbatim.
+/// Everything except `ior` lies in [0, 1].
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Principled {
+	pub base_color: Vector3,
+	pub metallic: f64,
+	pub roughness: f64,
+	/// Stretches the specular highlight along the surface tangent, for brushed metals
+	pub anisotropic: f64,
+	/// Rotates the direction of anisotropy about the normal, a full turn is 1
+	pub anisotropic_rotation: f64,
+	/// Specular reflectance of dielectrics, 0.5 corresponds to 4% at normal incidence
+	pub specular: f64,
+	/// Tints the dielectric specular reflection towards the base color
+	pub specular_tint: f64,
+	/// Retro-reflective rim, mostly for cloth
+	pub sheen: f64,
+	pub sheen_tint: f64,
+	/// A second, colorless specular lobe on top
+	pub clearcoat: f64,
+	pub clearcoat_roughness: f64,
+	/// Index of refraction used for transmission
+	pub ior: f64,
+	pub transmission: f64,
+	/// Blends the diffuse lobe towards a flatter, subsurface like response
+	pub subsurface: f64,
+	/// An iridescent film over the specular reflection
+	pub thin_film: Option<ThinFilm>,
+}
+
+impl Default for Principled {
+	fn default() -> Self {
+		Principled {
+			base_color: Vector3::new(0.8, 0.8, 0.8),
+			metallic: 0.0,
+			roughness: 0.5,
+			anisotropic: 0.0,
+			anisotropic_rotation: 0.0,
+			specular: 0.5,
+			specular_tint: 0.0,
+			sheen: 0.0,
+			sheen_tint: 0.5,
+			clearcoat: 0.0,
+			clearcoat_roughness: 0.03,
+			ior: 1.45,
+			transmission: 0.0,
+			subsurface: 0.0,
+			thin_film: None,
+		}
+	}
+}
+
+impl Principled {
+	pub fn bsdf(&self, surface: &SurfaceProperties) -> PrincipledBsdf {
+		let clamp = |x: f64| x.clamp(0.0, 1.0);
+		let metallic = clamp(self.metallic);
+		let transmission = clamp(self.transmission);
+
+		let base_luminance = luminance(self.base_color);
+		let tint = if base_luminance > 0.0 {
+			self.base_color / base_luminance
+		} else {
+			Vector3::new(1.0, 1.0, 1.0)
+		};
+		let white = Vector3::new(1.0, 1.0, 1.0);
+
+		let dielectric_f0 = (white * (1.0 - self.specular_tint) + tint * self.specular_tint) * (self.specular * 0.08);
+		let f0 = dielectric_f0 * (1.0 - metallic) + self.base_color * metallic;
+		let specular_fresnel = match &self.thin_film {
+			// The film sits on a dielectric matching the reflectance the specular lobe would have without it
+			Some(film) => Fresnel::ThinFilm {
+				thickness: film.thickness_at(surface),
+				ior: film.ior,
+				eta: Vector3::new(fresnel::f0_to_ior(f0.x), fresnel::f0_to_ior(f0.y), fresnel::f0_to_ior(f0.z)),
+				k: Vector3::new(0.0, 0.0, 0.0),
+			},
+			None => Fresnel::Schlick(f0),
+		};
+		let distribution = Ggx::from_roughness_anisotropic(self.roughness, self.anisotropic, self.anisotropic_rotation * 2.0 * PI);
+
+		let (roughness, subsurface) = (clamp(self.roughness), clamp(self.subsurface));
+		let sheen = (white * (1.0 - self.sheen_tint) + tint * self.sheen_tint) * self.sheen;
+
+		PrincipledBsdf {
+			base_color: self.base_color,
+			roughness,
+			subsurface,
+			sheen,
+			diffuse_weight: (1.0 - metallic) * (1.0 - transmission),
+			diffuse_normalization: diffuse_normalization(self.base_color, roughness, subsurface, sheen),
+			specular: MicrofacetReflection::compensated(distribution, specular_fresnel),
+			specular_f0: f0,
+			specular_weight: 1.0 - (1.0 - metallic) * transmission,
+			clearcoat: 0.25 * clamp(self.clearcoat),
+			clearcoat_distribution: Gtr1::new(self.clearcoat_roughness * self.clearcoat_roughness),
+			transmission: MicrofacetDielectric::compensated(distribution, self.ior, self.base_color),
+			transmission_weight: (1.0 - metallic) * transmission,
+		}
+	}
+}
+
+///
+/// The principled BSDF at a shading point
+///
+/// Lobes are picked proportional to their estimated reflectance towards wo, and every
+/// sample is weighted with the combined density of all lobes.
+///
+#[derive(Debug, Clone)]
+pub struct PrincipledBsdf {
+	base_color: Vector3,
+	roughness: f64,
+	subsurface: f64,
+	sheen: Vector3,
+	diffuse_weight: f64,
+	diffuse_normalization: f64,
+	specular: MicrofacetReflection,
+	specular_f0: Vector3,
+	specular_weight: f64,
+	clearcoat: f64,
+	clearcoat_distribution: Gtr1,
+	transmission: MicrofacetDielectric,
+	transmission_weight: f64,
+}
+
+/// The clear coat's masking always uses a fixed roughness, as in Disney's model
+const CLEARCOAT_MASKING_ALPHA: f64 = 0.25;
+
+/// Cosine of the angle between either direction and their half vector
+fn cos_d(wo: Vector3, wi: Vector3) -> f64 {
+	let wh = wo + wi;
+	if wh.magnitude2() == 0.0 {
+		return 0.0;
+	}
+	wi.dot(wh.normalize())
+}
+
+/// Burley's diffuse lobe for a white surface, for directions in the upper hemisphere
+/// Uses Frostbite's renormalization, so the retro-reflection at high roughness doesn't add energy
+pub fn burley_diffuse(wo: Vector3, wi: Vector3, roughness: f64) -> f64 {
+	let cos_d = cos_d(wo, wi);
+	let (fl, fv) = (fresnel::schlick_weight(wi.y), fresnel::schlick_weight(wo.y));
+
+	let energy_bias = 0.5 * roughness;
+	let energy_factor = 1.0 - roughness * (1.0 - 1.0 / 1.51);
+	let fd90 = energy_bias + 2.0 * cos_d * cos_d * roughness;
+
+	(1.0 + (fd90 - 1.0) * fl) * (1.0 + (fd90 - 1.0) * fv) * energy_factor / PI
+}
+
+/// Burley's approximation of Hanrahan-Krueger subsurface scattering for a white surface
+pub fn subsurface_diffuse(wo: Vector3, wi: Vector3, roughness: f64) -> f64 {
+	let cos_d = cos_d(wo, wi);
+	let (fl, fv) = (fresnel::schlick_weight(wi.y), fresnel::schlick_weight(wo.y));
+
+	let fss90 = cos_d * cos_d * roughness;
+	let fss = (1.0 + (fss90 - 1.0) * fl) * (1.0 + (fss90 - 1.0) * fv);
+
+	1.25 * (fss * (1.0 / (wi.y + wo.y) - 0.5) + 0.5) / PI
+}
+
+/// The sheen lobe for a sheen color of one
+pub fn sheen_weight(wo: Vector3, wi: Vector3) -> f64 {
+	fresnel::schlick_weight(cos_d(wo, wi))
+}
+
+///
+/// Scale of the diffuse lobes so they never reflect more than they receive
+///
+/// Burley's model isn't energy conserving, the subsurface approximation and the sheen in
+/// particular reflect more than a white Lambertian surface at some angles. A constant factor
+/// keeps the lobes reciprocal, it is chosen from the tabulated albedo of the worst direction.
+///
+fn diffuse_normalization(base_color: Vector3, roughness: f64, subsurface: f64, sheen: Vector3) -> f64 {
+	let n = DIFFUSE_RESOLUTION;
+	let alpha = roughness * roughness;
+	let brightest = |v: Vector3| v.x.max(v.y).max(v.z);
+
+	let albedo = (0..n)
+		.map(|c| {
+			let cos = multiple_scattering::table_cos(c, n);
+			let diffuse = multiple_scattering::lookup(&PRINCIPLED_DIFFUSE_ALBEDO, n, cos, alpha);
+			let subsurface_albedo = multiple_scattering::lookup(&PRINCIPLED_SUBSURFACE_ALBEDO, n, cos, alpha);
+			let base = brightest(base_color) * (diffuse * (1.0 - subsurface) + subsurface_albedo * subsurface);
+
+			base + brightest(sheen) * PRINCIPLED_SHEEN_ALBEDO[c]
+		})
+		.fold(0.0, f64::max);
+
+	1.0 / albedo.max(1.0)
+}
+
+impl PrincipledBsdf {
+	/// Burley diffuse with the subsurface approximation and sheen, under the specular lobe
+	fn diffuse(&self, wo: Vector3, wi: Vector3) -> Vector3 {
+		let r = self.roughness;
+		let diffuse = self.base_color * (burley_diffuse(wo, wi, r) * (1.0 - self.subsurface) + subsurface_diffuse(wo, wi, r) * self.subsurface);
+		let sheen = self.sheen * sheen_weight(wo, wi);
+
+		(diffuse + sheen).mul_element_wise(self.specular.base_weight(wo, wi)) * (self.diffuse_weight * self.diffuse_normalization)
+	}
+
+	fn clearcoat(&self, wo: Vector3, wi: Vector3) -> f64 {
+		let wh = (wo + wi).normalize();
+		let d = self.clearcoat_distribution.d(wh);
+		let g = Ggx::new(CLEARCOAT_MASKING_ALPHA).g(wo, wi);
+		let f = fresnel::schlick(wi.dot(wh), Vector3::new(0.04, 0.04, 0.04)).x;
+
+		self.clearcoat * d * g * f / (4.0 * wo.y * wi.y)
+	}
+
+	fn clearcoat_pdf(&self, wo: Vector3, wi: Vector3) -> f64 {
+		let wh = (wo + wi).normalize();
+		self.clearcoat_distribution.pdf(wh) / (4.0 * wo.dot(wh))
+	}
+
+	/// Probabilities of sampling the diffuse, specular, clear coat and transmission lobes
+	fn lobe_probabilities(&self, wo: Vector3) -> [f64; 4] {
+		let cos = wo.y.abs();
+
+		let weights = [
+			self.diffuse_weight * (luminance(self.base_color) + luminance(self.sheen)),
+			self.specular_weight * luminance(fresnel::schlick(cos, self.specular_f0)),
+			self.clearcoat * fresnel::schlick(cos, Vector3::new(0.04, 0.04, 0.04)).x,
+			self.transmission_weight,
+		];
+		let total: f64 = weights.iter().sum();
+		if total.is_nan() || total <= 0.0 {
+			return [0.0; 4];
+		}
+
+		[weights[0] / total, weights[1] / total, weights[2] / total, weights[3] / total]
+	}
+}
+
+impl Bsdf for PrincipledBsdf {
+	fn eval(&self, wo: Vector3, wi: Vector3) -> Vector3 {
+		let mut f = Vector3::new(0.0, 0.0, 0.0);
+
+		if same_hemisphere(wo, wi) {
+			let (o, i) = (upper_hemisphere(wo), upper_hemisphere(wi));
+			if self.diffuse_weight > 0.0 {
+				f += self.diffuse(o, i);
+			}
+			f += self.specular.eval(o, i) * self.specular_weight;
+			if self.clearcoat > 0.0 {
+				let clearcoat = self.clearcoat(o, i);
+				f += Vector3::new(clearcoat, clearcoat, clearcoat);
+			}
+		}
+		if self.transmission_weight > 0.0 {
+			f += self.transmission.eval(wo, wi) * self.transmission_weight;
+		}
+
+		f
+	}
+
+	fn pdf(&self, wo: Vector3, wi: Vector3) -> f64 {
+		let p = self.lobe_probabilities(wo);
+		let mut pdf = 0.0;
+
+		if same_hemisphere(wo, wi) {
+			let (o, i) = (upper_hemisphere(wo), upper_hemisphere(wi));
+			if p[0] > 0.0 {
+				pdf += p[0] * sampling::cosine_hemisphere_pdf(i.y);
+			}
+			if p[1] > 0.0 {
+				pdf += p[1] * self.specular.pdf(o, i);
+			}
+			if p[2] > 0.0 {
+				pdf += p[2] * self.clearcoat_pdf(o, i);
+			}
+		}
+		if p[3] > 0.0 {
+			pdf += p[3] * self.transmission.pdf(wo, wi);
+		}
+
+		pdf
+	}
+
+	fn sample(&self, wo: Vector3, u: Vector2, uc: f64) -> Option<BsdfSample> {
+		let p = self.lobe_probabilities(wo);
+		let o = upper_hemisphere(wo);
+		let mirror = |mut w: Vector3| {
+			if wo.y < 0.0 {
+				w.y = -w.y;
+			}
+			w
+		};
+
+		let wi = if uc < p[0] {
+			mirror(sampling::cosine_sample_hemisphere(u))
+		} else if uc < p[0] + p[1] {
+			self.specular.sample(wo, u, ((uc - p[0]) / p[1]).min(1.0))?.wi
+		} else if uc < p[0] + p[1] + p[2] {
+			let wh = self.clearcoat_distribution.sample_wh(o, u);
+			let wi = reflect(o, wh);
+			if wi.y <= 0.0 {
+				return None;
+			}
+			mirror(wi)
+		} else if p[3] > 0.0 {
+			// Reuse the remainder of uc for the choice between reflection and refraction
+			let uc = ((uc - (p[0] + p[1] + p[2])) / p[3]).min(1.0);
+			self.transmission.sample(wo, u, uc)?.wi
+		} else {
+			return None;
+		};
+
+		BsdfSample::evaluate(self, wo, wi)
+	}
+}
@@ -0,0 +1,177 @@
+use serde::{Deserialize, Serialize};
+
+use super::{
+	bsdf::{reflect, same_hemisphere, upper_hemisphere, Bsdf, BsdfSample},
+	multiple_scattering,
+};
+use crate::{
+	math::{luminance, prelude::*},
+	sampling,
+};
+
+/// Smallest alpha of the sheen distribution, smoother fibers barely reflect anything
+const MIN_ALPHA: f64 = 1e-3;
+
+fn default_sheen_roughness() -> f64 {
+	0.5
+}
+
+///
+/// Fabric, like velvet or satin
+///
+/// In a project file:
+///
+/// ```json
+/// "Cloth": {
+///     "sheen_color": [0.9, 0.8, 0.9],
+///     "sheen_roughness": 0.4,
+///     "base_color": [0.3, 0.05, 0.1]
+/// }
+/// ```
+///
+/// Fibers sticking out of the surface scatter light back at grazing angles, which gives the
+/// soft rim of cloth. Without a base color only that sheen is reflected, e.g. for a layer of
+/// dust, with one the sheen sits on top of a diffuse base.
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Cloth {
+	pub sheen_color: Vector3,
+	/// Perceptual roughness, from 0 (fibers aligned with the normal) to 1
+	#[serde(default = "default_sheen_roughness")]
+	pub sheen_roughness: f64,
+	#[serde(default)]
+	pub base_color: Option<Vector3>,
+}
+
+impl Cloth {
+	pub fn bsdf(&self) -> SheenBsdf {
+		let roughness = self.sheen_roughness.clamp(0.0, 1.0);
+		SheenBsdf::new(self.sheen_color, roughness * roughness, self.base_color.unwrap_or(Vector3::new(0.0, 0.0, 0.0)))
+	}
+}
+
+///
+/// The "Charlie" sheen lobe over a Lambertian base, on both sides of the surface
+/// Estevez and Kulla 2017, "Production Friendly Microfacet Sheen BRDF", with the visibility
+/// term of Neubelt and Pettineo 2013, "Crafting a Next-Gen Material Pipeline for The Order: 1886"
+///
+/// The base only receives the light the sheen doesn't reflect towards either direction, judged
+/// from the tabulated albedo of the sheen.
+///
+#[derive(Debug, Clone, Copy)]
+pub struct SheenBsdf {
+	color: Vector3,
+	alpha: f64,
+	base: Vector3,
+}
+
+impl SheenBsdf {
+	pub fn new(color: Vector3, alpha: f64, base: Vector3) -> SheenBsdf {
+		SheenBsdf {
+			color,
+			alpha: alpha.max(MIN_ALPHA),
+			base,
+		}
+	}
+
+	/// The distribution of fiber normals, sin(theta)^(1/alpha), normalized over projected area
+	fn d(&self, wh: Vector3) -> f64 {
+		let sin_theta = (1.0 - wh.y * wh.y).max(0.0).sqrt();
+		(2.0 + 1.0 / self.alpha) * sin_theta.powf(1.0 / self.alpha) / (2.0 * PI)
+	}
+
+	/// Brightest channel of the sheen, which decides how much light the base receives
+	fn max_sheen(&self) -> f64 {
+		self.color.x.max(self.color.y).max(self.color.z)
+	}
+
+	/// Fraction of light reaching the base, for a direction in the upper hemisphere
+	fn base_weight(&self, w: Vector3) -> f64 {
+		(1.0 - self.max_sheen() * multiple_scattering::sheen_albedo(w.y, self.alpha)).max(0.0)
+	}
+
+	/// The base for two directions in the upper hemisphere, normalized so its albedo is base_weight(wo)
+	fn base(&self, wo: Vector3, wi: Vector3) -> Vector3 {
+		let average = 1.0 - self.max_sheen() * multiple_scattering::sheen_average_albedo(self.alpha);
+		if average <= 0.0 {
+			return Vector3::new(0.0, 0.0, 0.0);
+		}
+
+		self.base * (self.base_weight(wo) * self.base_weight(wi) / (PI * average))
+	}
+
+	/// Probability of sampling the sheen instead of the base, for wo in the upper hemisphere
+	fn sheen_probability(&self, wo: Vector3) -> f64 {
+		let sheen = luminance(self.color) * multiple_scattering::sheen_albedo(wo.y, self.alpha);
+		let base = luminance(self.base) * self.base_weight(wo);
+		if base > 0.0 {
+			sheen / (sheen + base)
+		} else {
+			1.0
+		}
+	}
+}
+
+impl Bsdf for SheenBsdf {
+	fn eval(&self, wo: Vector3, wi: Vector3) -> Vector3 {
+		if !same_hemisphere(wo, wi) {
+			return Vector3::new(0.0, 0.0, 0.0);
+		}
+		let (wo, wi) = (upper_hemisphere(wo), upper_hemisphere(wi));
+		let base = self.base(wo, wi);
+		let wh = wo + wi;
+		if wh.magnitude2() == 0.0 {
+			return base;
+		}
+		let wh = wh.normalize();
+
+		let visibility = 1.0 / (4.0 * (wo.y + wi.y - wo.y * wi.y));
+		self.color * (self.d(wh) * visibility) + base
+	}
+
+	fn pdf(&self, wo: Vector3, wi: Vector3) -> f64 {
+		if !same_hemisphere(wo, wi) {
+			return 0.0;
+		}
+		let (wo, wi) = (upper_hemisphere(wo), upper_hemisphere(wi));
+		let p = self.sheen_probability(wo);
+		let base = (1.0 - p) * sampling::cosine_hemisphere_pdf(wi.y);
+		let wh = wo + wi;
+		if wh.magnitude2() == 0.0 {
+			return base;
+		}
+		let wh = wh.normalize();
+
+		// Sampled normals facing away from wo are mirrored about the normal, adding to the density
+		// of the normals they are mirrored onto
+		let mirrored = Vector3::new(-wh.x, wh.y, -wh.z);
+		let pdf_wh = if mirrored.dot(wo) < 0.0 { 2.0 } else { 1.0 } * self.d(wh) * wh.y;
+		p * pdf_wh / (4.0 * wo.dot(wh)) + base
+	}
+
+	fn sample(&self, wo: Vector3, u: Vector2, uc: f64) -> Option<BsdfSample> {
+		let o = upper_hemisphere(wo);
+		let mut wi = if uc < self.sheen_probability(o) {
+			// Sample the fiber normal proportional to its projected area, sin(theta) = u^(1/(2 + 1/alpha))
+			let sin_theta = u.x.powf(1.0 / (2.0 + 1.0 / self.alpha));
+			let cos_theta = (1.0 - sin_theta * sin_theta).max(0.0).sqrt();
+			let phi = 2.0 * PI * u.y;
+			let mut wh = Vector3::new(sin_theta * phi.cos(), cos_theta, sin_theta * phi.sin());
+			if wh.dot(o) < 0.0 {
+				wh.x = -wh.x;
+				wh.z = -wh.z;
+			}
+			reflect(o, wh)
+		} else {
+			sampling::cosine_sample_hemisphere(u)
+		};
+		if wi.y <= 0.0 {
+			return None;
+		}
+		if wo.y < 0.0 {
+			wi.y = -wi.y;
+		}
+
+		BsdfSample::evaluate(self, wo, wi)
+	}
+}
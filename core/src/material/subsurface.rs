@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+
+use super::{dielectric::MicrofacetDielectric, microfacet::Ggx};
+use crate::math::{create_coordinate_system_of_n, prelude::*};
+
+fn default_ior() -> f64 {
+	1.4
+}
+
+///
+/// A translucent material scattering light inside a closed object, like skin, marble, wax or milk
+///
+/// The surface is a dielectric boundary. Light refracted into the object does a random walk
+/// through the volume until it leaves through the boundary again, which the integrator traces
+/// against the object's own geometry. Objects using it have to be closed.
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Subsurface {
+	/// The color of the object after all scattering, per channel
+	pub albedo: Vector3,
+	/// Average distance light travels between scattering events, per channel, in scene units
+	pub mean_free_path: Vector3,
+	#[serde(default = "default_ior")]
+	pub ior: f64,
+	/// Roughness of the boundary
+	#[serde(default)]
+	pub roughness: f64,
+	/// Henyey-Greenstein asymmetry of the scattering inside, from -1 (backwards) to 1 (forwards)
+	#[serde(default)]
+	pub anisotropy: f64,
+}
+
+impl Subsurface {
+	/// The dielectric boundary of the object
+	pub fn boundary(&self) -> MicrofacetDielectric {
+		MicrofacetDielectric::compensated(Ggx::from_roughness(self.roughness), self.ior, Vector3::new(1.0, 1.0, 1.0))
+	}
+
+	///
+	/// Scattering and extinction coefficients of the interior, per channel
+	///
+	/// The albedo is mapped to the single scattering albedo that produces it after many bounces,
+	/// with the fit from Chiang et al. 2016, "Practical and Controllable Subsurface Scattering
+	/// for Production Path Tracing".
+	///
+	pub fn coefficients(&self) -> (Vector3, Vector3) {
+		let single_scattering = |albedo: f64| {
+			let a = albedo.clamp(0.0, 0.999);
+			let s = 4.09712 + 4.20863 * a - (9.59217 + 41.6808 * a + 17.7126 * a * a).sqrt();
+			1.0 - s * s
+		};
+		let extinction = |mean_free_path: f64| 1.0 / mean_free_path.max(1e-6);
+
+		let sigma_t = Vector3::new(
+			extinction(self.mean_free_path.x),
+			extinction(self.mean_free_path.y),
+			extinction(self.mean_free_path.z),
+		);
+		let albedo = Vector3::new(
+			single_scattering(self.albedo.x),
+			single_scattering(self.albedo.y),
+			single_scattering(self.albedo.z),
+		);
+
+		(albedo.mul_element_wise(sigma_t), sigma_t)
+	}
+
+	///
+	/// Samples a new direction of travel after scattering off a particle
+	///
+	/// Importance samples the Henyey-Greenstein phase function exactly, so the sample carries
+	/// no weight.
+	///
+	pub fn sample_phase(&self, direction: Vector3, u: Vector2) -> Vector3 {
+		let g = self.anisotropy.clamp(-0.99, 0.99);
+		let cos_theta = if g.abs() < 1e-3 {
+			1.0 - 2.0 * u.x
+		} else {
+			let s = (1.0 - g * g) / (1.0 + g - 2.0 * g * u.x);
+			(1.0 + g * g - s * s) / (2.0 * g)
+		};
+		let sin_theta = (1.0 - cos_theta * cos_theta).max(0.0).sqrt();
+		let phi = 2.0 * PI * u.y;
+
+		let (t, b) = create_coordinate_system_of_n(direction);
+		t * (sin_theta * phi.cos()) + b * (sin_theta * phi.sin()) + direction * cos_theta
+	}
+}
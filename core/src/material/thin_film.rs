@@ -0,0 +1,180 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	geometry::SurfaceProperties,
+	math::prelude::*,
+	texture::{self, Texture},
+};
+
+/// Wavelength bands in nanometers averaged into the red, green and blue channels
+const CHANNEL_BANDS: [(f64, f64); 3] = [(600.0, 700.0), (500.0, 600.0), (400.0, 500.0)];
+/// Wavelengths evaluated per channel, enough to keep thick films from aliasing into noise
+const SAMPLES_PER_BAND: usize = 8;
+
+fn default_ior() -> f64 {
+	1.33
+}
+
+///
+/// A thin transparent film on top of a specular surface, like a soap bubble or an oil slick
+///
+/// Light reflecting off the top and the bottom of the film interferes, shifting the color of
+/// the reflection with the thickness of the film and the viewing angle.
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThinFilm {
+	/// Thickness of the film in nanometers, visible iridescence needs roughly 100 to 1000
+	pub thickness: f64,
+	#[serde(default = "default_ior")]
+	pub ior: f64,
+	/// Scales the thickness across the surface, for films of varying thickness
+	#[serde(default, deserialize_with = "texture::optional_data")]
+	pub thickness_texture: Option<Texture>,
+}
+
+impl ThinFilm {
+	pub fn thickness_at(&self, surface: &SurfaceProperties) -> f64 {
+		match &self.thickness_texture {
+			Some(texture) => self.thickness * texture.sample_scalar(surface.uv),
+			None => self.thickness,
+		}
+	}
+}
+
+///
+/// Reflectance of a surface with the complex index of refraction eta + ik below a film,
+/// averaged over the wavelengths of each channel
+///
+/// Sums the reflections between both interfaces of the film (Airy summation) for each
+/// polarization. A film of thickness zero reduces to the Fresnel reflectance of the base.
+///
+pub fn reflectance(cos_theta_i: f64, thickness: f64, film_ior: f64, eta: Vector3, k: Vector3) -> Vector3 {
+	let cos_i = cos_theta_i.abs().min(1.0);
+	let mut channels = [0.0; 3];
+
+	for (channel, &(start, end)) in CHANNEL_BANDS.iter().enumerate() {
+		let base = Complex::new(eta[channel], k[channel]);
+		channels[channel] = (0..SAMPLES_PER_BAND)
+			.map(|i| {
+				let wavelength = start + (end - start) * (i as f64 + 0.5) / SAMPLES_PER_BAND as f64;
+				airy_reflectance(cos_i, thickness, film_ior, base, wavelength)
+			})
+			.sum::<f64>()
+			/ SAMPLES_PER_BAND as f64;
+	}
+
+	Vector3::new(channels[0], channels[1], channels[2])
+}
+
+fn airy_reflectance(cos_i: f64, thickness: f64, film_ior: f64, base: Complex, wavelength: f64) -> f64 {
+	let outside = Complex::new(1.0, 0.0);
+	let film = Complex::new(film_ior, 0.0);
+	let sin2_i = 1.0 - cos_i * cos_i;
+
+	// Snell's law keeps n sin(theta) constant through all layers
+	let cos_in = |n: Complex| (Complex::new(1.0, 0.0) - Complex::new(sin2_i, 0.0) / (n * n)).sqrt();
+	let cos_outside = Complex::new(cos_i, 0.0);
+	let cos_film = cos_in(film);
+	let cos_base = cos_in(base);
+
+	// Phase difference between light reflected at the top and the bottom of the film
+	let phase = film * cos_film * (4.0 * PI * thickness / wavelength);
+	let shift = (Complex::new(0.0, 1.0) * phase).exp();
+
+	let airy = |r12: Complex, r23: Complex| {
+		let r = (r12 + r23 * shift) / (Complex::new(1.0, 0.0) + r12 * r23 * shift);
+		r.norm_sqr()
+	};
+
+	let s = airy(
+		fresnel_s(outside, cos_outside, film, cos_film),
+		fresnel_s(film, cos_film, base, cos_base),
+	);
+	let p = airy(
+		fresnel_p(outside, cos_outside, film, cos_film),
+		fresnel_p(film, cos_film, base, cos_base),
+	);
+
+	((s + p) / 2.0).clamp(0.0, 1.0)
+}
+
+/// Amplitude reflection coefficient for light polarized perpendicular to the plane of incidence
+fn fresnel_s(n_i: Complex, cos_i: Complex, n_t: Complex, cos_t: Complex) -> Complex {
+	(n_i * cos_i - n_t * cos_t) / (n_i * cos_i + n_t * cos_t)
+}
+
+/// Amplitude reflection coefficient for light polarized parallel to the plane of incidence
+fn fresnel_p(n_i: Complex, cos_i: Complex, n_t: Complex, cos_t: Complex) -> Complex {
+	(n_t * cos_i - n_i * cos_t) / (n_t * cos_i + n_i * cos_t)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Complex {
+	re: f64,
+	im: f64,
+}
+
+impl Complex {
+	fn new(re: f64, im: f64) -> Complex {
+		Complex { re, im }
+	}
+
+	fn norm_sqr(self) -> f64 {
+		self.re * self.re + self.im * self.im
+	}
+
+	/// Principal square root, with a non-negative real part
+	fn sqrt(self) -> Complex {
+		let norm = self.norm_sqr().sqrt();
+		let re = ((norm + self.re) / 2.0).max(0.0).sqrt();
+		let im = ((norm - self.re) / 2.0).max(0.0).sqrt();
+		Complex::new(re, if self.im < 0.0 { -im } else { im })
+	}
+
+	fn exp(self) -> Complex {
+		let magnitude = self.re.exp();
+		Complex::new(magnitude * self.im.cos(), magnitude * self.im.sin())
+	}
+}
+
+impl std::ops::Add for Complex {
+	type Output = Complex;
+	fn add(self, other: Complex) -> Complex {
+		Complex::new(self.re + other.re, self.im + other.im)
+	}
+}
+
+impl std::ops::Sub for Complex {
+	type Output = Complex;
+	fn sub(self, other: Complex) -> Complex {
+		Complex::new(self.re - other.re, self.im - other.im)
+	}
+}
+
+impl std::ops::Mul for Complex {
+	type Output = Complex;
+	fn mul(self, other: Complex) -> Complex {
+		Complex::new(
+			self.re * other.re - self.im * other.im,
+			self.re * other.im + self.im * other.re,
+		)
+	}
+}
+
+impl std::ops::Mul<f64> for Complex {
+	type Output = Complex;
+	fn mul(self, scale: f64) -> Complex {
+		Complex::new(self.re * scale, self.im * scale)
+	}
+}
+
+impl std::ops::Div for Complex {
+	type Output = Complex;
+	fn div(self, other: Complex) -> Complex {
+		let denominator = other.norm_sqr();
+		Complex::new(
+			(self.re * other.re + self.im * other.im) / denominator,
+			(self.im * other.re - self.re * other.im) / denominator,
+		)
+	}
+}
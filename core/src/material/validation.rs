@@ -0,0 +1,707 @@
+//!
+//! Statistical checks for BSDFs
+//!
+//! Bugs in importance sampling rarely show up as obviously wrong images, a pdf that is off by a
+//! constant or misses part of the lobe just makes the render converge to the wrong brightness.
+//! `Validation::run` checks a BSDF for:
+//!
+//! - non-negative, finite values and densities
+//! - samples whose value and density agree with `eval` and `pdf`
+//! - energy conservation, by estimating its albedo in a white furnace
+//! - Helmholtz reciprocity
+//! - sampled directions following `pdf`, with a chi-square goodness of fit test
+//!
+
+use std::fmt;
+
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+use super::bsdf::Bsdf;
+use crate::{math::prelude::*, sampling};
+
+/// Resolution of the chi-square histogram in cos(theta) and phi, cells are equal in solid angle
+const HISTOGRAM_COS_RESOLUTION: usize = 16;
+const HISTOGRAM_PHI_RESOLUTION: usize = 32;
+/// The pdf is integrated over every cell with adaptive Simpson quadrature, so sharp lobes are resolved
+const INTEGRATION_TOLERANCE: f64 = 1e-6;
+const INTEGRATION_DEPTH: usize = 6;
+/// Cells expecting fewer samples are pooled, the chi-square distribution doesn't hold for them
+const MIN_EXPECTED_FREQUENCY: f64 = 5.0;
+
+#[derive(Debug, Clone)]
+pub struct Validation {
+	/// Number of directions towards the viewer that are tested, spread over both hemispheres
+	pub directions: usize,
+	/// Samples per direction for the furnace and chi-square tests
+	pub samples: usize,
+	/// Index of refraction of the inside relative to the outside, for BSDFs that transmit light
+	/// Transmission scales radiance by 1/eta^2, this undoes it for the energy and reciprocity checks
+	pub eta: Option<f64>,
+	/// BSDFs that knowingly give up reciprocity, e.g. for energy compensation, can skip the check
+	pub reciprocal: bool,
+	/// Albedo allowed above one, on top of the statistical error of the estimate
+	pub energy_tolerance: f64,
+	/// Probability of a correct BSDF failing the chi-square test of any direction
+	pub significance: f64,
+	pub seed: u64,
+}
+
+impl Default for Validation {
+	fn default() -> Self {
+		Validation {
+			directions: 8,
+			samples: 100_000,
+			eta: None,
+			reciprocal: true,
+			energy_tolerance: 0.01,
+			significance: 0.01,
+			seed: 7,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+pub enum Failure {
+	/// eval or pdf returned a negative, infinite or NaN value
+	InvalidValue { wo: Vector3, wi: Vector3, f: Vector3, pdf: f64 },
+	/// A sample's value or density differs from `eval` or `pdf` in the same direction
+	InconsistentSample { wo: Vector3, wi: Vector3, sampled: (Vector3, f64), evaluated: (Vector3, f64) },
+	/// The BSDF reflects more light than it receives
+	EnergyGain { wo: Vector3, albedo: Vector3 },
+	/// f(wo, wi) and f(wi, wo) differ
+	NotReciprocal { wo: Vector3, wi: Vector3, forward: Vector3, backward: Vector3 },
+	/// The distribution of sampled directions doesn't match the pdf
+	ChiSquare { wo: Vector3, statistic: f64, degrees_of_freedom: usize, p_value: f64 },
+}
+
+impl fmt::Display for Failure {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Failure::InvalidValue { wo, wi, f: value, pdf } => {
+				write!(f, "invalid value {:?} or pdf {} for wo {:?}, wi {:?}", value, pdf, wo, wi)
+			}
+			Failure::InconsistentSample { wo, wi, sampled, evaluated } => write!(
+				f,
+				"sample (f {:?}, pdf {}) doesn't match eval/pdf (f {:?}, pdf {}) for wo {:?}, wi {:?}",
+				sampled.0, sampled.1, evaluated.0, evaluated.1, wo, wi
+			),
+			Failure::EnergyGain { wo, albedo } => write!(f, "albedo {:?} above one for wo {:?}", albedo, wo),
+			Failure::NotReciprocal { wo, wi, forward, backward } => {
+				write!(f, "f(wo, wi) = {:?} but f(wi, wo) = {:?} for wo {:?}, wi {:?}", forward, backward, wo, wi)
+			}
+			Failure::ChiSquare {
+				wo,
+				statistic,
+				degrees_of_freedom,
+				p_value,
+			} => write!(
+				f,
+				"sampled directions don't follow the pdf for wo {:?}: chi-square {} with {} degrees of freedom, p-value {}",
+				wo, statistic, degrees_of_freedom, p_value
+			),
+		}
+	}
+}
+
+impl std::error::Error for Failure {}
+
+impl Validation {
+	pub fn run(&self, bsdf: &dyn Bsdf) -> Result<(), Failure> {
+		let mut rng = SmallRng::seed_from_u64(self.seed);
+		// Correct the significance for testing several directions (Šidák)
+		let significance = 1.0 - (1.0 - self.significance).powf(1.0 / self.directions as f64);
+
+		for wo in self.outgoing_directions() {
+			self.check_values(bsdf, wo, &mut rng)?;
+			if self.reciprocal {
+				self.check_reciprocity(bsdf, wo, &mut rng)?;
+			}
+
+			let (albedo, error) = self.albedo(bsdf, wo, &mut rng)?;
+			let limit = 1.0 + self.energy_tolerance + 4.0 * error;
+			if albedo.x > limit || albedo.y > limit || albedo.z > limit {
+				return Err(Failure::EnergyGain { wo, albedo });
+			}
+
+			self.check_distribution(bsdf, wo, significance, &mut rng)?;
+		}
+
+		Ok(())
+	}
+
+	/// Directions evenly spaced in cos(theta) from grazing to normal, alternating sides
+	pub fn outgoing_directions(&self) -> Vec<Vector3> {
+		let per_side = self.directions.div_ceil(2);
+		(0..self.directions)
+			.map(|i| {
+				let cos = if per_side > 1 {
+					0.05 + 0.9 * (i / 2) as f64 / (per_side - 1) as f64
+				} else {
+					0.5
+				};
+				let cos = if i % 2 == 0 { cos } else { -cos };
+				let phi = 0.7 * i as f64;
+				let sin = (1.0 - cos * cos).sqrt();
+				Vector3::new(sin * phi.cos(), cos, sin * phi.sin())
+			})
+			.collect()
+	}
+
+	/// Relative index of refraction of the side a direction lies on
+	fn side_eta(&self, w: Vector3) -> f64 {
+		match self.eta {
+			Some(eta) if w.y < 0.0 => eta,
+			_ => 1.0,
+		}
+	}
+
+	fn check_values(&self, bsdf: &dyn Bsdf, wo: Vector3, rng: &mut SmallRng) -> Result<(), Failure> {
+		let valid = |f: Vector3, pdf: f64| {
+			[f.x, f.y, f.z, pdf].iter().all(|v| v.is_finite() && *v >= 0.0)
+		};
+
+		for _ in 0..self.samples / 10 {
+			let wi = sampling::uniform_sample_sphere(Vector2::new(rng.gen(), rng.gen()));
+			let (f, pdf) = (bsdf.eval(wo, wi), bsdf.pdf(wo, wi));
+			if !valid(f, pdf) {
+				return Err(Failure::InvalidValue { wo, wi, f, pdf });
+			}
+		}
+
+		for _ in 0..self.samples / 10 {
+			let sample = match bsdf.sample(wo, Vector2::new(rng.gen(), rng.gen()), rng.gen()) {
+				Some(sample) => sample,
+				None => continue,
+			};
+			let (f, pdf) = (bsdf.eval(wo, sample.wi), bsdf.pdf(wo, sample.wi));
+			if !valid(sample.f, sample.pdf) || sample.pdf <= 0.0 {
+				return Err(Failure::InvalidValue {
+					wo,
+					wi: sample.wi,
+					f: sample.f,
+					pdf: sample.pdf,
+				});
+			}
+
+			let close = |a: f64, b: f64| (a - b).abs() <= 1e-6 * a.abs().max(b.abs()).max(1.0);
+			if !(close(sample.pdf, pdf) && close(sample.f.x, f.x) && close(sample.f.y, f.y) && close(sample.f.z, f.z)) {
+				return Err(Failure::InconsistentSample {
+					wo,
+					wi: sample.wi,
+					sampled: (sample.f, sample.pdf),
+					evaluated: (f, pdf),
+				});
+			}
+		}
+
+		Ok(())
+	}
+
+	///
+	/// Checks f(wo, wi) / eta_o^2 = f(wi, wo) / eta_i^2
+	///
+	/// For reflection both sides have the same index of refraction and this is plain symmetry.
+	///
+	fn check_reciprocity(&self, bsdf: &dyn Bsdf, wo: Vector3, rng: &mut SmallRng) -> Result<(), Failure> {
+		for _ in 0..self.samples / 10 {
+			let wi = sampling::uniform_sample_sphere(Vector2::new(rng.gen(), rng.gen()));
+			let forward = bsdf.eval(wo, wi) / (self.side_eta(wo) * self.side_eta(wo));
+			let backward = bsdf.eval(wi, wo) / (self.side_eta(wi) * self.side_eta(wi));
+
+			let close = |a: f64, b: f64| (a - b).abs() <= 1e-4 * a.abs().max(b.abs()).max(1e-3);
+			if !(close(forward.x, backward.x) && close(forward.y, backward.y) && close(forward.z, backward.z)) {
+				return Err(Failure::NotReciprocal { wo, wi, forward, backward });
+			}
+		}
+
+		Ok(())
+	}
+
+	///
+	/// Estimates the fraction of light arriving from all directions that is scattered towards wo,
+	/// returning the estimate and its standard error
+	///
+	pub fn albedo(&self, bsdf: &dyn Bsdf, wo: Vector3, rng: &mut SmallRng) -> Result<(Vector3, f64), Failure> {
+		let mut sum = Vector3::new(0.0, 0.0, 0.0);
+		let mut sum_squared = 0.0;
+
+		for _ in 0..self.samples {
+			let sample = match bsdf.sample(wo, Vector2::new(rng.gen(), rng.gen()), rng.gen()) {
+				Some(sample) => sample,
+				None => continue,
+			};
+			// Undo the radiance scaling of transmission, it doesn't change the energy
+			let scale = (self.side_eta(sample.wi) / self.side_eta(wo)).powi(2);
+			let weight = sample.f * (sample.wi.y.abs() / sample.pdf * scale);
+			if !(weight.x.is_finite() && weight.y.is_finite() && weight.z.is_finite()) {
+				return Err(Failure::InvalidValue {
+					wo,
+					wi: sample.wi,
+					f: sample.f,
+					pdf: sample.pdf,
+				});
+			}
+
+			let max = weight.x.max(weight.y).max(weight.z);
+			sum += weight;
+			sum_squared += max * max;
+		}
+
+		let n = self.samples as f64;
+		let albedo = sum / n;
+		let max = albedo.x.max(albedo.y).max(albedo.z);
+		let variance = (sum_squared / n - max * max).max(0.0);
+
+		Ok((albedo, (variance / n).sqrt()))
+	}
+
+	///
+	/// Compares a histogram of sampled directions over the sphere with the pdf integrated over
+	/// every cell, as in pbrt's BSDF tests
+	///
+	fn check_distribution(&self, bsdf: &dyn Bsdf, wo: Vector3, significance: f64, rng: &mut SmallRng) -> Result<(), Failure> {
+		let cell_count = HISTOGRAM_COS_RESOLUTION * HISTOGRAM_PHI_RESOLUTION;
+		let cell = |w: Vector3| {
+			let c = ((w.y + 1.0) / 2.0 * HISTOGRAM_COS_RESOLUTION as f64) as usize;
+			let phi = w.z.atan2(w.x).rem_euclid(2.0 * PI);
+			let p = (phi / (2.0 * PI) * HISTOGRAM_PHI_RESOLUTION as f64) as usize;
+			c.min(HISTOGRAM_COS_RESOLUTION - 1) * HISTOGRAM_PHI_RESOLUTION + p.min(HISTOGRAM_PHI_RESOLUTION - 1)
+		};
+
+		let mut observed = vec![0.0; cell_count];
+		for _ in 0..self.samples {
+			if let Some(sample) = bsdf.sample(wo, Vector2::new(rng.gen(), rng.gen()), rng.gen()) {
+				observed[cell(sample.wi)] += 1.0;
+			}
+		}
+
+		// cos(theta) and phi are an area preserving parametrization of the sphere, so integrating
+		// over them integrates over solid angle
+		let cos_step = 2.0 / HISTOGRAM_COS_RESOLUTION as f64;
+		let phi_step = 2.0 * PI / HISTOGRAM_PHI_RESOLUTION as f64;
+		let pdf = |cos: f64, phi: f64| {
+			let sin = (1.0 - cos * cos).max(0.0).sqrt();
+			bsdf.pdf(wo, Vector3::new(sin * phi.cos(), cos, sin * phi.sin()))
+		};
+		let mut expected = vec![0.0; cell_count];
+		for c in 0..HISTOGRAM_COS_RESOLUTION {
+			for p in 0..HISTOGRAM_PHI_RESOLUTION {
+				let cos = -1.0 + c as f64 * cos_step;
+				let phi = p as f64 * phi_step;
+				let probability = adaptive_simpson(
+					&|cos| adaptive_simpson(&|phi| pdf(cos, phi), phi, phi + phi_step, INTEGRATION_TOLERANCE, INTEGRATION_DEPTH),
+					cos,
+					cos + cos_step,
+					INTEGRATION_TOLERANCE,
+					INTEGRATION_DEPTH,
+				);
+				expected[c * HISTOGRAM_PHI_RESOLUTION + p] = probability * self.samples as f64;
+			}
+		}
+
+		// Pool the cells with too few expected samples
+		let mut statistic = 0.0;
+		let mut degrees_of_freedom = 0;
+		let (mut pooled_observed, mut pooled_expected) = (0.0, 0.0);
+		for (o, e) in observed.iter().zip(expected.iter()) {
+			if *e < MIN_EXPECTED_FREQUENCY {
+				pooled_observed += o;
+				pooled_expected += e;
+			} else {
+				statistic += (o - e) * (o - e) / e;
+				degrees_of_freedom += 1;
+			}
+		}
+		if pooled_expected > 0.0 || pooled_observed > 0.0 {
+			// Samples in cells the pdf considers (nearly) impossible count as a failure, unless there are only a few
+			let e = pooled_expected.max(MIN_EXPECTED_FREQUENCY);
+			statistic += (pooled_observed - e) * (pooled_observed - e) / e;
+			degrees_of_freedom += 1;
+		}
+		if degrees_of_freedom < 2 {
+			return Ok(());
+		}
+		let degrees_of_freedom = degrees_of_freedom - 1;
+
+		let p_value = chi_square_p_value(statistic, degrees_of_freedom);
+		if p_value < significance {
+			return Err(Failure::ChiSquare {
+				wo,
+				statistic,
+				degrees_of_freedom,
+				p_value,
+			});
+		}
+
+		Ok(())
+	}
+}
+
+/// An interval with the integrand at its ends and midpoint, and Simpson's rule over it
+#[derive(Clone, Copy)]
+struct Interval {
+	a: f64,
+	b: f64,
+	fa: f64,
+	fm: f64,
+	fb: f64,
+	whole: f64,
+}
+
+impl Interval {
+	fn new(a: f64, b: f64, fa: f64, fm: f64, fb: f64) -> Interval {
+		let whole = (b - a) / 6.0 * (fa + 4.0 * fm + fb);
+		Interval { a, b, fa, fm, fb, whole }
+	}
+}
+
+/// Integrates f over [a, b], refining intervals until Simpson's rule converges
+fn adaptive_simpson(f: &dyn Fn(f64) -> f64, a: f64, b: f64, tolerance: f64, depth: usize) -> f64 {
+	fn refine(f: &dyn Fn(f64) -> f64, interval: Interval, tolerance: f64, depth: usize) -> f64 {
+		let Interval { a, b, fa, fm, fb, whole } = interval;
+		let m = (a + b) / 2.0;
+		let left = Interval::new(a, m, fa, f((a + m) / 2.0), fm);
+		let right = Interval::new(m, b, fm, f((m + b) / 2.0), fb);
+		let delta = left.whole + right.whole - whole;
+
+		if depth == 0 || delta.abs() <= 15.0 * tolerance {
+			return left.whole + right.whole + delta / 15.0;
+		}
+		refine(f, left, tolerance / 2.0, depth - 1) + refine(f, right, tolerance / 2.0, depth - 1)
+	}
+
+	refine(f, Interval::new(a, b, f(a), f((a + b) / 2.0), f(b)), tolerance, depth)
+}
+
+/// Probability of a chi-square distributed variable exceeding x
+fn chi_square_p_value(x: f64, degrees_of_freedom: usize) -> f64 {
+	regularized_gamma_q(degrees_of_freedom as f64 / 2.0, x / 2.0)
+}
+
+/// The regularized upper incomplete gamma function Q(a, x), from Numerical Recipes
+fn regularized_gamma_q(a: f64, x: f64) -> f64 {
+	if x <= 0.0 {
+		return 1.0;
+	}
+	let prefactor = (-x + a * x.ln() - ln_gamma(a)).exp();
+
+	if x < a + 1.0 {
+		// Series for the lower function P
+		let mut term = 1.0 / a;
+		let mut sum = term;
+		for n in 1..1000 {
+			term *= x / (a + n as f64);
+			sum += term;
+			if term < sum * 1e-15 {
+				break;
+			}
+		}
+		(1.0 - sum * prefactor).max(0.0)
+	} else {
+		// Continued fraction for Q, with the modified Lentz method
+		let tiny = 1e-300;
+		let mut b = x + 1.0 - a;
+		let mut c = 1.0 / tiny;
+		let mut d = 1.0 / b;
+		let mut h = d;
+		for i in 1..1000 {
+			let an = -(i as f64) * (i as f64 - a);
+			b += 2.0;
+			d = an * d + b;
+			if d.abs() < tiny {
+				d = tiny;
+			}
+			c = b + an / c;
+			if c.abs() < tiny {
+				c = tiny;
+			}
+			d = 1.0 / d;
+			let delta = d * c;
+			h *= delta;
+			if (delta - 1.0).abs() < 1e-15 {
+				break;
+			}
+		}
+		prefactor * h
+	}
+}
+
+/// Lanczos approximation of ln(Γ(x)) for x > 0
+fn ln_gamma(x: f64) -> f64 {
+	const COEFFICIENTS: [f64; 6] = [
+		76.18009172947146,
+		-86.50532032941677,
+		24.01409824083091,
+		-1.231739572450155,
+		0.1208650973866179e-2,
+		-0.5395239384953e-5,
+	];
+
+	let tmp = x + 5.5;
+	let tmp = tmp - (x + 0.5) * tmp.ln();
+	let series = COEFFICIENTS
+		.iter()
+		.enumerate()
+		.fold(1.000000000190015, |sum, (i, c)| sum + c / (x + 1.0 + i as f64));
+
+	-tmp + (2.5066282746310005 * series / x).ln()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		geometry::SurfaceProperties,
+		material::{
+			bsdf::{Lambertian, MicrofacetReflection},
+			dielectric::MicrofacetDielectric,
+			fresnel::Fresnel,
+			microfacet::Ggx,
+			MeasuredBrdf,
+		},
+		project::MaterialDefinition,
+		Material,
+	};
+
+	fn surface() -> SurfaceProperties {
+		SurfaceProperties {
+			normal: Vector3::new(0.0, 1.0, 0.0),
+			geometric_normal: Vector3::new(0.0, 1.0, 0.0),
+			uv: Vector2::new(0.3, 0.6),
+			tangent: Vector3::new(1.0, 0.0, 0.0),
+			handedness: 1.0,
+		}
+	}
+
+	/// Fewer samples than `Validation::default`, which keeps the suite fast and still fails `Broken`
+	fn validation() -> Validation {
+		Validation {
+			samples: 20_000,
+			..Validation::default()
+		}
+	}
+
+	/// A material as written in a project file
+	fn material(json: &str) -> Material {
+		serde_json::from_str::<MaterialDefinition>(json).unwrap().build().unwrap()
+	}
+
+	fn assert_valid(bsdf: &dyn Bsdf, validation: &Validation) {
+		if let Err(failure) = validation.run(bsdf) {
+			panic!("{}", failure);
+		}
+	}
+
+	fn assert_material_valid(json: &str, validation: &Validation) {
+		let bsdf = material(json).bsdf(&surface()).unwrap();
+		if let Err(failure) = validation.run(bsdf.as_ref()) {
+			panic!("{}: {}", json, failure);
+		}
+	}
+
+	/// Albedo of a BSDF that should reflect all light
+	fn assert_white(bsdf: &dyn Bsdf, tolerance: f64) {
+		let validation = Validation::default();
+		let mut rng = SmallRng::seed_from_u64(validation.seed);
+		for wo in validation.outgoing_directions() {
+			let (albedo, _) = validation.albedo(bsdf, wo, &mut rng).unwrap();
+			assert!((albedo.x - 1.0).abs() < tolerance, "albedo {:?} for wo {:?}", albedo, wo);
+		}
+	}
+
+	#[test]
+	fn detects_wrong_pdf() {
+		/// Cosine sampling claiming a uniform density
+		struct Broken;
+		impl Bsdf for Broken {
+			fn eval(&self, _wo: Vector3, _wi: Vector3) -> Vector3 {
+				Vector3::new(0.5, 0.5, 0.5) / PI
+			}
+			fn pdf(&self, _wo: Vector3, _wi: Vector3) -> f64 {
+				1.0 / (2.0 * PI)
+			}
+			fn sample(&self, wo: Vector3, u: Vector2, _uc: f64) -> Option<crate::material::BsdfSample> {
+				let wi = sampling::cosine_sample_hemisphere(u);
+				let wi = if wo.y < 0.0 { -wi } else { wi };
+				crate::material::BsdfSample::evaluate(self, wo, wi)
+			}
+		}
+
+		match validation().run(&Broken) {
+			Err(Failure::ChiSquare { .. }) => (),
+			result => panic!("expected a chi-square failure, got {:?}", result),
+		}
+	}
+
+	#[test]
+	fn detects_energy_gain() {
+		match validation().run(&Lambertian(Vector3::new(1.2, 1.0, 1.0))) {
+			Err(Failure::EnergyGain { .. }) => (),
+			result => panic!("expected an energy gain, got {:?}", result),
+		}
+	}
+
+	#[test]
+	fn chi_square_p_values() {
+		// Reference values of the chi-square distribution
+		assert!((chi_square_p_value(3.84, 1) - 0.05).abs() < 1e-3);
+		assert!((chi_square_p_value(18.31, 10) - 0.05).abs() < 1e-3);
+		assert!((chi_square_p_value(124.34, 100) - 0.05).abs() < 1e-3);
+	}
+
+	#[test]
+	fn lambertian() {
+		let white = Lambertian(Vector3::new(1.0, 1.0, 1.0));
+		assert_valid(&white, &validation());
+		assert_white(&white, 0.01);
+	}
+
+	#[test]
+	fn diffuse_and_metal() {
+		for roughness in &[0.1, 0.5, 1.0] {
+			assert_material_valid(&format!(r#"{{ "Diffuse": [[1, 1, 1], {}] }}"#, roughness), &validation());
+			assert_material_valid(&format!(r#"{{ "Metal": [[0.9, 0.6, 0.3], {}] }}"#, roughness), &validation());
+		}
+	}
+
+	#[test]
+	fn microfacet_reflection() {
+		for roughness in &[0.3, 0.6, 1.0] {
+			let white = Fresnel::Schlick(Vector3::new(1.0, 1.0, 1.0));
+			assert_valid(&MicrofacetReflection::new(Ggx::from_roughness(*roughness), white), &validation());
+
+			let compensated = MicrofacetReflection::compensated(Ggx::from_roughness(*roughness), white);
+			assert_valid(&compensated, &validation());
+			assert_white(&compensated, 0.03);
+		}
+	}
+
+	#[test]
+	fn conductor() {
+		assert_material_valid(r#"{ "Conductor": { "ior": "Gold", "roughness": 0.4 } }"#, &validation());
+		assert_material_valid(
+			r#"{ "Conductor": { "ior": "Aluminium", "roughness": 0.5, "anisotropic": 0.8, "anisotropic_rotation": 0.1 } }"#,
+			&validation(),
+		);
+		assert_material_valid(
+			r#"{ "Conductor": { "ior": "Chrome", "roughness": 0.3, "thin_film": { "thickness": 400 } } }"#,
+			&validation(),
+		);
+	}
+
+	#[test]
+	fn dielectric() {
+		for roughness in &[0.3, 0.7] {
+			let single = MicrofacetDielectric::new(Ggx::from_roughness(*roughness), 1.5, Vector3::new(1.0, 1.0, 1.0));
+			assert_valid(&single, &Validation { eta: Some(1.5), ..validation() });
+
+			// Scaling by the albedo towards the viewer breaks reciprocity, the uncompensated lobe above
+			// checks the eta^2 relation instead
+			let compensated = MicrofacetDielectric::compensated(Ggx::from_roughness(*roughness), 1.5, Vector3::new(1.0, 1.0, 1.0));
+			assert_valid(&compensated, &Validation { eta: Some(1.5), reciprocal: false, ..validation() });
+		}
+	}
+
+	#[test]
+	fn principled() {
+		let validation = validation();
+		assert_material_valid(r#"{ "Principled": {} }"#, &validation);
+		assert_material_valid(r#"{ "Principled": { "metallic": 1.0, "roughness": 0.3 } }"#, &validation);
+		assert_material_valid(
+			r#"{ "Principled": { "base_color": [1, 1, 1], "roughness": 0.8, "sheen": 1.0, "subsurface": 0.5 } }"#,
+			&validation,
+		);
+		assert_material_valid(
+			r#"{ "Principled": { "roughness": 0.4, "clearcoat": 1.0, "clearcoat_roughness": 0.2 } }"#,
+			&validation,
+		);
+		assert_material_valid(
+			r#"{ "Principled": { "roughness": 0.4, "anisotropic": 0.7, "anisotropic_rotation": 0.3 } }"#,
+			&validation,
+		);
+		// Transmission uses the compensated dielectric, which is only reciprocal without compensation,
+		// see `dielectric`
+		assert_material_valid(
+			r#"{ "Principled": { "transmission": 1.0, "roughness": 0.5, "ior": 1.45 } }"#,
+			&Validation { eta: Some(1.45), reciprocal: false, ..validation },
+		);
+	}
+
+	#[test]
+	fn layered() {
+		assert_material_valid(
+			r#"{ "Layered": [{ "Diffuse": [[1, 1, 1], 1.0] }, { "roughness": 0.2 }] }"#,
+			&validation(),
+		);
+		assert_material_valid(
+			// A smooth coat concentrates the light in a near-specular lobe the chi-square test can't
+			// resolve, so the coat is given some roughness
+			r#"{ "Layered": [{ "Conductor": { "ior": "Copper", "roughness": 0.5 } }, { "ior": 1.6, "roughness": 0.3, "tint": [0.8, 0.3, 0.3] }] }"#,
+			&validation(),
+		);
+	}
+
+	#[test]
+	fn subsurface_boundary() {
+		// The boundary is a compensated dielectric as well, so reciprocity is skipped like in `dielectric`
+		assert_material_valid(
+			r#"{ "Subsurface": { "albedo": [0.8, 0.6, 0.5], "mean_free_path": [1, 0.5, 0.3], "roughness": 0.4 } }"#,
+			&Validation { eta: Some(1.4), reciprocal: false, ..validation() },
+		);
+	}
+
+	#[test]
+	fn cloth() {
+		for roughness in &[0.3, 0.7, 1.0] {
+			assert_material_valid(
+				&format!(r#"{{ "Cloth": {{ "sheen_color": [1, 1, 1], "sheen_roughness": {} }} }}"#, roughness),
+				&validation(),
+			);
+			assert_material_valid(
+				&format!(
+					r#"{{ "Cloth": {{ "sheen_color": [0.9, 0.8, 1], "sheen_roughness": {}, "base_color": [1, 1, 1] }} }}"#,
+					roughness
+				),
+				&validation(),
+			);
+		}
+	}
+
+	#[test]
+	fn graph() {
+		assert_material_valid(
+			r#"{
+				"nodes": {
+					"paint": { "Diffuse": { "color": [0.8, 0.2, 0.2] } },
+					"metal": { "Conductor": { "ior": "Silver", "roughness": 0.4 } },
+					"mix": { "MixShader": { "a": "paint", "b": "metal", "factor": 0.3 } }
+				},
+				"output": "mix"
+			}"#,
+			&validation(),
+		);
+	}
+
+	#[test]
+	fn measured() {
+		// A Lambertian BRDF with an albedo of one half, in the MERL format
+		const SAMPLES: usize = 90 * 90 * 180;
+		const CHANNEL_SCALE: [f64; 3] = [1.0 / 1500.0, 1.15 / 1500.0, 1.66 / 1500.0];
+		let mut bytes = Vec::with_capacity(12 + SAMPLES * 3 * 8);
+		for dimension in &[90i32, 90, 180] {
+			bytes.extend_from_slice(&dimension.to_le_bytes());
+		}
+		for scale in CHANNEL_SCALE.iter() {
+			let value = 0.5 / PI / scale;
+			for _ in 0..SAMPLES {
+				bytes.extend_from_slice(&value.to_le_bytes());
+			}
+		}
+		let path = std::env::temp_dir().join("raymond_validation_lambert.binary");
+		std::fs::write(&path, bytes).unwrap();
+
+		let brdf = MeasuredBrdf::load(path.clone()).unwrap();
+		std::fs::remove_file(path).ok();
+		assert_valid(&brdf, &validation());
+	}
+}
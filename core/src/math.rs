@@ -30,3 +30,20 @@ pub mod prelude {
 	pub use super::consts::*;
 }
 
+
+/// Builds an orthonormal basis around n
+/// Returns the two vectors orthogonal to n
+pub fn create_coordinate_system_of_n(n: types::Vector3) -> (types::Vector3, types::Vector3) {
+	let sign = if n.z > 0.0 { 1.0 } else { -1.0 };
+	let a = -1.0 / (sign + n.z);
+	let b = n.x * n.y * a;
+	(
+		types::Vector3::new(1.0 + sign * n.x * n.x * a, sign * b, -sign * n.x),
+		types::Vector3::new(b, sign + n.y * n.y * a, -n.y),
+	)
+}
+
+/// Relative luminance of a linear RGB color (Rec. 709 primaries)
+pub fn luminance(c: types::Vector3) -> types::TFloat {
+	0.2126 * c.x + 0.7152 * c.y + 0.0722 * c.z
+}
@@ -1,58 +1,209 @@
 use crate::scene::{Scene};
-use crate::geometry::{Plane, Sphere, Mesh};
+use crate::geometry::{Plane, Quad, Sphere, Mesh};
 use crate::scene;
 use crate::Material;
+use crate::material::{Emission, EmissionStrength};
+use crate::material::graph::{GraphDescription, GraphError, MaterialGraph};
 use crate::geometry::AccGrid;
+use crate::light::Light;
+use crate::integrator::IntegratorSettings;
 
 use std::sync::Arc;
 use std::path::{Path, PathBuf};
 use std::fs::File;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::{error, fmt};
 
-use serde::{Serialize, Deserialize};
+use serde::{Serialize, Deserialize, de::DeserializeOwned};
+
+thread_local! {
+	/// Directory of the file being read, the assets it refers to are relative to it
+	static ASSET_DIRECTORY: RefCell<PathBuf> = const { RefCell::new(PathBuf::new()) };
+}
+
+/// Where a path to an asset, like a texture, points to while a project or material library is read
+/// Relative paths start at the file's directory, outside of `Project::load` and `MaterialLibrary::load` at the working directory
+pub fn resolve_asset(path: &Path) -> PathBuf {
+	ASSET_DIRECTORY.with(|directory| directory.borrow().join(path))
+}
+
+/// Reads a JSON file, resolving the asset paths in it against its directory
+fn read_json<T: DeserializeOwned>(path: &Path) -> Result<T, Box<dyn error::Error>> {
+	let file = File::open(path)?;
+	let directory = path.parent().unwrap_or_else(|| Path::new("")).to_path_buf();
+
+	let outer = ASSET_DIRECTORY.with(|current| current.replace(directory));
+	let result = serde_json::from_reader(file);
+	ASSET_DIRECTORY.with(|current| current.replace(outer));
+
+	Ok(result?)
+}
+
+/// Reads a path to an asset that's loaded after the file was read, resolved with `resolve_asset`
+fn asset_path<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<PathBuf, D::Error> {
+	PathBuf::deserialize(deserializer).map(|path| resolve_asset(&path))
+}
 
 #[derive(Serialize, Deserialize)]
 pub enum Geometry {
 	Plane(Plane),
 	Sphere(Sphere),
-	Mesh(PathBuf),
+	/// A finite rectangle or parallelogram, the usual shape of area lights
+	Quad(Quad),
+	/// A PLY file, relative to the project file
+	Mesh(#[serde(deserialize_with = "asset_path")] PathBuf),
+}
+
+
+/// A material as written in a project file, either given directly or as a node graph
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MaterialDefinition {
+	Material(Material),
+	Graph(GraphDescription),
 }
 
+impl MaterialDefinition {
+	pub fn build(self) -> Result<Material, GraphError> {
+		match self {
+			MaterialDefinition::Material(material) => Ok(material),
+			MaterialDefinition::Graph(graph) => Ok(Material::Graph(Arc::new(MaterialGraph::compile(&graph)?))),
+		}
+	}
+}
+
+/// The material of an object, either the name of an entry in the material table or a definition of its own
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MaterialReference {
+	Named(String),
+	Inline(MaterialDefinition),
+}
 
 #[derive(Serialize, Deserialize)]
 pub struct Object {
 	geometry: Geometry,
-	material: Material,
+	material: MaterialReference,
 }
 
+///
+/// A file of named materials shared between projects
+///
+/// ```json
+/// { "materials": { "brushed_steel": { "Conductor": { "ior": "Iron", "roughness": 0.3 } } } }
+/// ```
+///
+#[derive(Serialize, Deserialize)]
+pub struct MaterialLibrary {
+	pub materials: HashMap<String, MaterialDefinition>,
+}
+
+impl MaterialLibrary {
+	/// Paths to textures and other assets are relative to the library
+	pub fn load(p: impl AsRef<Path>) -> Result<MaterialLibrary, Box<dyn error::Error>> {
+		read_json(p.as_ref())
+	}
+}
+
+#[derive(Debug)]
+pub enum ProjectError {
+	UnknownMaterial(String),
+	/// An emissive plane with its strength given as power, which spreads out to nothing over an infinite plane
+	PowerOnPlane { object: usize },
+}
+
+impl fmt::Display for ProjectError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			ProjectError::UnknownMaterial(name) => write!(f, "no material named '{}' in the project or its libraries", name),
+			ProjectError::PowerOnPlane { object } => {
+				write!(f, "object {} is an infinite plane, its emission strength has to be given as radiance", object)
+			}
+		}
+	}
+}
+
+impl error::Error for ProjectError {}
+
 #[derive(Serialize, Deserialize)]
 pub struct Project {
+	/// Material libraries, relative to the project file
+	/// Their materials replace the project's own materials of the same name, later libraries take precedence
+	#[serde(default)]
+	pub libraries: Vec<PathBuf>,
+	/// Materials objects can refer to by name
+	#[serde(default)]
+	pub materials: HashMap<String, MaterialDefinition>,
 	pub objects: Vec<Object>,
+	/// Lights that aren't part of the geometry, see `light::Light`
+	#[serde(default)]
+	pub lights: Vec<Light>,
+	/// How the project is rendered, path tracing unless chosen otherwise
+	#[serde(default)]
+	pub integrator: IntegratorSettings,
 }
 
 impl Project {
+	/// Paths to libraries, textures and other assets are relative to the project file
 	pub fn load(p: impl AsRef<Path>) -> Result<Project, Box<dyn std::error::Error>> {
-		let file = File::open(p)?;
-		Ok(serde_json::from_reader(file)?)
+		let mut project: Project = read_json(p.as_ref())?;
+
+		let directory = p.as_ref().parent().unwrap_or_else(|| Path::new(""));
+		for library in project.libraries.iter_mut() {
+			*library = directory.join(&library);
+		}
+
+		Ok(project)
 	}
 
-	pub fn build_scene(self) -> Scene {
+	/// Loads all meshes and material libraries and compiles material graphs
+	pub fn build_scene(self) -> Result<Scene, Box<dyn std::error::Error>> {
 		let mut scene = Scene::new();
 
-		for obj in self.objects {
-			scene.objects.push(scene::Object {
+		let mut definitions = self.materials;
+		for library in self.libraries.iter() {
+			definitions.extend(MaterialLibrary::load(library)?.materials);
+		}
+		let mut materials = HashMap::new();
+		for (name, definition) in definitions {
+			materials.insert(name, definition.build()?);
+		}
+
+		for (index, obj) in self.objects.into_iter().enumerate() {
+			let object = scene::Object {
 				geometry: match obj.geometry {
 					Geometry::Plane(p) => scene::Geometry::Plane(p),
 					Geometry::Sphere(s) => scene::Geometry::Sphere(s),
+					Geometry::Quad(q) => scene::Geometry::Quad(q),
 					Geometry::Mesh(m) => {
 						scene::Geometry::Grid(
 							Arc::new(AccGrid::build_from_mesh(Mesh::load_ply(m)))
 						)
 					}
 				},
-				material: obj.material
-			});
+				material: match obj.material {
+					MaterialReference::Named(name) => materials
+						.get(&name)
+						.cloned()
+						.ok_or(ProjectError::UnknownMaterial(name))?,
+					MaterialReference::Inline(definition) => definition.build()?,
+				},
+			};
+
+			let power = matches!(
+				object.material.base(),
+				Material::Emission(Emission { strength: EmissionStrength::Power(_), .. })
+			);
+			if power && matches!(object.geometry, scene::Geometry::Plane(_)) {
+				return Err(ProjectError::PowerOnPlane { object: index }.into());
+			}
+
+			scene.add(object);
 		}
 
-		scene
+		scene.lights = self.lights;
+
+		Ok(scene)
 	}
 }
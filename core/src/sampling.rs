@@ -0,0 +1,301 @@
+//!
+//! Warping functions, mapping uniform random numbers to directions and points
+//! All hemispheres are oriented along +y, like the local shading frame
+//!
+
+use rand::{rngs::ThreadRng, Rng};
+
+use crate::math::prelude::*;
+
+///
+/// Source of the uniform random numbers in [0, 1) that paths are built from
+///
+/// Each consumer asks for the dimensions it needs in a fixed order, so samplers that
+/// distribute their samples well per dimension can be swapped in.
+///
+pub trait Sampler {
+	fn next_1d(&mut self) -> f64;
+
+	fn next_2d(&mut self) -> Vector2 {
+		let x = self.next_1d();
+		Vector2::new(x, self.next_1d())
+	}
+}
+
+/// Independent random numbers, with nothing distributing them across samples
+pub struct RandomSampler {
+	rng: ThreadRng,
+}
+
+impl RandomSampler {
+	pub fn new() -> RandomSampler {
+		RandomSampler { rng: rand::thread_rng() }
+	}
+}
+
+impl Default for RandomSampler {
+	fn default() -> RandomSampler {
+		RandomSampler::new()
+	}
+}
+
+impl Sampler for RandomSampler {
+	fn next_1d(&mut self) -> f64 {
+		self.rng.gen()
+	}
+}
+
+/// Maps the unit square to the unit disk, preserving relative areas
+pub fn concentric_sample_disk(u: Vector2) -> Vector2 {
+	let offset = u * 2.0 - Vector2::new(1.0, 1.0);
+	if offset.x == 0.0 && offset.y == 0.0 {
+		return Vector2::new(0.0, 0.0);
+	}
+
+	let (r, theta) = if offset.x.abs() > offset.y.abs() {
+		(offset.x, PI / 4.0 * (offset.y / offset.x))
+	} else {
+		(offset.y, PI / 2.0 - PI / 4.0 * (offset.x / offset.y))
+	};
+
+	Vector2::new(r * theta.cos(), r * theta.sin())
+}
+
+pub fn cosine_sample_hemisphere(u: Vector2) -> Vector3 {
+	let d = concentric_sample_disk(u);
+	let y = (1.0 - d.x * d.x - d.y * d.y).max(0.0).sqrt();
+
+	Vector3::new(d.x, y, d.y)
+}
+
+pub fn cosine_hemisphere_pdf(cos_theta: f64) -> f64 {
+	cos_theta.max(0.0) / PI
+}
+
+pub fn uniform_sample_sphere(u: Vector2) -> Vector3 {
+	let y = 1.0 - 2.0 * u.x;
+	let r = (1.0 - y * y).max(0.0).sqrt();
+	let phi = 2.0 * PI * u.y;
+
+	Vector3::new(r * phi.cos(), y, r * phi.sin())
+}
+
+pub fn uniform_sphere_pdf() -> f64 {
+	1.0 / (4.0 * PI)
+}
+
+/// Directions within a cone around +y, uniform in solid angle
+pub fn uniform_sample_cone(u: Vector2, cos_max: f64) -> Vector3 {
+	let y = 1.0 - u.x * (1.0 - cos_max);
+	let r = (1.0 - y * y).max(0.0).sqrt();
+	let phi = 2.0 * PI * u.y;
+
+	Vector3::new(r * phi.cos(), y, r * phi.sin())
+}
+
+pub fn uniform_cone_pdf(cos_max: f64) -> f64 {
+	1.0 / (2.0 * PI * (1.0 - cos_max))
+}
+
+///
+/// Veach's power heuristic with an exponent of two, the multiple importance sampling weight of
+/// a sample taken with density `pdf` when another strategy could have taken it with `other_pdf`
+///
+pub fn power_heuristic(pdf: f64, other_pdf: f64) -> f64 {
+	let (a, b) = (pdf * pdf, other_pdf * other_pdf);
+	if a + b > 0.0 {
+		a / (a + b)
+	} else {
+		0.0
+	}
+}
+
+///
+/// A piecewise constant distribution over [0, 1], sampled by inverting its CDF
+///
+#[derive(Debug, Clone)]
+pub struct Distribution1D {
+	func: Vec<f64>,
+	cdf: Vec<f64>,
+	integral: f64,
+}
+
+impl Distribution1D {
+	/// Values must be non-negative. An all zero function is sampled uniformly.
+	pub fn new(func: Vec<f64>) -> Distribution1D {
+		let n = func.len();
+		let mut cdf = vec![0.0; n + 1];
+		for i in 0..n {
+			cdf[i + 1] = cdf[i] + func[i].max(0.0) / n as f64;
+		}
+
+		let integral = cdf[n];
+		for (i, value) in cdf.iter_mut().enumerate().skip(1) {
+			*value = if integral > 0.0 { *value / integral } else { i as f64 / n as f64 };
+		}
+
+		Distribution1D { func, cdf, integral }
+	}
+
+	pub fn count(&self) -> usize {
+		self.func.len()
+	}
+
+	/// Average value of the function over [0, 1]
+	pub fn integral(&self) -> f64 {
+		self.integral
+	}
+
+	/// Returns the sampled position, its density and the index of the segment it lies in
+	pub fn sample_continuous(&self, u: f64) -> (f64, f64, usize) {
+		let offset = self.find_segment(u);
+		let (start, end) = (self.cdf[offset], self.cdf[offset + 1]);
+		let du = if end > start { (u - start) / (end - start) } else { 0.0 };
+
+		((offset as f64 + du) / self.count() as f64, self.pdf_of_segment(offset), offset)
+	}
+
+	/// Picks a segment with probability proportional to its value
+	/// Returns the index and the probability of choosing it
+	pub fn sample_discrete(&self, u: f64) -> (usize, f64) {
+		let offset = self.find_segment(u);
+		(offset, self.cdf[offset + 1] - self.cdf[offset])
+	}
+
+	/// Density of `sample_continuous` generating x
+	pub fn pdf(&self, x: f64) -> f64 {
+		let offset = ((x * self.count() as f64) as usize).min(self.count() - 1);
+		self.pdf_of_segment(offset)
+	}
+
+	/// Probability of `sample_discrete` picking the segment
+	pub fn discrete_probability(&self, index: usize) -> f64 {
+		self.cdf[index + 1] - self.cdf[index]
+	}
+
+	fn pdf_of_segment(&self, offset: usize) -> f64 {
+		if self.integral > 0.0 {
+			self.func[offset].max(0.0) / self.integral
+		} else {
+			1.0
+		}
+	}
+
+	/// The last segment whose cdf lies below u, skipping segments with zero probability
+	fn find_segment(&self, u: f64) -> usize {
+		let n = self.count();
+		let (mut low, mut high) = (0, n);
+		while high - low > 1 {
+			let middle = (low + high) / 2;
+			if self.cdf[middle] <= u {
+				low = middle;
+			} else {
+				high = middle;
+			}
+		}
+
+		low.min(n - 1)
+	}
+}
+
+///
+/// Picks one of a set of weighted items in constant time (Vose's alias method)
+///
+/// Every slot holds an item and an alias, a uniform choice of the slot followed by a biased
+/// coin flip between the two gives each item its probability.
+///
+#[derive(Debug, Clone)]
+pub struct AliasTable {
+	/// Probability of keeping the slot's own item instead of its alias
+	threshold: Vec<f64>,
+	alias: Vec<usize>,
+	probability: Vec<f64>,
+}
+
+impl AliasTable {
+	/// Weights must be non-negative and not empty. All zero weights are sampled uniformly.
+	pub fn new(weights: &[f64]) -> AliasTable {
+		let n = weights.len();
+		let total: f64 = weights.iter().map(|w| w.max(0.0)).sum();
+		let probability: Vec<f64> = if total > 0.0 {
+			weights.iter().map(|w| w.max(0.0) / total).collect()
+		} else {
+			vec![1.0 / n as f64; n]
+		};
+
+		let mut threshold: Vec<f64> = probability.iter().map(|p| p * n as f64).collect();
+		let mut alias: Vec<usize> = (0..n).collect();
+		let (mut small, mut large): (Vec<usize>, Vec<usize>) = (0..n).partition(|&i| threshold[i] < 1.0);
+
+		while let (Some(&less), Some(&more)) = (small.last(), large.last()) {
+			small.pop();
+			alias[less] = more;
+			threshold[more] -= 1.0 - threshold[less];
+			if threshold[more] < 1.0 {
+				large.pop();
+				small.push(more);
+			}
+		}
+		// Whatever is left over is only off from one by rounding
+		for i in small.into_iter().chain(large) {
+			threshold[i] = 1.0;
+		}
+
+		AliasTable { threshold, alias, probability }
+	}
+
+	/// Returns the picked index, its probability and u remapped to [0, 1) for reuse
+	pub fn sample(&self, u: f64) -> (usize, f64, f64) {
+		let n = self.threshold.len();
+		let scaled = u * n as f64;
+		let slot = (scaled as usize).min(n - 1);
+		let coin = (scaled - slot as f64).min(1.0);
+
+		let threshold = self.threshold[slot];
+		let (index, remapped) = if coin < threshold {
+			(slot, coin / threshold)
+		} else {
+			(self.alias[slot], (coin - threshold) / (1.0 - threshold))
+		};
+
+		(index, self.probability[index], remapped.min(1.0))
+	}
+
+	pub fn probability(&self, index: usize) -> f64 {
+		self.probability[index]
+	}
+}
+
+///
+/// A piecewise constant distribution over [0, 1]², given as a row major grid of values
+/// Samples a row from the marginal distribution, then a column within it
+///
+#[derive(Debug, Clone)]
+pub struct Distribution2D {
+	conditional: Vec<Distribution1D>,
+	marginal: Distribution1D,
+}
+
+impl Distribution2D {
+	pub fn new(func: &[f64], width: usize, height: usize) -> Distribution2D {
+		let conditional: Vec<_> = (0..height)
+			.map(|y| Distribution1D::new(func[y * width..(y + 1) * width].to_vec()))
+			.collect();
+		let marginal = Distribution1D::new(conditional.iter().map(Distribution1D::integral).collect());
+
+		Distribution2D { conditional, marginal }
+	}
+
+	/// Returns a point in [0, 1]² and its density
+	pub fn sample(&self, u: Vector2) -> (Vector2, f64) {
+		let (y, pdf_y, row) = self.marginal.sample_continuous(u.y);
+		let (x, pdf_x, _) = self.conditional[row].sample_continuous(u.x);
+
+		(Vector2::new(x, y), pdf_x * pdf_y)
+	}
+
+	pub fn pdf(&self, p: Vector2) -> f64 {
+		let row = ((p.y * self.conditional.len() as f64) as usize).min(self.conditional.len() - 1);
+		self.conditional[row].pdf(p.x) * self.marginal.pdf(p.y)
+	}
+}
@@ -1,7 +1,13 @@
-use std::sync::Arc;
+use std::{
+	collections::hash_map::DefaultHasher,
+	hash::{Hash, Hasher},
+	sync::{Arc, OnceLock},
+};
 
 use crate::math::prelude::*;
-use crate::geometry::{AccGrid, Mesh, Plane, Sphere, Triangle, Ray, Hit, SurfaceProperties, Intersect};
+use crate::geometry::{AccGrid, Mesh, Plane, Quad, Sphere, Triangle, Ray, Hit, SurfaceProperties, Intersect};
+use crate::light::{Emitter, Light, LightSample};
+use crate::light_tree::LightTree;
 use crate::Material;
 
 
@@ -9,23 +15,36 @@ use crate::Material;
 pub enum Geometry {
 	Plane(Plane),
 	Sphere(Sphere),
+	Quad(Quad),
 	Grid(Arc<AccGrid>),
 }
 
 impl Geometry {
 	pub fn intersects(&self, ray: Ray) -> Option<Hit> {
 		match self {
-			&Geometry::Plane(ref p) => p.intersects(ray),
-			&Geometry::Sphere(ref s) => s.intersects(ray),
-			&Geometry::Grid(ref g) => g.intersects(ray),
+			Geometry::Plane(p) => p.intersects(ray),
+			Geometry::Sphere(s) => s.intersects(ray),
+			Geometry::Quad(q) => q.intersects(ray),
+			Geometry::Grid(g) => g.intersects(ray),
 		}
 	}
 
 	pub fn get_surface_properties(&self, hit: Hit) -> SurfaceProperties {
 		match self {
-			&Geometry::Plane(ref p) => p.get_surface_properties(hit),
-			&Geometry::Sphere(ref s) => s.get_surface_properties(hit),
-			&Geometry::Grid(ref g) => g.get_surface_properties(hit),
+			Geometry::Plane(p) => p.get_surface_properties(hit),
+			Geometry::Sphere(s) => s.get_surface_properties(hit),
+			Geometry::Quad(q) => q.get_surface_properties(hit),
+			Geometry::Grid(g) => g.get_surface_properties(hit),
+		}
+	}
+
+	/// Planes are infinite
+	pub fn surface_area(&self) -> f64 {
+		match self {
+			Geometry::Plane(_) => F_MAX,
+			Geometry::Sphere(s) => s.surface_area(),
+			Geometry::Quad(q) => q.surface_area(),
+			Geometry::Grid(g) => g.surface_area,
 		}
 	}
 }
@@ -36,40 +55,200 @@ pub struct Object {
 	pub material: Material,
 }
 
+/// Distance a ray moves past a transparent hit before looking for the next one
+const PASS_THROUGH_OFFSET: f64 = 1e-6;
+
+/// Shadow rays count as unoccluded if they hit something this close to their end, relative to their length
+const SHADOW_EPSILON: f64 = 1e-4;
+
 impl Object {
+	///
+	/// Whether the object emits light and is sampled directly, see `light::Emitter`
+	///
+	/// Planes are infinite and can't be sampled. Emissive planes only light the scene through
+	/// rays that hit them, which count their emission in full since no other strategy finds it.
+	///
+	pub fn is_light(&self) -> bool {
+		match (self.material.base(), &self.geometry) {
+			(_, Geometry::Plane(_)) => false,
+			(Material::Emission(_), _) => true,
+			_ => false,
+		}
+	}
+
+	///
+	/// The closest hit on a part of the surface that isn't transparent
+	///
+	/// Transparent parts are skipped stochastically with the material's opacity, so partially
+	/// transparent surfaces average out over many rays. The random number is a hash of the ray
+	/// and the hit distance, so the same ray always sees the same surface.
+	///
+	pub fn intersects(&self, ray: Ray) -> Option<Hit> {
+		if self.material.is_opaque() {
+			return self.geometry.intersects(ray);
+		}
+
+		let mut travelled = 0.0;
+		let mut current = ray;
+		loop {
+			let hit = self.geometry.intersects(current)?;
+			let distance = travelled + hit.distance;
+			let hit = Hit::with_child(ray, distance, hit.subobject_index);
+
+			let opacity = self.material.opacity(&self.geometry.get_surface_properties(hit));
+			if opacity >= 1.0 || (opacity > 0.0 && pass_through_hash(ray, distance) < opacity) {
+				return Some(hit);
+			}
+
+			travelled = distance + PASS_THROUGH_OFFSET;
+			current = Ray::new(ray.origin + ray.direction * travelled, ray.direction);
+		}
+	}
+}
+
+/// A number in [0, 1) that is the same for every query of this ray and hit, but uncorrelated between them
+fn pass_through_hash(ray: Ray, distance: f64) -> f64 {
+	let mut hasher = DefaultHasher::new();
+	for value in &[ray.origin.x, ray.origin.y, ray.origin.z, ray.direction.x, ray.direction.y, ray.direction.z, distance] {
+		value.to_bits().hash(&mut hasher);
+	}
+	// The top 53 bits, as many as an f64 has in its mantissa
+	(hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
 }
 
 #[derive(Clone)]
 pub struct Scene {
-	pub objects: Vec<Object>,
+	/// Only changed through `add`, which keeps `emitters` in sync
+	objects: Vec<Object>,
+	/// The emissive objects among `objects` that can be sampled, collected by `add`
+	emitters: Vec<Emitter>,
+	/// Lights that aren't part of the geometry
+	/// Changes after the first light was sampled aren't seen by the light tree
+	pub lights: Vec<Light>,
+	/// Picks the lights to sample, built on first use
+	light_tree: OnceLock<LightTree>,
 }
 
 impl Scene {
 	pub fn new() -> Scene {
 		Scene {
 			objects: Vec::new(),
+			emitters: Vec::new(),
+			lights: Vec::new(),
+			light_tree: OnceLock::new(),
+		}
+	}
+
+	pub fn add(&mut self, object: Object) {
+		if let Some(emitter) = Emitter::new(self.objects.len(), &object) {
+			self.emitters.push(emitter);
+			self.light_tree = OnceLock::new();
+		}
+		self.objects.push(object);
+	}
+
+	pub fn objects(&self) -> &[Object] {
+		&self.objects
+	}
+
+	pub fn emitters(&self) -> &[Emitter] {
+		&self.emitters
+	}
+
+	///
+	/// The tree over the emitters followed by the lights, built when it's first needed
+	///
+	/// Renderers should call this before copying the scene to their threads, so it's only built once.
+	///
+	pub fn light_tree(&self) -> &LightTree {
+		self.light_tree.get_or_init(|| {
+			let emitters = self.emitters.iter().map(|emitter| Some(emitter.bounds(&self.objects[emitter.object])));
+			let lights = self.lights.iter().map(Light::bounds);
+			LightTree::new(&emitters.chain(lights).collect::<Vec<_>>())
+		})
+	}
+
+	/// Probability of `sample_light` choosing a light from `point`, emitters are numbered before the lights
+	pub fn light_selection_probability(&self, point: Vector3, light: usize) -> f64 {
+		self.light_tree().probability(point, light)
+	}
+
+	///
+	/// Samples a point on one of the emitters or lights, as seen from `point`
+	///
+	/// The light tree chooses the light with `u_light`, the point on it is chosen with `u`.
+	/// Occlusion isn't checked, see `unoccluded`.
+	///
+	pub fn sample_light(&self, point: Vector3, u: Vector2, u_light: f64) -> Option<LightSample> {
+		let (index, probability) = self.light_tree().sample(point, u_light)?;
+		let mut sample = match self.emitters.get(index) {
+			Some(emitter) => emitter.sample(&self.objects[emitter.object], point, u)?,
+			None => self.lights[index - self.emitters.len()].sample(point, u)?,
+		};
+		sample.pdf *= probability;
+
+		Some(sample)
+	}
+
+	///
+	/// Density of `sample_light` choosing the point a ray hit an object at, with respect to solid
+	/// angle at the ray's origin
+	///
+	/// Zero for objects that aren't sampled, `normal` is the geometric normal at the hit.
+	///
+	pub fn light_pdf(&self, object: usize, hit: Hit, normal: Vector3) -> f64 {
+		// Emitters are collected in the order of their objects
+		match self.emitters.binary_search_by_key(&object, |emitter| emitter.object) {
+			Ok(index) => {
+				let emitter = &self.emitters[index];
+				emitter.pdf(&self.objects[object], hit, normal) * self.light_selection_probability(hit.ray.origin, index)
+			}
+			Err(_) => 0.0,
+		}
+	}
+
+	///
+	/// Whether nothing blocks a shadow ray towards a light sampled from `point`
+	///
+	/// The ray leaves from `origin`, which is moved off the surface `point` lies on.
+	///
+	pub fn unoccluded(&self, point: Vector3, origin: Vector3, light: &LightSample) -> bool {
+		if light.distance.is_infinite() {
+			return self.intersect(Ray::new(origin, light.direction)).is_none();
+		}
+
+		self.visible(origin, point + light.direction * light.distance)
+	}
+
+	/// Whether nothing blocks the line between two points
+	/// Points that coincide, or are too close to give the line a direction, see each other
+	pub fn visible(&self, from: Vector3, to: Vector3) -> bool {
+		let distance = from.distance(to);
+		if !distance.is_normal() {
+			return true;
+		}
+		match self.intersect(Ray::new(from, (to - from) / distance)) {
+			Some((_, hit)) => hit.distance >= distance * (1.0 - SHADOW_EPSILON),
+			None => true,
 		}
 	}
 
 	pub fn intersect(&self, ray: Ray) -> Option<(&Object, Hit)> {
+		self.closest_hit(ray).map(|(index, hit)| (&self.objects[index], hit))
+	}
+
+	/// Like `intersect`, but with the index of the object in `objects`
+	pub fn closest_hit(&self, ray: Ray) -> Option<(usize, Hit)> {
 		let mut closest_distance = F_MAX;
 		let mut closest_object = None;
 
 		for (i, object) in self.objects.iter().enumerate() {
-			match object.geometry.intersects(ray) {
-				Some(hit) => {
-					if hit.distance < closest_distance {
-						closest_distance = hit.distance;
-						closest_object = Some((i, hit));
-					}
-				}
-				None => (),
+			if let Some(hit) = object.intersects(ray).filter(|hit| hit.distance < closest_distance) {
+				closest_distance = hit.distance;
+				closest_object = Some((i, hit));
 			}
 		}
 
-		match closest_object {
-			Some((o, h)) => return Some((&self.objects[o], h)),
-			_ => return None,
-		}
+		closest_object
 	}
 }
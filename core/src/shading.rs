@@ -0,0 +1,241 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	geometry::SurfaceProperties,
+	math::{create_coordinate_system_of_n, prelude::*},
+	texture::{self, Texture},
+};
+
+/// Smallest cosine we allow between the view direction and the shading normal
+const MIN_VIEW_COSINE: f64 = 0.01;
+
+/// Which way the green channel of a normal map points
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum NormalMapConvention {
+	/// Green points towards +v, used by Blender, Maya and most DCC tools
+	#[default]
+	OpenGl,
+	/// Green points towards -v, used by Unreal, 3ds Max and Substance's DirectX preset
+	DirectX,
+}
+
+fn default_strength() -> f64 {
+	1.0
+}
+
+///
+/// Perturbs the shading normal of a surface
+///
+/// Normal maps store a tangent space normal, bump maps store a height field
+/// whose slope tilts the normal. Both are oriented by the surface tangent.
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum NormalMap {
+	Normal {
+		#[serde(deserialize_with = "texture::data")]
+		texture: Texture,
+		#[serde(default)]
+		convention: NormalMapConvention,
+		/// Blends between the unperturbed (0) and the fully mapped (1) normal
+		#[serde(default = "default_strength")]
+		strength: f64,
+	},
+	Bump {
+		#[serde(deserialize_with = "texture::data")]
+		texture: Texture,
+		/// Height difference per unit of uv space for a texel difference of 1
+		#[serde(default = "default_strength")]
+		scale: f64,
+	},
+}
+
+impl NormalMap {
+	pub fn perturb(&self, surface: &SurfaceProperties) -> Vector3 {
+		let (tangent, bitangent) = tangent_frame(surface.normal, surface.tangent);
+		// Texture space follows the parametrization, so the bitangent has to point towards increasing v
+		let bitangent = bitangent * surface.handedness;
+		let normal = surface.normal;
+
+		let perturbed = match self {
+			NormalMap::Normal {
+				texture,
+				convention,
+				strength,
+			} => {
+				let texel = texture.sample(surface.uv) * 2.0 - Vector3::new(1.0, 1.0, 1.0);
+				let green = match convention {
+					NormalMapConvention::OpenGl => texel.y,
+					NormalMapConvention::DirectX => -texel.y,
+				};
+
+				tangent * (texel.x * strength) + bitangent * (green * strength) + normal * texel.z.max(0.0)
+			}
+			NormalMap::Bump { texture, scale } => {
+				let du = 1.0 / texture.width as f64;
+				let dv = 1.0 / texture.height as f64;
+				let height = texture.sample_scalar(surface.uv);
+				let dh_du = (texture.sample_scalar(surface.uv + Vector2::new(du, 0.0)) - height) / du;
+				let dh_dv = (texture.sample_scalar(surface.uv + Vector2::new(0.0, dv)) - height) / dv;
+
+				normal - (tangent * dh_du + bitangent * dh_dv) * *scale
+			}
+		};
+
+		if perturbed.magnitude2() < 1e-12 {
+			return normal;
+		}
+		perturbed.normalize()
+	}
+}
+
+///
+/// How opaque a surface is, from 0 (fully transparent) to 1
+///
+/// Textures are read as grayscale, so masks are best stored as a separate grayscale image
+/// rather than in an alpha channel.
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Opacity {
+	Constant(f64),
+	Texture(#[serde(deserialize_with = "texture::data")] Texture),
+}
+
+impl Opacity {
+	pub fn at(&self, surface: &SurfaceProperties) -> f64 {
+		match self {
+			Opacity::Constant(opacity) => *opacity,
+			Opacity::Texture(texture) => texture.sample_scalar(surface.uv),
+		}
+	}
+}
+
+///
+/// Orthonormal shading frame
+/// The local space is y-up: the normal maps to +y, the tangent to +x and the bitangent to +z
+///
+#[derive(Debug, Clone, Copy)]
+pub struct Frame {
+	pub tangent: Vector3,
+	pub normal: Vector3,
+	pub bitangent: Vector3,
+}
+
+impl Frame {
+	pub fn new(normal: Vector3, tangent: Vector3) -> Frame {
+		let (tangent, bitangent) = tangent_frame(normal, tangent);
+
+		Frame {
+			tangent,
+			normal,
+			bitangent,
+		}
+	}
+
+	pub fn to_local(&self, v: Vector3) -> Vector3 {
+		Vector3::new(v.dot(self.tangent), v.dot(self.normal), v.dot(self.bitangent))
+	}
+
+	pub fn to_world(&self, v: Vector3) -> Vector3 {
+		self.tangent * v.x + self.normal * v.y + self.bitangent * v.z
+	}
+}
+
+/// Orthonormalizes the tangent against the normal and returns the (tangent, bitangent) pair
+/// Falls back to an arbitrary frame when the surface has no usable tangent
+pub fn tangent_frame(normal: Vector3, tangent: Vector3) -> (Vector3, Vector3) {
+	let tangent = tangent - normal * normal.dot(tangent);
+	let length = tangent.magnitude();
+
+	if !length.is_finite() || length < 1e-6 {
+		return create_coordinate_system_of_n(normal);
+	}
+
+	let tangent = tangent / length;
+	(tangent, normal.cross(tangent))
+}
+
+///
+/// Makes sure the view direction lies in the upper hemisphere of the shading normal
+///
+/// Interpolated and mapped normals can face away from the viewer even though the
+/// geometric surface doesn't, which shows up as black fringes. In that case the
+/// normal is bent towards the view direction just enough to make it visible again.
+///
+pub fn consistent_shading_normal(shading_normal: Vector3, view_dir: Vector3) -> Vector3 {
+	let cos = shading_normal.dot(view_dir);
+	if cos >= MIN_VIEW_COSINE {
+		return shading_normal;
+	}
+
+	(shading_normal + view_dir * (MIN_VIEW_COSINE - cos)).normalize()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::geometry::{Intersect, Quad, Ray, Sphere};
+
+	/// A height field rising towards +v, v = 0 is the bottom row
+	fn ramp_in_v() -> NormalMap {
+		let size = 4;
+		let data = (0..size * size)
+			.map(|i| {
+				let height = 1.0 - ((i / size) as f64 + 0.5) / size as f64;
+				Vector3::new(height, height, height)
+			})
+			.collect();
+
+		NormalMap::Bump {
+			texture: Texture::from_texels(size, size, data),
+			scale: 0.1,
+		}
+	}
+
+	/// The direction of increasing v, by finite differences of the parametrization
+	fn dp_dv(surface: &SurfaceProperties, position: Vector3, nearby: impl Fn(Vector2) -> Vector3) -> Vector3 {
+		let dv = 1e-4;
+		(nearby(surface.uv + Vector2::new(0.0, dv)) - position).normalize()
+	}
+
+	#[test]
+	fn bump_ramp_tilts_sphere_normal_against_v() {
+		let sphere = Sphere {
+			origin: Vector3::new(0.0, 0.0, 0.0),
+			radius: 1.0,
+		};
+		let ray = Ray::new(Vector3::new(3.0, 0.2, 0.3), Vector3::new(-1.0, 0.0, 0.0));
+		let hit = sphere.intersects(ray).unwrap();
+		let surface = sphere.get_surface_properties(hit);
+		let position = ray.origin + ray.direction * hit.distance;
+
+		// Inverse of the sphere's parametrization, u around the equator and v from pole to pole
+		let dp_dv = dp_dv(&surface, position, |uv| {
+			let (phi, theta) = ((uv.x - 0.5) * 2.0 * PI, (1.0 - uv.y) * PI);
+			Vector3::new(theta.sin() * phi.cos(), theta.cos(), theta.sin() * phi.sin())
+		});
+		let perturbed = ramp_in_v().perturb(&surface);
+
+		assert!(dp_dv.y > 0.9);
+		assert!(perturbed.dot(dp_dv) < -0.05, "{:?} doesn't tilt against {:?}", perturbed, dp_dv);
+		assert!(perturbed.dot(surface.tangent).abs() < 1e-9);
+	}
+
+	#[test]
+	fn bump_ramp_tilts_quad_normal_against_v() {
+		let quad = Quad {
+			origin: Vector3::new(-1.0, -1.0, 2.0),
+			edge_u: Vector3::new(2.0, 0.0, 0.0),
+			edge_v: Vector3::new(0.0, 2.0, 0.0),
+		};
+		let ray = Ray::new(Vector3::new(0.1, 0.2, 0.0), Vector3::new(0.0, 0.0, 1.0));
+		let hit = quad.intersects(ray).unwrap();
+		let surface = quad.get_surface_properties(hit);
+		let position = ray.origin + ray.direction * hit.distance;
+
+		let dp_dv = dp_dv(&surface, position, |uv| quad.origin + quad.edge_u * uv.x + quad.edge_v * uv.y);
+		let perturbed = ramp_in_v().perturb(&surface);
+
+		assert!(perturbed.dot(dp_dv) < -0.05, "{:?} doesn't tilt against {:?}", perturbed, dp_dv);
+		assert!(perturbed.dot(surface.tangent).abs() < 1e-9);
+	}
+}
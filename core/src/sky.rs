@@ -0,0 +1,393 @@
+//!
+//! Analytic daylight, the sky model of Preetham et al. 1999, "A Practical Analytic Model for Daylight"
+//!
+//! The sky is baked into an environment map when the project is loaded, so it's importance
+//! sampled the same way, and the sun is a directional light with the size of the real one.
+//! Radiance is in kcd/m², so a clear sky at noon lights a white surface to around 30.
+//!
+
+use std::{convert::TryFrom, fmt};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	color::{cie_xyz, planck, xyz_to_rgb},
+	light::{default_intensity, equirectangular_direction, DirectionalLight, EnvironmentMap, Light},
+	material::EmissionColor,
+	math::{luminance, prelude::*},
+	sampling,
+	texture::Texture,
+};
+
+/// Luminance of the sun outside the atmosphere, in kcd/m²
+const SUN_LUMINANCE: f64 = 1.9e6;
+const SUN_TEMPERATURE: f64 = 5778.0;
+const SUN_ANGULAR_DIAMETER: f64 = 0.53;
+
+/// Below the horizon the sun is switched off and the sky fades out over civil twilight
+const TWILIGHT_ELEVATION: f64 = -6.0;
+
+/// Size of the baked sky, the model is smooth enough that this resolves the glow around the sun
+const DOME_WIDTH: usize = 512;
+const DOME_HEIGHT: usize = 256;
+
+fn default_turbidity() -> f64 {
+	3.0
+}
+
+fn default_ground_albedo() -> Vector3 {
+	Vector3::new(0.2, 0.2, 0.2)
+}
+
+/// A calendar date, written as "YYYY-MM-DD" in a project file
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Date {
+	pub year: i32,
+	pub month: u32,
+	pub day: u32,
+}
+
+impl Date {
+	fn is_leap_year(&self) -> bool {
+		(self.year % 4 == 0 && self.year % 100 != 0) || self.year % 400 == 0
+	}
+
+	fn days_in_month(&self, month: u32) -> u32 {
+		match month {
+			2 if self.is_leap_year() => 29,
+			2 => 28,
+			4 | 6 | 9 | 11 => 30,
+			_ => 31,
+		}
+	}
+
+	/// Starting at 1 for the first of January
+	pub fn day_of_year(&self) -> u32 {
+		(1..self.month).map(|month| self.days_in_month(month)).sum::<u32>() + self.day
+	}
+}
+
+impl TryFrom<String> for Date {
+	type Error = String;
+
+	fn try_from(text: String) -> Result<Self, Self::Error> {
+		let parts: Vec<_> = text.split('-').collect();
+		let invalid = || format!("Invalid date '{}', expected YYYY-MM-DD", text);
+		if parts.len() != 3 {
+			return Err(invalid());
+		}
+
+		let date = Date {
+			year: parts[0].parse().map_err(|_| invalid())?,
+			month: parts[1].parse().map_err(|_| invalid())?,
+			day: parts[2].parse().map_err(|_| invalid())?,
+		};
+		if date.month < 1 || date.month > 12 || date.day < 1 || date.day > date.days_in_month(date.month) {
+			return Err(invalid());
+		}
+
+		Ok(date)
+	}
+}
+
+impl From<Date> for String {
+	fn from(date: Date) -> String {
+		format!("{:04}-{:02}-{:02}", date.year, date.month, date.day)
+	}
+}
+
+///
+/// Where the sun is in the sky
+///
+/// North is towards -z and east towards +x, angles are in degrees.
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SunPosition {
+	/// Elevation above the horizon, azimuth clockwise from north
+	Angles { elevation: f64, azimuth: f64 },
+	/// The sun as seen from a place on earth, latitude north and longitude east of Greenwich
+	Location {
+		latitude: f64,
+		longitude: f64,
+		date: Date,
+		/// Local time in hours, 14.5 is half past two in the afternoon
+		hour: f64,
+		/// Hours the local time is ahead of UTC
+		#[serde(default)]
+		utc_offset: f64,
+	},
+}
+
+impl SunPosition {
+	/// Elevation and azimuth in degrees
+	pub fn angles(&self) -> (f64, f64) {
+		match *self {
+			SunPosition::Angles { elevation, azimuth } => (elevation, azimuth),
+			SunPosition::Location { latitude, longitude, date, hour, utc_offset } => {
+				// Approximations from the appendix of Preetham et al.
+				let day = date.day_of_year() as f64;
+				let solar_time = hour - utc_offset + longitude / 15.0 + 0.170 * (4.0 * PI * (day - 80.0) / 373.0).sin()
+					- 0.129 * (2.0 * PI * (day - 8.0) / 355.0).sin();
+				let declination = 0.4093 * (2.0 * PI * (day - 81.0) / 368.0).sin();
+				let hour_angle = PI * (solar_time - 12.0) / 12.0;
+				let latitude = latitude.to_radians();
+
+				let elevation = (latitude.sin() * declination.sin()
+					+ latitude.cos() * declination.cos() * hour_angle.cos())
+				.clamp(-1.0, 1.0)
+				.asin();
+				let azimuth = (-hour_angle.sin() * declination.cos()).atan2(
+					declination.sin() * latitude.cos() - declination.cos() * latitude.sin() * hour_angle.cos(),
+				);
+
+				(elevation.to_degrees(), azimuth.to_degrees().rem_euclid(360.0))
+			}
+		}
+	}
+
+	/// Unit vector towards the sun
+	pub fn direction(&self) -> Vector3 {
+		let (elevation, azimuth) = self.angles();
+		let (elevation, azimuth) = (elevation.to_radians(), azimuth.to_radians());
+
+		Vector3::new(elevation.cos() * azimuth.sin(), elevation.sin(), -elevation.cos() * azimuth.cos())
+	}
+}
+
+/// A sky as written in a project file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Sky {
+	pub sun: SunPosition,
+	/// Haziness of the atmosphere, 2 is a very clear sky and 10 a hazy one
+	#[serde(default = "default_turbidity")]
+	pub turbidity: f64,
+	/// Reflectance of the ground, which lights the lower half of the sky
+	#[serde(default = "default_ground_albedo")]
+	pub ground_albedo: Vector3,
+	/// Scales the sky and the sun, to bring them into the range of the other lights
+	#[serde(default = "default_intensity")]
+	pub intensity: f64,
+}
+
+///
+/// A daylight sky with the sun in it, used in place of an environment map
+///
+/// Directions are sampled from the sun or the sky with probability proportional to the power
+/// each of them carries.
+///
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(from = "Sky", into = "Sky")]
+pub struct SkyLight {
+	pub sky: Sky,
+	/// Boxed, as a `Light` can itself be a sky
+	dome: Box<Light>,
+	sun: Box<Light>,
+	sun_probability: f64,
+}
+
+impl From<Sky> for SkyLight {
+	fn from(sky: Sky) -> SkyLight {
+		let sun_direction = sky.sun.direction();
+		let (elevation, _) = sky.sun.angles();
+		let turbidity = sky.turbidity.clamp(1.7, 10.0);
+		let model = Preetham::new(sun_direction, turbidity);
+		let twilight = ((elevation - TWILIGHT_ELEVATION) / -TWILIGHT_ELEVATION).clamp(0.0, 1.0);
+
+		let mut sun = DirectionalLight {
+			direction: -sun_direction,
+			color: EmissionColor::Rgb(if elevation > 0.0 { sun_color(sun_direction.y, turbidity) } else { Vector3::new(0.0, 0.0, 0.0) }),
+			irradiance: 0.0,
+			angular_diameter: SUN_ANGULAR_DIAMETER,
+		};
+		sun.irradiance = sky.intensity * SUN_LUMINANCE / sampling::uniform_cone_pdf(sun.cos_max());
+		let sun_radiance = sun.color.rgb() * (sky.intensity * SUN_LUMINANCE);
+
+		// The upper half is the model, the lower half the ground lit by it
+		let texel_solid_angle = |y: usize| {
+			let (top, bottom) = (PI * y as f64 / DOME_HEIGHT as f64, PI * (y + 1) as f64 / DOME_HEIGHT as f64);
+			2.0 * PI * (top.cos() - bottom.cos()) / DOME_WIDTH as f64
+		};
+		let mut data = Vec::with_capacity(DOME_WIDTH * DOME_HEIGHT);
+		let mut irradiance = sun_radiance * (sun_direction.y.max(0.0) / sampling::uniform_cone_pdf(sun.cos_max()));
+		for y in 0..DOME_HEIGHT / 2 {
+			for x in 0..DOME_WIDTH {
+				let direction = equirectangular_direction(Vector2::new(
+					(x as f64 + 0.5) / DOME_WIDTH as f64,
+					(y as f64 + 0.5) / DOME_HEIGHT as f64,
+				));
+				let radiance = model.radiance(direction) * (sky.intensity * twilight);
+				irradiance += radiance * (direction.y * texel_solid_angle(y));
+				data.push(radiance);
+			}
+		}
+		let ground = sky.ground_albedo.mul_element_wise(irradiance) / PI;
+		data.resize(DOME_WIDTH * DOME_HEIGHT, ground);
+
+		let sky_power: f64 = (0..DOME_HEIGHT)
+			.map(|y| (0..DOME_WIDTH).map(|x| luminance(data[x + y * DOME_WIDTH])).sum::<f64>() * texel_solid_angle(y))
+			.sum();
+		let sun_power = luminance(sun.color.rgb()) * sun.irradiance;
+		let sun_probability = if sun_power > 0.0 { sun_power / (sun_power + sky_power) } else { 0.0 };
+
+		let dome = EnvironmentMap {
+			texture: Texture::from_texels(DOME_WIDTH, DOME_HEIGHT, data),
+			intensity: 1.0,
+			rotation: 0.0,
+		};
+
+		SkyLight {
+			sky,
+			dome: Box::new(Light::Environment(dome.into())),
+			sun: Box::new(Light::Directional(sun)),
+			sun_probability,
+		}
+	}
+}
+
+impl From<SkyLight> for Sky {
+	fn from(light: SkyLight) -> Sky {
+		light.sky
+	}
+}
+
+impl fmt::Debug for SkyLight {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "SkyLight {{ sky: {:?} }}", self.sky)
+	}
+}
+
+impl SkyLight {
+	/// A direction towards the sun or the sky, None if the sun was picked but is below the horizon
+	pub fn sample(&self, point: Vector3, u: Vector2) -> Option<Vector3> {
+		let p = self.sun_probability;
+		if u.x < p {
+			self.sun.sample(point, Vector2::new(u.x / p, u.y)).map(|sample| sample.direction)
+		} else {
+			self.dome.sample(point, Vector2::new((u.x - p) / (1.0 - p), u.y)).map(|sample| sample.direction)
+		}
+	}
+
+	pub fn pdf(&self, direction: Vector3) -> f64 {
+		self.sun_probability * self.sun.pdf(direction) + (1.0 - self.sun_probability) * self.dome.pdf(direction)
+	}
+
+	pub fn radiance(&self, direction: Vector3) -> Vector3 {
+		self.sun.radiance(direction) + self.dome.radiance(direction)
+	}
+}
+
+///
+/// Color of the sun after passing through the atmosphere, relative to its luminance outside of it
+///
+/// Only Rayleigh scattering and aerosols are accounted for, from the appendix of Preetham et al.
+///
+fn sun_color(cos_theta: f64, turbidity: f64) -> Vector3 {
+	const STEPS: usize = 80;
+	let zenith_degrees = cos_theta.acos().to_degrees();
+	let optical_mass = 1.0 / (cos_theta + 0.15 * (93.885 - zenith_degrees).powf(-1.253));
+	let beta = 0.04608 * turbidity - 0.04586;
+
+	let (mut attenuated, mut unattenuated) = (Vector3::new(0.0, 0.0, 0.0), 0.0);
+	for i in 0..STEPS {
+		let wavelength = 380.0 + (i as f64 + 0.5) * 400.0 / STEPS as f64;
+		let micrometers = wavelength / 1000.0;
+		let rayleigh = (-0.008735 * micrometers.powf(-4.08) * optical_mass).exp();
+		let aerosol = (-beta * micrometers.powf(-1.3) * optical_mass).exp();
+
+		let xyz = cie_xyz(wavelength) * planck(wavelength, SUN_TEMPERATURE);
+		attenuated += xyz * (rayleigh * aerosol);
+		unattenuated += xyz.y;
+	}
+
+	let rgb = xyz_to_rgb(attenuated / unattenuated);
+	Vector3::new(rgb.x.max(0.0), rgb.y.max(0.0), rgb.z.max(0.0))
+}
+
+/// The Perez et al. distribution of a sky quantity relative to its value at the zenith
+#[derive(Debug, Clone, Copy)]
+struct Perez([f64; 5]);
+
+impl Perez {
+	fn new(turbidity: f64, coefficients: [(f64, f64); 5]) -> Perez {
+		let mut values = [0.0; 5];
+		for (value, (t, c)) in values.iter_mut().zip(coefficients.iter()) {
+			*value = t * turbidity + c;
+		}
+
+		Perez(values)
+	}
+
+	fn eval(&self, cos_theta: f64, gamma: f64) -> f64 {
+		let [a, b, c, d, e] = self.0;
+		(1.0 + a * (b / cos_theta.max(0.01)).exp()) * (1.0 + c * (d * gamma).exp() + e * gamma.cos().powi(2))
+	}
+}
+
+/// Sky luminance and chromaticity for one sun position and turbidity
+struct Preetham {
+	sun: Vector3,
+	/// Luminance in kcd/m² and the xy chromaticity at the zenith
+	zenith: Vector3,
+	/// Distributions of the luminance and chromaticity, divided by their value at the zenith
+	distributions: [(Perez, f64); 3],
+}
+
+impl Preetham {
+	fn new(sun: Vector3, turbidity: f64) -> Preetham {
+		// The model isn't defined for the sun below the horizon
+		let sun = Vector3::new(sun.x, sun.y.max(0.0), sun.z).normalize();
+		let theta = sun.y.acos();
+		let t = turbidity;
+
+		let chi = (4.0 / 9.0 - t / 120.0) * (PI - 2.0 * theta);
+		let zenith_luminance = ((4.0453 * t - 4.9710) * chi.tan() - 0.2155 * t + 2.4192).max(0.0);
+		let chromaticity = |m: [[f64; 4]; 3]| {
+			let thetas = [theta.powi(3), theta.powi(2), theta, 1.0];
+			let row = |r: [f64; 4]| r.iter().zip(thetas.iter()).map(|(a, b)| a * b).sum::<f64>();
+			t * t * row(m[0]) + t * row(m[1]) + row(m[2])
+		};
+		let zenith_x = chromaticity([
+			[0.00166, -0.00375, 0.00209, 0.0],
+			[-0.02903, 0.06377, -0.03202, 0.00394],
+			[0.11693, -0.21196, 0.06052, 0.25886],
+		]);
+		let zenith_y = chromaticity([
+			[0.00275, -0.00610, 0.00317, 0.0],
+			[-0.04214, 0.08970, -0.04153, 0.00516],
+			[0.15346, -0.26756, 0.06670, 0.26688],
+		]);
+
+		let distributions = [
+			Perez::new(t, [(0.1787, -1.4630), (-0.3554, 0.4275), (-0.0227, 5.3251), (0.1206, -2.5771), (-0.0670, 0.3703)]),
+			Perez::new(t, [(-0.0193, -0.2592), (-0.0665, 0.0008), (-0.0004, 0.2125), (-0.0641, -0.8989), (-0.0033, 0.0452)]),
+			Perez::new(t, [(-0.0167, -0.2608), (-0.0950, 0.0092), (-0.0079, 0.2102), (-0.0441, -1.6537), (-0.0109, 0.0529)]),
+		];
+
+		Preetham {
+			sun,
+			zenith: Vector3::new(zenith_luminance, zenith_x, zenith_y),
+			distributions: [
+				(distributions[0], distributions[0].eval(1.0, theta)),
+				(distributions[1], distributions[1].eval(1.0, theta)),
+				(distributions[2], distributions[2].eval(1.0, theta)),
+			],
+		}
+	}
+
+	/// Linear RGB radiance of the sky in a direction above the horizon
+	fn radiance(&self, direction: Vector3) -> Vector3 {
+		let gamma = direction.dot(self.sun).clamp(-1.0, 1.0).acos();
+		let [(luminance, luminance_zenith), (x, x_zenith), (y, y_zenith)] = self.distributions;
+
+		let big_y = self.zenith.x * luminance.eval(direction.y, gamma) / luminance_zenith;
+		let x = self.zenith.y * x.eval(direction.y, gamma) / x_zenith;
+		let y = self.zenith.z * y.eval(direction.y, gamma) / y_zenith;
+		if y.is_nan() || y <= 0.0 {
+			return Vector3::new(0.0, 0.0, 0.0);
+		}
+
+		let rgb = xyz_to_rgb(Vector3::new(x / y * big_y, big_y, (1.0 - x - y) / y * big_y));
+		Vector3::new(rgb.x.max(0.0), rgb.y.max(0.0), rgb.z.max(0.0))
+	}
+}
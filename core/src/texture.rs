@@ -0,0 +1,222 @@
+use std::{convert::TryFrom, fmt, fs::File, io::BufReader, path::{Path, PathBuf}, sync::Arc};
+
+use image::codecs::hdr::HdrDecoder;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::{color, math::prelude::*, project};
+
+/// How the values in an image file relate to the quantity they describe
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ColorSpace {
+	/// Encoded with the sRGB transfer curve, like nearly all 8 bit color images
+	Srgb,
+	/// Proportional to the quantity, like HDR images and data such as normals or roughness
+	Linear,
+}
+
+fn has_extension(path: &Path, extensions: &[&str]) -> bool {
+	let extension = path.extension().and_then(|extension| extension.to_str()).unwrap_or("");
+	extensions.iter().any(|candidate| extension.eq_ignore_ascii_case(candidate))
+}
+
+/// Radiance files, which `image::open` would clamp to 8 bits
+fn is_hdr(path: &Path) -> bool {
+	has_extension(path, &["hdr"])
+}
+
+/// Floating point formats, which store linear values rather than sRGB encoded ones
+fn is_linear_format(path: &Path) -> bool {
+	has_extension(path, &["hdr", "exr"])
+}
+
+///
+/// A texture as written in a project file, the path to the image and optionally its color space
+/// The path is relative to the project file, or the material library the texture is part of
+///
+/// ```json
+/// "texture": "assets/textures/wood.png"
+/// "texture": { "path": "assets/textures/wood_roughness.png", "color_space": "Linear" }
+/// ```
+///
+/// Without a color space, textures holding colors are read as sRGB, unless they're floating
+/// point `.hdr` or `.exr` images, and textures holding data like normals, heights or opacity
+/// are read as linear.
+///
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TextureDescription {
+	Path(PathBuf),
+	Encoded { path: PathBuf, color_space: ColorSpace },
+}
+
+impl TextureDescription {
+	fn load(self, default: impl FnOnce(&Path) -> ColorSpace) -> Result<Texture, image::ImageError> {
+		let (path, color_space) = match self {
+			TextureDescription::Path(path) => {
+				let color_space = default(&path);
+				(path, color_space)
+			}
+			TextureDescription::Encoded { path, color_space } => (path, color_space),
+		};
+
+		// Keep the path as written, so the texture is written back the same way
+		let texture = Texture::load(project::resolve_asset(&path), color_space)?;
+		Ok(Texture { path, ..texture })
+	}
+}
+
+///
+/// An RGB image, sampled through uv coordinates
+/// The texels are converted to linear values on load
+///
+/// Deserializes as a color texture, fields holding data use `texture::data` instead.
+///
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(try_from = "TextureDescription", into = "TextureDescription")]
+pub struct Texture {
+	pub path: PathBuf,
+	/// The color space of the file, the texels themselves are always linear
+	pub color_space: ColorSpace,
+	pub width: usize,
+	pub height: usize,
+	data: Arc<Vec<Vector3>>,
+}
+
+/// Deserializes a texture holding data rather than colors, which is linear unless stated otherwise
+pub fn data<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Texture, D::Error> {
+	TextureDescription::deserialize(deserializer)?
+		.load(|_| ColorSpace::Linear)
+		.map_err(serde::de::Error::custom)
+}
+
+/// Like `data`, for optional fields
+pub fn optional_data<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Texture>, D::Error> {
+	Option::<TextureDescription>::deserialize(deserializer)?
+		.map(|description| description.load(|_| ColorSpace::Linear))
+		.transpose()
+		.map_err(serde::de::Error::custom)
+}
+
+impl Texture {
+	pub fn load(path: PathBuf, color_space: ColorSpace) -> Result<Texture, image::ImageError> {
+		let (width, height, data) = if is_hdr(&path) {
+			// Decoding through `image::open` would clamp Radiance files to 8 bits
+			let decoder = HdrDecoder::new(BufReader::new(File::open(&path).map_err(image::ImageError::IoError)?))?;
+			let metadata = decoder.metadata();
+			let pixels = decoder.read_image_hdr()?;
+
+			(metadata.width as usize, metadata.height as usize, pixels)
+		} else {
+			let image = image::open(&path)?.to_rgb32f();
+
+			(image.width() as usize, image.height() as usize, image.pixels().cloned().collect())
+		};
+		let decode: fn(f64) -> f64 = match color_space {
+			ColorSpace::Srgb => color::srgb_to_linear,
+			ColorSpace::Linear => |value| value,
+		};
+		let data = data
+			.into_iter()
+			.map(|p| Vector3::new(decode(p[0] as f64), decode(p[1] as f64), decode(p[2] as f64)))
+			.collect();
+
+		Ok(Texture {
+			path,
+			color_space,
+			width,
+			height,
+			data: Arc::new(data),
+		})
+	}
+
+	/// A texture computed rather than loaded, it has no path to be written back to a project file
+	pub fn from_texels(width: usize, height: usize, data: Vec<Vector3>) -> Texture {
+		assert_eq!(data.len(), width * height);
+
+		Texture {
+			path: PathBuf::new(),
+			color_space: ColorSpace::Linear,
+			width,
+			height,
+			data: Arc::new(data),
+		}
+	}
+
+	/// Fetches a single texel, wrapping around the edges
+	pub fn texel(&self, x: isize, y: isize) -> Vector3 {
+		let x = x.rem_euclid(self.width as isize) as usize;
+		let y = y.rem_euclid(self.height as isize) as usize;
+
+		self.data[x + y * self.width]
+	}
+
+	/// Bilinearly filtered lookup, the texture repeats outside of [0, 1]
+	/// v = 0 is the bottom row of the image
+	pub fn sample(&self, uv: Vector2) -> Vector3 {
+		let x = uv.x * self.width as f64 - 0.5;
+		let y = (1.0 - uv.y) * self.height as f64 - 0.5;
+		let (x0, y0) = (x.floor(), y.floor());
+		let (fx, fy) = (x - x0, y - y0);
+		let (x0, y0) = (x0 as isize, y0 as isize);
+
+		let top = self.texel(x0, y0) * (1.0 - fx) + self.texel(x0 + 1, y0) * fx;
+		let bottom = self.texel(x0, y0 + 1) * (1.0 - fx) + self.texel(x0 + 1, y0 + 1) * fx;
+
+		top * (1.0 - fy) + bottom * fy
+	}
+
+	/// Mean of all texels
+	pub fn average(&self) -> Vector3 {
+		self.data.iter().fold(Vector3::new(0.0, 0.0, 0.0), |sum, texel| sum + texel) / self.data.len() as f64
+	}
+
+	/// Scalar lookup, averaging the channels
+	pub fn sample_scalar(&self, uv: Vector2) -> f64 {
+		let s = self.sample(uv);
+		(s.x + s.y + s.z) / 3.0
+	}
+}
+
+impl TryFrom<TextureDescription> for Texture {
+	type Error = image::ImageError;
+
+	fn try_from(description: TextureDescription) -> Result<Self, Self::Error> {
+		description.load(|path| if is_linear_format(path) { ColorSpace::Linear } else { ColorSpace::Srgb })
+	}
+}
+
+impl From<Texture> for TextureDescription {
+	fn from(texture: Texture) -> TextureDescription {
+		TextureDescription::Encoded {
+			path: texture.path,
+			color_space: texture.color_space,
+		}
+	}
+}
+
+impl fmt::Debug for Texture {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"Texture {{ path: {:?}, color_space: {:?}, width: {}, height: {} }}",
+			self.path, self.color_space, self.width, self.height
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn exr_defaults_to_linear() {
+		let path = std::env::temp_dir().join("raymond_texture_radiance.EXR");
+		image::Rgb32FImage::from_pixel(2, 2, image::Rgb([4.0, 0.5, 0.25])).save_with_format(&path, image::ImageFormat::OpenExr).unwrap();
+
+		let texture: Texture = serde_json::from_value(serde_json::json!(path)).unwrap();
+		std::fs::remove_file(&path).ok();
+
+		assert_eq!(texture.color_space, ColorSpace::Linear);
+		assert!((texture.texel(0, 0) - Vector3::new(4.0, 0.5, 0.25)).magnitude() < 1e-6);
+	}
+}
@@ -1,5 +1,6 @@
 use raytracer::{
 	acc_grid,
+	integrator::IntegratorSettings,
 	material::Material,
 	mesh::Mesh,
 	primitives::Plane,
@@ -76,10 +77,10 @@ fn main() {
 		let mut scene = Scene::new();
 		let mut sphere_mesh = Mesh::load_ply(PathBuf::from("assets/meshes/ico_sphere.ply"));
 
-		// scene.objects.push(Object::Sphere(Sphere { origin: Vector3::new(-1.5, -0.5, 3.5), radius: 0.5, material: Material::Diffuse(
+		// scene.add(Object::Sphere(Sphere { origin: Vector3::new(-1.5, -0.5, 3.5), radius: 0.5, material: Material::Diffuse(
 		//     Vector3::new(1.0, 0.00, 0.00), 0.04
 		// )}));
-		// scene.objects.push(Object::Sphere(Sphere { origin: Vector3::new(1.25, -0.25, 3.5), radius: 0.75, material: Material::Metal(
+		// scene.add(Object::Sphere(Sphere { origin: Vector3::new(1.25, -0.25, 3.5), radius: 0.75, material: Material::Metal(
 		//     Vector3::new(0.05, 0.25, 1.00), 0.02
 		// )}));
 
@@ -95,40 +96,40 @@ fn main() {
 		let cube_grid = Arc::new(cube_grid);
 		let cube_model = Object::Grid(cube_grid, Material::Metal(Vector3::new(1.0, 1.0, 0.1), 0.15));
 
-		scene.objects.push(cube_model);
+		scene.add(cube_model);
 
 		// // Floor
-		scene.objects.push(Object::Plane(Plane {
+		scene.add(Object::Plane(Plane {
 			origin: Vector3::new(0.0, -1.0, 0.0),
 			normal: Vector3::new(0.0, 1.0, 0.0),
 			material: Material::Diffuse(Vector3::new(0.75, 0.75, 0.75), 0.5),
 		}));
 		// Ceiling
-		scene.objects.push(Object::Plane(Plane {
+		scene.add(Object::Plane(Plane {
 			origin: Vector3::new(0.0, 2.0, 0.0),
 			normal: Vector3::new(0.0, -1.0, 0.0),
 			material: Material::Emission(Vector3::new(1.5, 1.5, 1.5), Vector3::new(1.0, 1.0, 1.0), 0.27, 0.0),
 		}));
 		// Frontwall
-		scene.objects.push(Object::Plane(Plane {
+		scene.add(Object::Plane(Plane {
 			origin: Vector3::new(0.0, 0.0, -2.0),
 			normal: Vector3::new(0.0, 0.0, 1.0),
 			material: Material::Diffuse(Vector3::new(1.0, 1.0, 1.0), 0.4),
 		}));
 		// Backwall
-		scene.objects.push(Object::Plane(Plane {
+		scene.add(Object::Plane(Plane {
 			origin: Vector3::new(0.0, 0.0, 5.0),
 			normal: Vector3::new(0.0, 0.0, -1.0),
 			material: Material::Diffuse(Vector3::new(0.0, 0.0, 0.0), 0.9),
 		}));
 		// left wall
-		scene.objects.push(Object::Plane(Plane {
+		scene.add(Object::Plane(Plane {
 			origin: Vector3::new(-2.0, 0.0, 0.0),
 			normal: Vector3::new(1.0, 0.0, 0.0),
 			material: Material::Diffuse(Vector3::new(0.0, 0.0, 0.0), 0.9),
 		}));
 		// right wall
-		scene.objects.push(Object::Plane(Plane {
+		scene.add(Object::Plane(Plane {
 			origin: Vector3::new(2.0, 0.0, 0.0),
 			normal: Vector3::new(-1.0, 0.0, 0.0),
 			material: Material::Diffuse(Vector3::new(0.0, 0.0, 0.0), 0.9),
@@ -154,7 +155,7 @@ fn main() {
 		.camera_settings(camera)
 		.sample_count(50)
 		.tile_size((16, 16))
-		.bounce_limit(5)
+		.integrator(IntegratorSettings::PathTracer { roulette_depth: 3, bounce_limit: Some(5) })
 		.build()
 		.unwrap();
 
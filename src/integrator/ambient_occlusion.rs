@@ -0,0 +1,52 @@
+use core::{
+	math::prelude::*,
+	prelude::*,
+	sampling::{self, Sampler},
+	scene::Scene,
+	shading::Frame,
+};
+
+use super::Integrator;
+
+///
+/// The fraction of cosine weighted directions above the first hit that escape the nearby geometry
+///
+/// Materials and lights are ignored, which makes it a quick check of the geometry and its normals.
+/// Rays that miss the scene are black.
+///
+#[derive(Debug, Clone)]
+pub struct AmbientOcclusion {
+	/// Geometry further away than this doesn't occlude
+	pub distance: f64,
+}
+
+impl Integrator for AmbientOcclusion {
+	fn radiance(&self, ray: Ray, scene: &Scene, sampler: &mut dyn Sampler) -> Vector3 {
+		let (object, hit) = match scene.intersect(ray) {
+			Some(closest) => closest,
+			None => return Vector3::new(0.0, 0.0, 0.0),
+		};
+		let surface_properties = object.geometry.get_surface_properties(hit);
+		let position = ray.origin + ray.direction * hit.distance;
+
+		// The hemisphere on the viewer's side of the surface
+		let mut normal = surface_properties.geometric_normal;
+		if normal.dot(ray.direction) > 0.0 {
+			normal = -normal;
+		}
+		let frame = Frame::new(normal, surface_properties.tangent);
+		let direction = frame.to_world(sampling::cosine_sample_hemisphere(sampler.next_2d()));
+
+		let origin = position + normal * 0.0001;
+		let open = match scene.intersect(Ray::new(origin, direction)) {
+			Some((_, occluder)) => occluder.distance > self.distance,
+			None => true,
+		};
+
+		if open {
+			Vector3::new(1.0, 1.0, 1.0)
+		} else {
+			Vector3::new(0.0, 0.0, 0.0)
+		}
+	}
+}
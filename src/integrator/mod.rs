@@ -0,0 +1,25 @@
+//!
+//! The algorithms computing the light arriving at the camera, see `core::integrator` for how
+//! they're chosen
+//!
+
+mod ambient_occlusion;
+mod path_tracer;
+
+pub use self::{ambient_occlusion::AmbientOcclusion, path_tracer::PathTracer};
+pub use core::integrator::IntegratorSettings;
+
+use core::{math::prelude::*, prelude::*, sampling::Sampler, scene::Scene};
+
+pub trait Integrator: Send + Sync {
+	/// Radiance arriving at the camera along a primary ray, one sample of it
+	fn radiance(&self, ray: Ray, scene: &Scene, sampler: &mut dyn Sampler) -> Vector3;
+}
+
+/// The integrator the settings describe
+pub fn create(settings: &IntegratorSettings) -> Box<dyn Integrator> {
+	match *settings {
+		IntegratorSettings::PathTracer { roulette_depth, bounce_limit } => Box::new(PathTracer { roulette_depth, bounce_limit }),
+		IntegratorSettings::AmbientOcclusion { distance } => Box::new(AmbientOcclusion { distance }),
+	}
+}
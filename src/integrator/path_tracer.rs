@@ -0,0 +1,288 @@
+use core::{
+	material::{Bsdf, Subsurface},
+	math::prelude::*,
+	prelude::*,
+	sampling::{self, Sampler},
+	scene::{Object, Scene},
+	shading::{consistent_shading_normal, Frame},
+};
+
+use super::Integrator;
+
+///
+/// Unidirectional path tracing with next event estimation
+///
+/// Every vertex samples a light and the BSDF, and the two strategies are combined with multiple
+/// importance sampling. Subsurface materials are handled with a random walk through their interior.
+///
+/// Paths are ended by Russian roulette once they're `roulette_depth` vertices long, which keeps
+/// the estimate unbiased. `bounce_limit` additionally cuts them off, trading bias for speed.
+///
+#[derive(Debug, Clone)]
+pub struct PathTracer {
+	/// Vertices that are always traced before Russian roulette starts ending paths
+	pub roulette_depth: usize,
+	/// Paths end after this many vertices, None to only end them by Russian roulette
+	pub bounce_limit: Option<usize>,
+}
+
+/// Upper bound on the probability of a path surviving Russian roulette
+/// Without it, paths through materials that don't absorb anything would never end
+const MAX_SURVIVAL_PROBABILITY: f64 = 0.95;
+
+impl Integrator for PathTracer {
+	///
+	/// Lights are also sampled directly at every vertex. The emission of lights that paths hit is
+	/// weighted against light sampling with the density the BSDF sampled the ray with.
+	///
+	fn radiance(&self, ray: Ray, scene: &Scene, sampler: &mut dyn Sampler) -> Vector3 {
+		let mut radiance = Vector3::new(0.0, 0.0, 0.0);
+		let mut throughput = Vector3::new(1.0, 1.0, 1.0);
+		let mut ray = ray;
+		// None for camera rays and other rays leaving points where lights weren't sampled
+		let mut bsdf_pdf = None;
+		let mut depth = 1;
+
+		loop {
+			if self.bounce_limit.is_some_and(|limit| depth > limit) {
+				break;
+			}
+
+			let (object_index, hit) = match scene.closest_hit(ray) {
+				Some(closest) => closest,
+				None => {
+					radiance += throughput.mul_element_wise(escaped_radiance(ray, scene, bsdf_pdf));
+					break;
+				}
+			};
+			let object = &scene.objects()[object_index];
+			let surface_properties = object.geometry.get_surface_properties(hit);
+			let fragment_position = ray.origin + ray.direction * hit.distance;
+			let view_dir = -ray.direction;
+
+			if let Material::Emission(emission) = object.material.base() {
+				let cos_theta = surface_properties.geometric_normal.dot(view_dir);
+				let emitted = emission.radiance(&surface_properties, cos_theta, object.geometry.surface_area());
+				let weight = match bsdf_pdf {
+					Some(bsdf_pdf) => {
+						let light_pdf = scene.light_pdf(object_index, hit, surface_properties.geometric_normal);
+						sampling::power_heuristic(bsdf_pdf, light_pdf)
+					}
+					None => 1.0,
+				};
+				radiance += throughput.mul_element_wise(emitted) * weight;
+				break;
+			}
+			let bsdf = match object.material.bsdf(&surface_properties) {
+				Some(bsdf) => bsdf,
+				None => break,
+			};
+
+			// The shading frame stays oriented along the outside of the surface, the bsdf deals with
+			// directions below it. Only the consistency fix is done on the viewer's side.
+			let geometric_normal = surface_properties.geometric_normal;
+			let side = if geometric_normal.dot(view_dir) < 0.0 { -1.0 } else { 1.0 };
+			let normal = consistent_shading_normal(object.material.shading_normal(&surface_properties) * side, view_dir) * side;
+			let frame = Frame::new(normal, surface_properties.tangent);
+			let wo = frame.to_local(view_dir);
+
+			// Light of the sampled emitters arrives after one more bounce, which has to be within the limit
+			if self.bounce_limit.is_none_or(|limit| depth < limit) {
+				let direct = Self::sample_direct_light(&*bsdf, &frame, wo, fragment_position, geometric_normal, scene, sampler);
+				radiance += throughput.mul_element_wise(direct);
+			}
+
+			let sample = match bsdf.sample(wo, sampler.next_2d(), sampler.next_1d()) {
+				Some(sample) => sample,
+				None => break,
+			};
+			let direction = frame.to_world(sample.wi);
+
+			// Shading and geometric normal have to agree on which side of the surface the sample lies,
+			// otherwise light leaks through the surface
+			let geometric_cos = direction.dot(geometric_normal);
+			if (geometric_cos > 0.0) != (sample.wi.y > 0.0) {
+				break;
+			}
+			throughput = throughput.mul_element_wise(sample.f) * (sample.wi.y.abs() / sample.pdf);
+
+			let next_ray = Ray {
+				origin: fragment_position + geometric_normal * (0.0001 * geometric_cos.signum()),
+				direction,
+			};
+			match object.material.base() {
+				// Light refracted into a subsurface material continues through its interior
+				Material::Subsurface(subsurface) if side > 0.0 && geometric_cos < 0.0 => {
+					match self.trace_subsurface(object, subsurface, next_ray, sampler) {
+						Some((weight, exit)) => {
+							throughput = throughput.mul_element_wise(weight);
+							ray = exit;
+							// Lights weren't sampled at the exit point, so the next hit counts their emission in full
+							bsdf_pdf = None;
+						}
+						None => break,
+					}
+				}
+				_ => {
+					ray = next_ray;
+					bsdf_pdf = Some(sample.pdf);
+				}
+			}
+			depth += 1;
+
+			// Paths carrying little light are likely ended, the survivors are weighted up to make up for them
+			if depth > self.roulette_depth {
+				let survival = throughput.x.max(throughput.y).max(throughput.z).min(MAX_SURVIVAL_PROBABILITY);
+				if sampler.next_1d() >= survival {
+					break;
+				}
+				throughput /= survival;
+			}
+		}
+
+		radiance
+	}
+}
+
+impl PathTracer {
+	///
+	/// Next event estimation, the light arriving from a point sampled on one of the emitters
+	///
+	/// `wo` is in the local shading frame, the shadow ray leaves from the side of the surface the
+	/// light lies on. The result is weighted against the BSDF sampling the same direction.
+	///
+	fn sample_direct_light(
+		bsdf: &dyn Bsdf,
+		frame: &Frame,
+		wo: Vector3,
+		position: Vector3,
+		geometric_normal: Vector3,
+		scene: &Scene,
+		sampler: &mut dyn Sampler,
+	) -> Vector3 {
+		let light = match scene.sample_light(position, sampler.next_2d(), sampler.next_1d()) {
+			Some(light) => light,
+			None => return Vector3::new(0.0, 0.0, 0.0),
+		};
+
+		let wi = frame.to_local(light.direction);
+		let geometric_cos = light.direction.dot(geometric_normal);
+		if (geometric_cos > 0.0) != (wi.y > 0.0) {
+			return Vector3::new(0.0, 0.0, 0.0);
+		}
+		let f = bsdf.eval(wo, wi);
+		if f == Vector3::new(0.0, 0.0, 0.0) {
+			return Vector3::new(0.0, 0.0, 0.0);
+		}
+
+		let origin = position + geometric_normal * (0.0001 * geometric_cos.signum());
+		if !scene.unoccluded(position, origin, &light) {
+			return Vector3::new(0.0, 0.0, 0.0);
+		}
+
+		let weight = if light.delta {
+			1.0
+		} else {
+			sampling::power_heuristic(light.pdf, bsdf.pdf(wo, wi))
+		};
+		f.mul_element_wise(light.radiance) * (weight * wi.y.abs() / light.pdf)
+	}
+
+	///
+	/// Random walk through the interior of a subsurface material, starting just below its surface
+	///
+	/// Only the object's own geometry bounds the walk. Returns the ray the walk left the object
+	/// along and the throughput of the walk, None if it was absorbed.
+	///
+	fn trace_subsurface(
+		&self,
+		object: &Object,
+		subsurface: &Subsurface,
+		ray: Ray,
+		sampler: &mut dyn Sampler,
+	) -> Option<(Vector3, Ray)> {
+		let (sigma_s, sigma_t) = subsurface.coefficients();
+		let boundary = subsurface.boundary();
+		let mut throughput = Vector3::new(1.0, 1.0, 1.0);
+		let mut ray = ray;
+
+		for _ in 0..MAX_SUBSURFACE_STEPS {
+			let hit = object.geometry.intersects(ray)?;
+
+			// Distances are sampled with the extinction of a random channel and weighted with
+			// the density averaged over all channels
+			let channel = ((sampler.next_1d() * 3.0) as usize).min(2);
+			let distance = -(1.0 - sampler.next_1d()).ln() / sigma_t[channel];
+			let travelled = distance.min(hit.distance);
+			let transmittance = Vector3::new(
+				(-sigma_t.x * travelled).exp(),
+				(-sigma_t.y * travelled).exp(),
+				(-sigma_t.z * travelled).exp(),
+			);
+
+			if distance < hit.distance {
+				let density = sigma_t.mul_element_wise(transmittance);
+				let pdf = (density.x + density.y + density.z) / 3.0;
+				throughput = throughput.mul_element_wise(sigma_s.mul_element_wise(transmittance)) / pdf;
+
+				ray = Ray {
+					origin: ray.origin + ray.direction * distance,
+					direction: subsurface.sample_phase(ray.direction, sampler.next_2d()),
+				};
+				continue;
+			}
+			let pdf = (transmittance.x + transmittance.y + transmittance.z) / 3.0;
+			throughput = throughput.mul_element_wise(transmittance) / pdf;
+
+			// Arrived at the boundary from inside, either refract out or reflect back in
+			let surface = object.geometry.get_surface_properties(hit);
+			let position = ray.origin + ray.direction * hit.distance;
+			let frame = Frame::new(object.material.shading_normal(&surface), surface.tangent);
+			let sample = boundary.sample(frame.to_local(-ray.direction), sampler.next_2d(), sampler.next_1d())?;
+			let direction = frame.to_world(sample.wi);
+			let geometric_cos = direction.dot(surface.geometric_normal);
+			if (geometric_cos > 0.0) != (sample.wi.y > 0.0) {
+				return None;
+			}
+			throughput = throughput.mul_element_wise(sample.f) * (sample.wi.y.abs() / sample.pdf);
+
+			let next_ray = Ray {
+				origin: position + surface.geometric_normal * (0.0001 * geometric_cos.signum()),
+				direction,
+			};
+			if geometric_cos > 0.0 {
+				return Some((throughput, next_ray));
+			}
+			ray = next_ray;
+		}
+
+		None
+	}
+}
+
+/// Radiance of the lights at infinity arriving along a ray that left the scene
+fn escaped_radiance(ray: Ray, scene: &Scene, bsdf_pdf: Option<f64>) -> Vector3 {
+	let direction = ray.direction;
+	let mut radiance = Vector3::new(0.0, 0.0, 0.0);
+
+	for (index, light) in scene.lights.iter().enumerate() {
+		let emitted = light.radiance(direction);
+		if emitted == Vector3::new(0.0, 0.0, 0.0) {
+			continue;
+		}
+
+		let weight = match bsdf_pdf {
+			Some(bsdf_pdf) => {
+				let selection_probability = scene.light_selection_probability(ray.origin, scene.emitters().len() + index);
+				sampling::power_heuristic(bsdf_pdf, light.pdf(direction) * selection_probability)
+			}
+			None => 1.0,
+		};
+		radiance += emitted * weight;
+	}
+
+	radiance
+}
+
+/// Upper bound on scattering events inside a subsurface material, longer walks are terminated
+const MAX_SUBSURFACE_STEPS: usize = 256;
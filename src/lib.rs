@@ -5,6 +5,7 @@ extern crate serde_derive;
 #[macro_use]
 extern crate derive_builder;
 
+pub mod integrator;
 pub mod trace;
 pub mod transform;
 
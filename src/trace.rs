@@ -15,11 +15,15 @@ use rand;
 
 use num_cpus;
 
-use super::{transform::Transform};
+use super::{
+	integrator::{self, Integrator, IntegratorSettings},
+	transform::Transform,
+};
 
 use core::{
 	prelude::*,
-	math::prelude::*,
+	project::Project,
+	sampling::RandomSampler,
 	tile::Tile,
 	geometry::*,
 	scene::Scene,
@@ -51,12 +55,22 @@ pub struct Settings {
 	#[builder(default = "0")]
 	pub samples_per_iteration: usize,
 	pub tile_size: (usize, usize),
-	pub bounce_limit: usize,
+	/// The algorithm computing each sample and its parameters
+	#[builder(default)]
+	pub integrator: IntegratorSettings,
+}
+
+impl SettingsBuilder {
+	/// Takes the settings a project file chooses, currently its integrator
+	pub fn project(&mut self, project: &Project) -> &mut Self {
+		self.integrator(project.integrator.clone())
+	}
 }
 
 struct TraceContext {
 	pub scene: Scene,
 	pub settings: Settings,
+	pub integrator: Arc<dyn Integrator>,
 }
 
 #[derive(Clone, Debug)]
@@ -172,6 +186,10 @@ pub fn render_tiled(scene: Scene, settings: Settings) -> TaskHandle {
 		}
 	}
 
+	// Built before the scene is copied to the workers, instead of by each of them
+	scene.light_tree();
+	let integrator: Arc<dyn Integrator> = integrator::create(&settings.integrator).into();
+
 	let thread_count = Arc::new(AtomicUsize::new(settings.worker_count));
 	for _ in 0..settings.worker_count {
 		let queue = queue.clone();
@@ -182,40 +200,44 @@ pub fn render_tiled(scene: Scene, settings: Settings) -> TaskHandle {
 		let context = TraceContext {
 			scene: scene.clone(),
 			settings: settings.clone(),
+			integrator: integrator.clone(),
 		};
 
 		let thread_count = thread_count.clone();
-		thread::spawn(move || loop {
-			let mut tile = match queue.try_pop() {
-				Some(t) => t,
-				None => {
-					thread_count.fetch_sub(1, Ordering::Relaxed);
-					return;
-				}
-			};
+		thread::spawn(move || {
+			let mut sampler = RandomSampler::new();
+			loop {
+				let mut tile = match queue.try_pop() {
+					Some(t) => t,
+					None => {
+						thread_count.fetch_sub(1, Ordering::Relaxed);
+						return;
+					}
+				};
 
-			for y in tile.top..(tile.top + tile.height) {
-				for x in tile.left..(tile.left + tile.width) {
-					let primary = generate_primary_ray(x, y, &context.settings.camera_settings);
-					let sample = trace(primary, &context, 1);
+				for y in tile.top..(tile.top + tile.height) {
+					for x in tile.left..(tile.left + tile.width) {
+						let primary = generate_primary_ray(x, y, &context.settings.camera_settings);
+						let sample = context.integrator.radiance(primary, &context.scene, &mut sampler);
 
-					// Map the global pixel indices to the local tile buffer and store the sample
-					tile.data[(x - tile.left) + (y - tile.top) * tile.width] += sample;
+						// Map the global pixel indices to the local tile buffer and store the sample
+						tile.data[(x - tile.left) + (y - tile.top) * tile.width] += sample;
+					}
 				}
-			}
 
-			tile.sample_count += 1;
-			info!(target: "TraceCore", "Tile[{:4}, {:4}] finished sample [{}]", tile.left, tile.top, tile.sample_count);
+				tile.sample_count += 1;
+				info!(target: "TraceCore", "Tile[{:4}, {:4}] finished sample [{}]", tile.left, tile.top, tile.sample_count);
 
-			// Check if we are done
-			if tile.sample_count == context.settings.sample_count {
-				sender.send(Message::TileFinished(tile.clone())).unwrap();
-			} else {
-				queue.push(tile.clone());
+				// Check if we are done
+				if tile.sample_count == context.settings.sample_count {
+					sender.send(Message::TileFinished(tile.clone())).unwrap();
+				} else {
+					queue.push(tile.clone());
 
-				// Check if we want to send our tile down the pipe
-				if context.settings.samples_per_iteration != 0 && tile.sample_count % context.settings.samples_per_iteration == 0 {
-					sender.send(Message::TileProgressed(tile.clone())).unwrap();
+					// Check if we want to send our tile down the pipe
+					if context.settings.samples_per_iteration != 0 && tile.sample_count % context.settings.samples_per_iteration == 0 {
+						sender.send(Message::TileProgressed(tile.clone())).unwrap();
+					}
 				}
 			}
 		});
@@ -229,96 +251,6 @@ pub fn render_tiled(scene: Scene, settings: Settings) -> TaskHandle {
 	};
 }
 
-fn trace(ray: Ray, context: &TraceContext, depth: usize) -> Vector3 {
-	let settings = &context.settings;
-
-	if depth > settings.bounce_limit {
-		return Vector3::new(0.0, 0.0, 0.0);
-	}
-
-	let intersect = context.scene.intersect(ray);
-	let (object, hit) = match intersect {
-		Some((o, h)) => (o, h),
-		None => return Vector3::new(0.0, 0.0, 0.0),
-	};
-	let surface_properties = object.geometry.get_surface_properties(hit);
-	let normal = surface_properties.normal;
-	let fragment_position = ray.origin + ray.direction * hit.distance;
-	let (material_color, material_roughness, material_metalness) = match object.material {
-		Material::Diffuse(color, roughness) => (color, roughness, 0.0),
-		Material::Metal(color, roughness) => (color, roughness, 1.0),
-		Material::Emission(e, _, _, _) => {
-			return e;
-		}
-		_ => panic!(),
-	};
-
-	let view_dir = (settings.camera_settings.transform.position - fragment_position).normalize();
-	let f0 = Vector3::new(0.04, 0.04, 0.04);
-	let f0 = lerp_vec(f0, material_color, material_metalness);
-	// Decide whether to sample diffuse or specular
-	let r = rand::random::<f64>();
-	let local_cartesian = create_coordinate_system_of_n(normal);
-	let local_cartesian_transform = cgmath::Matrix3::from_cols(local_cartesian.0, normal, local_cartesian.1);
-	let prob_d = lerp(0.5, 0.0, material_metalness);
-	if r < prob_d {
-		let (sample, pdf) = uniform_sample_hemisphere();
-		let sample_world = (local_cartesian_transform * sample).normalize();
-		let radiance = trace(
-			Ray {
-				origin: fragment_position + normal * 0.00001,
-				direction: sample_world,
-			},
-			context,
-			depth + 1,
-		);
-		let cos_theta = normal.dot(sample_world).max(0.0);
-		let halfway = (sample_world + view_dir).normalize();
-		let fresnel = fresnel_schlick(halfway.dot(view_dir).max(0.0), f0);
-		let specular_part = fresnel;
-		let mut diffuse_part = Vector3::new(1.0, 1.0, 1.0) - specular_part;
-		diffuse_part *= 1.0 - material_metalness;
-		let output = (diffuse_part.mul_element_wise(material_color)).mul_element_wise(radiance) * cos_theta;
-		return output / (prob_d * pdf);
-	} else {
-		// Sample specular
-		let reflect = (-view_dir - 2.0 * (-view_dir.dot(normal) * normal)).normalize();
-		fn importance_sample_ggx(reflect: Vector3, roughness: f64) -> Vector3 {
-			let r1: f64 = rand::random();
-			let r2: f64 = rand::random();
-			let a = roughness * roughness;
-			let phi = 2.0 * PI * r1;
-			let theta = a * (r2 / (1.0 - r2)).sqrt();
-			let h = Vector3::new(theta.sin() * phi.cos(), theta.cos(), theta.sin() * phi.sin());
-			let (tangent, bitangent) = create_coordinate_system_of_n(reflect);
-			let matrix = cgmath::Matrix3::from_cols(tangent, reflect, bitangent);
-			return (matrix * h).normalize();
-		}
-		let sample_world = importance_sample_ggx(reflect, material_roughness);
-		let radiance = trace(
-			Ray {
-				origin: fragment_position + normal * 0.0001,
-				direction: sample_world,
-			},
-			context,
-			depth + 1,
-		);
-		let cos_theta = normal.dot(sample_world);
-		let light_dir = sample_world.normalize();
-		let halfway = (light_dir + view_dir).normalize();
-		let F = fresnel_schlick(halfway.dot(view_dir), f0);
-		let D = ggx_distribution(normal, halfway, material_roughness);
-		let G = geometry_smith(normal, view_dir, sample_world, material_roughness);
-		let nominator = D * G * F;
-		let denominator = 4.0 * normal.dot(view_dir) * cos_theta + 0.001;
-		let specular = nominator / denominator;
-		let output = (specular).mul_element_wise(radiance) * cos_theta;
-		// pdf
-		let pdf = { (D * normal.dot(halfway)) / (4.0 * halfway.dot(view_dir)) + 0.0001 };
-		return output / (1.0 - prob_d) / pdf;
-	}
-}
-
 fn generate_primary_ray(x: usize, y: usize, camera: &CameraSettings) -> Ray {
 	let width = camera.backbuffer_width as f64;
 	let height = camera.backbuffer_height as f64;
@@ -359,58 +291,42 @@ fn generate_primary_ray_with_dof(x: usize, y: usize, camera: &CameraSettings) ->
 	};
 }
 
-fn ggx_distribution(n: Vector3, h: Vector3, roughness: f64) -> f64 {
-	let a2 = roughness * roughness;
-	let NdotH = n.dot(h);
-
-	let nominator = a2;
-	let denominator = NdotH.powf(2.0) * (a2 - 1.0) + 1.0;
-	let denominator = (PI * denominator * denominator).max(1e-7);
-	return nominator / denominator;
-}
-
-fn geometry_schlick_ggx(n: Vector3, v: Vector3, r: f64) -> f64 {
-	let numerator = n.dot(v).max(0.0);
-	let k = (r * r) / 8.0;
-	let denominator = numerator * (1.0 - k) + k;
-
-	return numerator / denominator;
-}
-
-fn geometry_smith(n: Vector3, v: Vector3, l: Vector3, r: f64) -> f64 {
-	return geometry_schlick_ggx(n, v, r) * geometry_schlick_ggx(n, l, r);
-}
-
-fn fresnel_schlick(cos_theta: f64, F0: Vector3) -> Vector3 {
-	return F0 + (Vector3::new(1.0, 1.0, 1.0) - F0) * (1.0 - cos_theta).powf(5.0);
-}
-
-fn lerp_vec(min: Vector3, max: Vector3, a: f64) -> Vector3 {
-	Vector3::new(lerp(min.x, max.x, a), lerp(min.y, max.y, a), lerp(min.z, max.z, a))
-}
-
-fn lerp(min: f64, max: f64, a: f64) -> f64 {
-	min + a * (max - min)
-}
-
-fn uniform_sample_hemisphere() -> (Vector3, f64) {
-	let r1 = rand::random::<f64>();
-	let r2 = rand::random::<f64>();
-
-	let theta = (r1.sqrt()).acos();
-	let phi = 2.0 * PI * r2;
-
-	let pdf = r1.sqrt();
-	let cartesian = Vector3::new(theta.sin() * phi.cos(), theta.cos(), theta.sin() * phi.sin());
-	return (cartesian, pdf);
-}
-
-fn create_coordinate_system_of_n(n: Vector3) -> (Vector3, Vector3) {
-	let sign = if n.z > 0.0 { 1.0 } else { -1.0 };
-	let a = -1.0 / (sign + n.z);
-	let b = n.x * n.y * a;
-	return (
-		Vector3::new(1.0 + sign * n.x * n.x * a, sign * b, -sign * n.x),
-		Vector3::new(b, sign + n.y * n.y * a, -n.y),
-	);
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn project_chooses_integrator() {
+		// An unlit wall, black to a path tracer but open to ambient occlusion
+		let project: Project = serde_json::from_value(serde_json::json!({
+			"objects": [{
+				"geometry": { "Plane": { "origin": { "x": 0.0, "y": 0.0, "z": 2.0 }, "normal": { "x": 0.0, "y": 0.0, "z": -1.0 } } },
+				"material": { "Diffuse": [{ "x": 0.8, "y": 0.8, "z": 0.8 }, 0.5] },
+			}],
+			"integrator": { "AmbientOcclusion": {} },
+		}))
+		.unwrap();
+
+		let camera = CameraSettingsBuilder::default()
+			.backbuffer_width(4)
+			.backbuffer_height(4)
+			.fov_vert(55.0)
+			.transform(Transform::identity())
+			.focal_length(2.5)
+			.aperture_radius(0.0)
+			.build()
+			.unwrap();
+		let settings = SettingsBuilder::default()
+			.camera_settings(camera)
+			.sample_count(1)
+			.tile_size((4, 4))
+			.worker_count(1)
+			.project(&project)
+			.build()
+			.unwrap();
+		assert!(matches!(settings.integrator, IntegratorSettings::AmbientOcclusion { .. }));
+
+		let image = render_tiled(project.build_scene().unwrap(), settings).r#await();
+		assert!(image.iter().all(|pixel| *pixel == Vector3::new(1.0, 1.0, 1.0)));
+	}
 }